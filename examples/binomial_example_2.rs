@@ -2,10 +2,9 @@ use datafusion::prelude::{CsvReadOptions, SessionContext};
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> std::io::Result<()> {
-
-    let mut opts = CsvReadOptions::default();
-    opts.delimiter = b'\t';
-    opts.file_extension = ".tsv";
+    let opts = CsvReadOptions::new()
+        .delimiter(b'\t')
+        .file_extension(".tsv");
 
     let mut ctx = SessionContext::new();
     datafusion_statrs::distribution::binomial::register(&mut ctx)?;
@@ -15,9 +14,9 @@ async fn main() -> std::io::Result<()> {
     coins_1 AS (
         SELECT
             coin_id,
-            arrow_cast(tosses, 'UInt64') AS tosses,
-            arrow_cast(heads, 'UInt64') AS heads,
-            arrow_cast(tails, 'UInt64') AS tails
+            tosses,
+            heads,
+            tails
         FROM coins
         WHERE heads > tails
     ),
@@ -12,12 +12,30 @@
 //! `bernoulli_pmf(x, p)`  
 //! `bernoulli_ln_pmf(x, p)`  
 //! `bernoulli_cdf(x, p)`  
-//! `bernoulli_sf(x, p)`
+//! `bernoulli_sf(x, p)`  
+//! `bernoulli_ln_cdf(x, p)`  
+//! `bernoulli_ln_sf(x, p)`  
+//! `bernoulli_quantile(q, p)`  
+//! `bernoulli_inverse_sf(q, p)`  
+//! `bernoulli_interval(level, p)`  
+//! `bernoulli_mean(p)`  
+//! `bernoulli_variance(p)`  
+//! `bernoulli_std_dev(p)`  
+//! `bernoulli_skewness(p)`  
+//! `bernoulli_entropy(p)`  
+//! `bernoulli_mode(p)`  
+//! `bernoulli_median(p)`  
+//! `bernoulli_support_min(p)`  
+//! `bernoulli_support_max(p)`  
+//! `bernoulli_random(p[, seed])`
 //!
 //! with
 //!
 //!   `x`: {0, 1} `UInt64`/`BIGINT UNSIGNED`,  
-//!   `p`: [0, 1] `Float64`/`DOUBLE`
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `q`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
 //!
 //! Examples
 //! ```
@@ -25,7 +43,7 @@
 //! async fn main() -> std::io::Result<()> {
 //!     let mut ctx = datafusion::prelude::SessionContext::new();
 //!     datafusion_statrs::distribution::bernoulli::register(&mut ctx)?;
-//!     ctx.sql("SELECT bernoulli_pmf(CAST(0 AS BIGINT UNSIGNED), 0.2)").await?
+//!     ctx.sql("SELECT bernoulli_pmf(0, 0.2)").await?
 //!        .show().await?;
 //!     Ok(())
 //! }
@@ -36,10 +54,18 @@ use datafusion::execution::FunctionRegistry;
 use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::Bernoulli;
 
-use super::super::utils::discrete1u1f::Discrete1U1F;
+use super::super::utils::discrete1u1f::{Discrete1U1F, DiscreteInverse1U1F};
 use super::super::utils::evaluator1u1f::{
-    CdfEvaluator1U1F, LnPmfEvaluator1U1F, PmfEvaluator1U1F, SfEvaluator1U1F,
+    CdfEvaluator1U1F, InverseCdfEvaluator1U1F, InverseSfEvaluator1U1F, LnCdfEvaluator1U1F,
+    LnPmfEvaluator1U1F, LnSfEvaluator1U1F, PmfEvaluator1U1F, SfEvaluator1U1F,
 };
+use super::super::utils::interval1f::Interval1F;
+use super::super::utils::random1f::Random1F;
+use super::super::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use super::super::utils::statistic1f::Statistic1F;
 
 type Pmf = Discrete1U1F<PmfEvaluator1U1F<Bernoulli>>;
 
@@ -69,9 +95,137 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("bernoulli_sf"))
 }
 
+type LnCdf = Discrete1U1F<LnCdfEvaluator1U1F<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("bernoulli_ln_cdf"))
+}
+
+type LnSf = Discrete1U1F<LnSfEvaluator1U1F<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("bernoulli_ln_sf"))
+}
+
+type Quantile = DiscreteInverse1U1F<InverseCdfEvaluator1U1F<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("bernoulli_quantile"))
+}
+
+type InverseSf = DiscreteInverse1U1F<InverseSfEvaluator1U1F<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("bernoulli_inverse_sf"))
+}
+
+type Interval = Interval1F<Bernoulli>;
+
+/// ScalarUDF for the Bernoulli Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("bernoulli_interval"))
+}
+
+type Mean = Statistic1F<MeanStatistic<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("bernoulli_mean"))
+}
+
+type Variance = Statistic1F<VarianceStatistic<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("bernoulli_variance"))
+}
+
+type StdDev = Statistic1F<StdDevStatistic<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("bernoulli_std_dev"))
+}
+
+type Skewness = Statistic1F<SkewnessStatistic<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("bernoulli_skewness"))
+}
+
+type Entropy = Statistic1F<EntropyStatistic<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("bernoulli_entropy"))
+}
+
+type Mode = Statistic1F<ModeStatistic<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("bernoulli_mode"))
+}
+
+type Median = Statistic1F<MedianStatistic<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("bernoulli_median"))
+}
+
+type SupportMin = Statistic1F<SupportMinStatistic<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("bernoulli_support_min"))
+}
+
+type SupportMax = Statistic1F<SupportMaxStatistic<Bernoulli>>;
+
+/// ScalarUDF for the Bernoulli Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("bernoulli_support_max"))
+}
+
+type Random = Random1F<Bernoulli>;
+
+/// ScalarUDF for the Bernoulli Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("bernoulli_random"))
+}
+
 /// Register the functions for the Bernoulli Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pmf(), ln_pmf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pmf(),
+            ln_pmf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -81,10 +235,10 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch, UInt64Array},
+            array::{Array, Float64Array, RecordBatch, UInt64Array},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
-        common::cast::as_float64_array,
+        common::cast::{as_float64_array, as_uint64_array},
         error::DataFusionError,
         prelude::{SessionContext, col},
     };
@@ -144,8 +298,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq!(res_col.value(0), 0.75);
         assert_eq!(res_col.value(1), 0.25);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -164,12 +318,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<BinomialError>().unwrap();
-                assert_eq!(*be.as_ref(), BinomialError::ProbabilityInvalid);
+                assert!(e.to_string().starts_with("bernoulli_pmf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<BinomialError>().unwrap();
+                assert_eq!(*be, BinomialError::ProbabilityInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -219,8 +373,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.75);
         assert_eq!(res_col.value(1), 1.0);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -250,7 +404,43 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.25);
         assert_eq!(res_col.value(1), 0.0);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn bernoulli_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT bernoulli_quantile(0.5, 0.3)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 0);
+    }
+
+    #[tokio::test]
+    async fn bernoulli_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT bernoulli_inverse_sf(0.2, 0.3)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 1);
     }
 }
@@ -1,26 +1,45 @@
 //! Module containing functions to the Beta Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Beta`].
-//! 
+//!
 //! The [Beta Distribution](https://en.wikipedia.org/wiki/Beta_distribution) has two
 //! parameters:
-//! 
+//!
 //! α: 0 < α  
 //! β: 0 < β
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `beta_pdf(x, α, β)`  
 //! `beta_ln_pdf(x, α, β)`  
 //! `beta_cdf(x, α, β)`  
-//! `beta_sf(x, α, β)`
-//! 
+//! `beta_sf(x, α, β)`  
+//! `beta_ln_cdf(x, α, β)`  
+//! `beta_ln_sf(x, α, β)`  
+//! `beta_quantile(p, α, β)`  
+//! `beta_inverse_sf(p, α, β)`  
+//! `beta_interval(level, α, β)`  
+//! `beta_mean(α, β)`  
+//! `beta_variance(α, β)`  
+//! `beta_std_dev(α, β)`  
+//! `beta_skewness(α, β)`  
+//! `beta_entropy(α, β)`  
+//! `beta_mode(α, β)`  
+//! `beta_median(α, β)`  
+//! `beta_support_min(α, β)`  
+//! `beta_support_max(α, β)`  
+//! `beta_random(α, β[, seed])`  
+//! `beta_fit(x)` (aggregate)
+//!
 //! with
-//! 
+//!
 //!   `x`: [0, 1] `Float64`/`DOUBLE`,  
 //!   `α`: (0, +∞) `Float64`/`DOUBLE`,  
-//!   `β`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `β`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -35,11 +54,22 @@
 
 use datafusion::error::DataFusionError;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
 use statrs::distribution::Beta;
 
 use crate::utils::continuous3f::Continuous3F;
-use crate::utils::evaluator3f::{CdfEvaluator3F, LnPdfEvaluator3F, PdfEvaluator3F, SfEvaluator3F};
+use crate::utils::evaluator3f::{
+    CdfEvaluator3F, InverseCdfEvaluator3F, InverseSfEvaluator3F, LnCdfEvaluator3F,
+    LnPdfEvaluator3F, LnSfEvaluator3F, PdfEvaluator3F, SfEvaluator3F,
+};
+use crate::utils::fit::FitAggregate;
+use crate::utils::interval2f::Interval2F;
+use crate::utils::random2f::Random2F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic2f::Statistic2F;
 
 type Pdf = Continuous3F<PdfEvaluator3F<Beta>>;
 
@@ -69,9 +99,145 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("beta_sf"))
 }
 
+type LnCdf = Continuous3F<LnCdfEvaluator3F<Beta>>;
+
+/// ScalarUDF for the Beta Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("beta_ln_cdf"))
+}
+
+type LnSf = Continuous3F<LnSfEvaluator3F<Beta>>;
+
+/// ScalarUDF for the Beta Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("beta_ln_sf"))
+}
+
+type Quantile = Continuous3F<InverseCdfEvaluator3F<Beta>>;
+
+/// ScalarUDF for the Beta Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("beta_quantile"))
+}
+
+type InverseSf = Continuous3F<InverseSfEvaluator3F<Beta>>;
+
+/// ScalarUDF for the Beta Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("beta_inverse_sf"))
+}
+
+type Interval = Interval2F<Beta>;
+
+/// ScalarUDF for the Beta Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("beta_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<Beta>>;
+
+/// ScalarUDF for the Beta Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("beta_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<Beta>>;
+
+/// ScalarUDF for the Beta Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("beta_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<Beta>>;
+
+/// ScalarUDF for the Beta Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("beta_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<Beta>>;
+
+/// ScalarUDF for the Beta Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("beta_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<Beta>>;
+
+/// ScalarUDF for the Beta Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("beta_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<Beta>>;
+
+/// ScalarUDF for the Beta Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("beta_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<Beta>>;
+
+/// ScalarUDF for the Beta Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("beta_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<Beta>>;
+
+/// ScalarUDF for the Beta Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("beta_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<Beta>>;
+
+/// ScalarUDF for the Beta Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("beta_support_max"))
+}
+
+type Random = Random2F<Beta>;
+
+/// ScalarUDF for the Beta Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("beta_random"))
+}
+
+type Fit = FitAggregate<Beta>;
+
+/// AggregateUDF for the Beta Distribution maximum likelihood fit
+pub fn fit() -> AggregateUDF {
+    AggregateUDF::from(Fit::new("beta_fit"))
+}
+
 /// Register the functions for the Beta Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register_aggregates(registry, vec![fit()])?;
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -81,10 +247,10 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
-        common::cast::as_float64_array,
+        common::cast::{as_float64_array, as_struct_array, as_uint64_array},
         error::DataFusionError,
         prelude::{SessionContext, col},
     };
@@ -150,8 +316,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 2.3336838198042265);
         assert_eq_float!(res_col.value(1), 0.0018482208251953966);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -172,12 +338,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<BetaError>().unwrap();
-                assert_eq!(*be.as_ref(), BetaError::ShapeBInvalid);
+                assert!(e.to_string().starts_with("beta_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<BetaError>().unwrap();
+                assert_eq!(*be, BetaError::ShapeBInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -229,8 +395,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.7290614760289211);
         assert_eq_float!(res_col.value(1), 0.999943733215332);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -262,7 +428,105 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.2709385239710789);
         assert_eq_float!(res_col.value(1), 5.626678466797133e-5);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn beta_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT beta_quantile(0.5, 2.0, 3.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.38572756813238945);
+    }
+
+    #[tokio::test]
+    async fn beta_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT beta_inverse_sf(1e-10, 2.0, 3.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.9997075768467905);
+    }
+
+    #[tokio::test]
+    async fn beta_entropy_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT beta_entropy(2.0, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), -0.12509280256138622);
+    }
+
+    #[tokio::test]
+    async fn beta_fit_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT beta_fit(x) FROM (VALUES (0.1), (0.35), (0.5), (0.62), (0.8), (0.27)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        // Newton's method stops within a relative 1e-12 of the estimates.
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("shape_a").unwrap())
+                .unwrap()
+                .value(0),
+            1.7283269730532818,
+            1e-12
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("shape_b").unwrap())
+                .unwrap()
+                .value(0),
+            2.215764829736349,
+            1e-12
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+                .unwrap()
+                .value(0),
+            0.8147867420959938,
+            1e-12
+        );
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            6
+        );
     }
 }
@@ -1,33 +1,52 @@
 //! Module containing functions to the Binomial Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Binomial`].
-//! 
+//!
 //! The [Binomial Distribution](https://en.wikipedia.org/wiki/Binomial_distribution) has two
 //! parameters:
-//! 
+//!
 //! n: n ∈ N (natural numbers)  
 //! p: 0 ≤ p ≤ 1
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `binomial_pmf(x, n, p)`  
 //! `binomial_ln_pmf(x, n, p)`  
 //! `binomial_cdf(x, n, p)`  
-//! `binomial_sf(x, n, p)`
-//! 
+//! `binomial_sf(x, n, p)`  
+//! `binomial_ln_cdf(x, n, p)`  
+//! `binomial_ln_sf(x, n, p)`  
+//! `binomial_quantile(q, n, p)`  
+//! `binomial_inverse_sf(q, n, p)`  
+//! `binomial_interval(level, n, p)`  
+//! `binomial_mean(n, p)`  
+//! `binomial_variance(n, p)`  
+//! `binomial_std_dev(n, p)`  
+//! `binomial_skewness(n, p)`  
+//! `binomial_entropy(n, p)`  
+//! `binomial_mode(n, p)`  
+//! `binomial_median(n, p)`  
+//! `binomial_support_min(n, p)`  
+//! `binomial_support_max(n, p)`  
+//! `binomial_random(n, p[, seed])`  
+//! `binomial_fit(x, n)` (aggregate)
+//!
 //! with
-//! 
+//!
 //!   `x`: 0 ≤ x ≤ n `UInt64`/`BIGINT UNSIGNED`,  
 //!   `n`: 0 ≤ n `UInt64`/`BIGINT UNSIGNED`,  
-//!   `p`: [0, 1] `Float64`/`DOUBLE`
-//! 
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `q`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
 //! async fn main() -> std::io::Result<()> {
 //!     let mut ctx = datafusion::prelude::SessionContext::new();
 //!     datafusion_statrs::distribution::binomial::register(&mut ctx)?;
-//!     ctx.sql("SELECT binomial_cdf(CAST(2 AS BIGINT UNSIGNED), CAST(5 AS BIGINT UNSIGNED), 0.2)").await?
+//!     ctx.sql("SELECT binomial_cdf(2, 5, 0.2)").await?
 //!        .show().await?;
 //!     Ok(())
 //! }
@@ -35,11 +54,22 @@
 
 use datafusion::error::DataFusionError;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
 use statrs::distribution::Binomial;
 
-use crate::utils::discrete2u1f::Discrete2U1F;
-use crate::utils::evaluator2u1f::{CdfEvaluator2U1F, LnPmfEvaluator2U1F, PmfEvaluator2U1F, SfEvaluator2U1F};
+use crate::utils::discrete2u1f::{Discrete2U1F, DiscreteInverse2U1F};
+use crate::utils::evaluator2u1f::{
+    CdfEvaluator2U1F, InverseCdfEvaluator2U1F, InverseSfEvaluator2U1F, LnCdfEvaluator2U1F,
+    LnPmfEvaluator2U1F, LnSfEvaluator2U1F, PmfEvaluator2U1F, SfEvaluator2U1F,
+};
+use crate::utils::fit::CountFitAggregate;
+use crate::utils::interval1u1f::Interval1U1F;
+use crate::utils::random1u1f::Random1U1F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic1u1f::Statistic1U1F;
 
 type Pmf = Discrete2U1F<PmfEvaluator2U1F<Binomial>>;
 
@@ -69,9 +99,145 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("binomial_sf"))
 }
 
+type LnCdf = Discrete2U1F<LnCdfEvaluator2U1F<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("binomial_ln_cdf"))
+}
+
+type LnSf = Discrete2U1F<LnSfEvaluator2U1F<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("binomial_ln_sf"))
+}
+
+type Quantile = DiscreteInverse2U1F<InverseCdfEvaluator2U1F<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("binomial_quantile"))
+}
+
+type InverseSf = DiscreteInverse2U1F<InverseSfEvaluator2U1F<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("binomial_inverse_sf"))
+}
+
+type Interval = Interval1U1F<Binomial>;
+
+/// ScalarUDF for the Binomial Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("binomial_interval"))
+}
+
+type Mean = Statistic1U1F<MeanStatistic<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("binomial_mean"))
+}
+
+type Variance = Statistic1U1F<VarianceStatistic<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("binomial_variance"))
+}
+
+type StdDev = Statistic1U1F<StdDevStatistic<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("binomial_std_dev"))
+}
+
+type Skewness = Statistic1U1F<SkewnessStatistic<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("binomial_skewness"))
+}
+
+type Entropy = Statistic1U1F<EntropyStatistic<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("binomial_entropy"))
+}
+
+type Mode = Statistic1U1F<ModeStatistic<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("binomial_mode"))
+}
+
+type Median = Statistic1U1F<MedianStatistic<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("binomial_median"))
+}
+
+type SupportMin = Statistic1U1F<SupportMinStatistic<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("binomial_support_min"))
+}
+
+type SupportMax = Statistic1U1F<SupportMaxStatistic<Binomial>>;
+
+/// ScalarUDF for the Binomial Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("binomial_support_max"))
+}
+
+type Random = Random1U1F<Binomial>;
+
+/// ScalarUDF for the Binomial Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("binomial_random"))
+}
+
+type Fit = CountFitAggregate<Binomial>;
+
+/// AggregateUDF for the Binomial Distribution maximum likelihood fit
+pub fn fit() -> AggregateUDF {
+    AggregateUDF::from(Fit::new("binomial_fit"))
+}
+
 /// Register the functions for the Binomial Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pmf(), ln_pmf(), cdf(), sf()])
+    crate::utils::register::register_aggregates(registry, vec![fit()])?;
+    crate::utils::register::register(
+        registry,
+        vec![
+            pmf(),
+            ln_pmf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -81,12 +247,12 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch, UInt64Array},
+            array::{Array, Float64Array, RecordBatch, UInt64Array},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
-        common::cast::as_float64_array,
+        common::cast::{as_float64_array, as_struct_array, as_uint64_array},
         error::DataFusionError,
-        prelude::{SessionContext, col},
+        prelude::{SessionConfig, SessionContext, col, lit},
     };
     use statrs::distribution::BinomialError;
 
@@ -150,8 +316,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.421875);
         assert_eq_float!(res_col.value(1), 0.421875);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -172,12 +338,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<BinomialError>().unwrap();
-                assert_eq!(*be.as_ref(), BinomialError::ProbabilityInvalid);
+                assert!(e.to_string().starts_with("binomial_pmf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<BinomialError>().unwrap();
+                assert_eq!(*be, BinomialError::ProbabilityInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -229,8 +395,178 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.421875, 3e-15);
         assert_eq_float!(res_col.value(1), 0.84375);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn binomial_cdf_literal_parameters() {
+        let cdf = cdf();
+
+        let recs = make_records(vec![
+            (Some(0), None, None),
+            (Some(1), None, None),
+            (None, None, None),
+        ]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![
+                (cdf.call(vec![col("x"), lit(3u64), lit(0.25)])).alias("q"),
+            ])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 3);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.421875, 3e-15);
+        assert_eq_float!(res_col.value(1), 0.84375);
+        assert!(res_col.is_null(2));
+    }
+
+    #[tokio::test]
+    async fn binomial_cdf_changing_parameters() {
+        let cdf = cdf();
+
+        let recs = make_records(vec![
+            (Some(0), Some(3), Some(0.25)),
+            (Some(1), Some(3), Some(0.25)),
+            (Some(1), Some(2), Some(0.5)),
+            (Some(1), Some(2), None),
+            (Some(1), Some(3), Some(0.25)),
+        ]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![
+                (cdf.call(vec![col("x"), col("n"), col("p")])).alias("q"),
+            ])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 5);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.421875, 3e-15);
+        assert_eq_float!(res_col.value(1), 0.84375);
+        assert_eq_float!(res_col.value(2), 0.75);
+        assert!(res_col.is_null(3));
+        assert_eq_float!(res_col.value(4), 0.84375);
+    }
+
+    #[tokio::test]
+    async fn binomial_cdf_integer_arguments() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_cdf(x, n, 0.25) FROM (VALUES (CAST(1 AS TINYINT), 3), (0, 3)) AS t(x, n)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 2);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.84375);
+        assert_eq_float!(res_col.value(1), 0.421875, 3e-15);
+    }
+
+    #[tokio::test]
+    async fn binomial_cdf_negative_count() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_cdf(1, -3, 0.25)")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::Execution(msg)) => {
+                assert_eq!(msg, "binomial_cdf: argument 2 must not be negative, got -3");
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn binomial_cdf_whole_float_and_decimal_counts() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_cdf(1.0, CAST(3 AS DECIMAL(10, 0)), 0.25), binomial_cdf(arrow_cast(1, 'Float32'), CAST(3.00 AS DECIMAL(5, 2)), 0.25)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(
+            as_float64_array(res[0].column(0)).unwrap().value(0),
+            0.84375
+        );
+        assert_eq_float!(
+            as_float64_array(res[0].column(1)).unwrap().value(0),
+            0.84375
+        );
+    }
+
+    #[tokio::test]
+    async fn binomial_cdf_fractional_count() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_cdf(1.5, 3, 0.25)")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::Execution(msg)) => {
+                assert_eq!(
+                    msg,
+                    "binomial_cdf: argument 1 must be a non-negative integer, got 1.5"
+                );
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn binomial_cdf_wrong_type() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx.sql("SELECT binomial_cdf('one', 3, 0.25)").await;
+        match res {
+            Err(e) => {
+                assert!(
+                    e.to_string().contains(
+                        "binomial_cdf: argument 1 must be a non-negative integer, got Utf8"
+                    )
+                );
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
     }
 
     #[tokio::test]
@@ -262,7 +598,253 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.578125, 3e-15);
         assert_eq_float!(res_col.value(1), 0.15625, 4e-15);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn binomial_ln_sf_tail() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_ln_sf(149, 150, 0.3)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(
+            as_float64_array(res[0].column(0)).unwrap().value(0),
+            -180.59592064889046
+        );
+    }
+
+    #[tokio::test]
+    async fn binomial_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_quantile(0.5, CAST(10 AS BIGINT UNSIGNED), 0.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 5);
+    }
+
+    #[tokio::test]
+    async fn binomial_quantile_out_of_range() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_quantile(1.5, CAST(10 AS BIGINT UNSIGNED), 0.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert!(res_col.is_null(0));
+    }
+
+    #[tokio::test]
+    async fn binomial_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_inverse_sf(1e-10, CAST(100 AS BIGINT UNSIGNED), 0.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 81);
+    }
+
+    #[tokio::test]
+    async fn binomial_mean_skewness_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_mean(10, 0.3), binomial_skewness(10, 0.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(as_float64_array(res[0].column(0)).unwrap().value(0), 3.0);
+        assert_eq_float!(as_float64_array(res[0].column(1)).unwrap().value(0), 0.0);
+    }
+
+    #[tokio::test]
+    async fn binomial_mode_median_support_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_mode(10, 0.3), binomial_median(10, 0.3), binomial_support_min(10, 0.3), binomial_support_max(10, 0.3)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 4);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(as_uint64_array(res[0].column(0)).unwrap().value(0), 3);
+        assert_eq!(as_uint64_array(res[0].column(1)).unwrap().value(0), 3);
+        assert_eq!(as_uint64_array(res[0].column(2)).unwrap().value(0), 0);
+        assert_eq!(as_uint64_array(res[0].column(3)).unwrap().value(0), 10);
+    }
+
+    #[tokio::test]
+    async fn binomial_median_one_trial() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_median(1, 0.6), binomial_median(1, 0.4), binomial_quantile(0.5, 1, 0.6)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(as_uint64_array(res[0].column(0)).unwrap().value(0), 1);
+        assert_eq!(as_uint64_array(res[0].column(1)).unwrap().value(0), 0);
+        assert_eq!(as_uint64_array(res[0].column(2)).unwrap().value(0), 1);
+    }
+
+    #[tokio::test]
+    async fn binomial_fit_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_fit(k, n) FROM (VALUES (3, 10), (5, 10), (2, 10), (NULL, 10), (7, 10)) AS t(k, n)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("trials").unwrap())
+                .unwrap()
+                .value(0),
+            10
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("p").unwrap())
+                .unwrap()
+                .value(0),
+            0.425
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+                .unwrap()
+                .value(0),
+            -8.363109286385501
+        );
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            4
+        );
+    }
+
+    #[tokio::test]
+    async fn binomial_fit_varying_trials() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_fit(k, n) FROM (VALUES (3, 10), (5, 12), (2, 8)) AS t(k, n)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert!(fit.column_by_name("trials").unwrap().is_null(0));
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("p").unwrap())
+                .unwrap()
+                .value(0),
+            0.3333333333333333
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+                .unwrap()
+                .value(0),
+            -4.301167404072709
+        );
+
+        let res = ctx
+            .sql("SELECT binomial_fit(k, n) FROM (VALUES (3, 10), (5, 4)) AS t(k, n)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert!(res[0].column(0).is_null(0));
+    }
+
+    #[tokio::test]
+    async fn binomial_fit_partitioned() {
+        let config = SessionConfig::new()
+            .with_target_partitions(4)
+            .with_batch_size(100);
+        let mut ctx = SessionContext::new_with_config(config);
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT binomial_fit(value % 3, 2) FROM generate_series(1, 9000)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("trials").unwrap())
+                .unwrap()
+                .value(0),
+            2
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("p").unwrap())
+                .unwrap()
+                .value(0),
+            0.5
+        );
+        // The partitions may sum the log-likelihood in any order.
+        let ln_likelihood = as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+            .unwrap()
+            .value(0);
+        assert!((ln_likelihood + 10397.207708399179).abs() < 1e-6);
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            9000
+        );
     }
 }
@@ -0,0 +1,413 @@
+//! Module containing functions to the Categorical Distribution.
+//!
+//! Implemented by [`statrs::distribution::Categorical`].
+//!
+//! The [Categorical Distribution](https://en.wikipedia.org/wiki/Categorical_distribution) has one
+//! parameter, a vector of probability masses:
+//!
+//! p: [p0, p1, ..., pk-1], 0 ≤ pi, 0 < Σ pi
+//!
+//! The masses need not sum to one; they are normalized by their total.
+//!
+//! Usage:
+//!
+//! `categorical_pmf(x, p)`  
+//! `categorical_ln_pmf(x, p)`  
+//! `categorical_cdf(x, p)`  
+//! `categorical_sf(x, p)`  
+//! `categorical_ln_cdf(x, p)`  
+//! `categorical_ln_sf(x, p)`  
+//! `categorical_interval(level, p)`  
+//! `categorical_mean(p)`  
+//! `categorical_variance(p)`  
+//! `categorical_std_dev(p)`  
+//! `categorical_skewness(p)`  
+//! `categorical_entropy(p)`  
+//! `categorical_mode(p)`  
+//! `categorical_median(p)`  
+//! `categorical_support_min(p)`  
+//! `categorical_support_max(p)`  
+//! `categorical_random(p[, seed])`
+//!
+//! with
+//!
+//!   `x`: {0, 1, ..., k-1} `UInt64`/`BIGINT UNSIGNED`,  
+//!   `p`: [0, +∞)ᵏ `List<Float64>`/`DOUBLE[]`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
+//! Examples
+//! ```
+//! #[tokio::main(flavor = "current_thread")]
+//! async fn main() -> std::io::Result<()> {
+//!     let mut ctx = datafusion::prelude::SessionContext::new();
+//!     datafusion_statrs::distribution::categorical::register(&mut ctx)?;
+//!     ctx.sql("SELECT categorical_pmf(1, [0.2, 0.3, 0.5])").await?
+//!        .show().await?;
+//!     Ok(())
+//! }
+//! ```
+
+use datafusion::error::DataFusionError;
+use datafusion::execution::FunctionRegistry;
+use datafusion::logical_expr::ScalarUDF;
+use statrs::distribution::Categorical;
+
+use crate::utils::discrete1u1l::Discrete1U1L;
+use crate::utils::evaluator1u1l::{
+    CdfEvaluator1U1L, LnCdfEvaluator1U1L, LnPmfEvaluator1U1L, LnSfEvaluator1U1L, PmfEvaluator1U1L,
+    SfEvaluator1U1L,
+};
+use crate::utils::interval1l::Interval1L;
+use crate::utils::random1l::Random1L;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic1l::Statistic1L;
+
+type Pmf = Discrete1U1L<PmfEvaluator1U1L<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution PMF
+pub fn pmf() -> ScalarUDF {
+    ScalarUDF::from(Pmf::new("categorical_pmf"))
+}
+
+type LnPmf = Discrete1U1L<LnPmfEvaluator1U1L<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution log PMF
+pub fn ln_pmf() -> ScalarUDF {
+    ScalarUDF::from(LnPmf::new("categorical_ln_pmf"))
+}
+
+type Cdf = Discrete1U1L<CdfEvaluator1U1L<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution CDF
+pub fn cdf() -> ScalarUDF {
+    ScalarUDF::from(Cdf::new("categorical_cdf"))
+}
+
+type Sf = Discrete1U1L<SfEvaluator1U1L<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution SF
+pub fn sf() -> ScalarUDF {
+    ScalarUDF::from(Sf::new("categorical_sf"))
+}
+
+type LnCdf = Discrete1U1L<LnCdfEvaluator1U1L<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("categorical_ln_cdf"))
+}
+
+type LnSf = Discrete1U1L<LnSfEvaluator1U1L<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("categorical_ln_sf"))
+}
+
+type Interval = Interval1L<Categorical>;
+
+/// ScalarUDF for the Categorical Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("categorical_interval"))
+}
+
+type Mean = Statistic1L<MeanStatistic<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("categorical_mean"))
+}
+
+type Variance = Statistic1L<VarianceStatistic<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("categorical_variance"))
+}
+
+type StdDev = Statistic1L<StdDevStatistic<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("categorical_std_dev"))
+}
+
+type Skewness = Statistic1L<SkewnessStatistic<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("categorical_skewness"))
+}
+
+type Entropy = Statistic1L<EntropyStatistic<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("categorical_entropy"))
+}
+
+type Mode = Statistic1L<ModeStatistic<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("categorical_mode"))
+}
+
+type Median = Statistic1L<MedianStatistic<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("categorical_median"))
+}
+
+type SupportMin = Statistic1L<SupportMinStatistic<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("categorical_support_min"))
+}
+
+type SupportMax = Statistic1L<SupportMaxStatistic<Categorical>>;
+
+/// ScalarUDF for the Categorical Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("categorical_support_max"))
+}
+
+type Random = Random1L<Categorical>;
+
+/// ScalarUDF for the Categorical Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("categorical_random"))
+}
+
+/// Register the functions for the Categorical Distribution
+pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
+    crate::utils::register::register(
+        registry,
+        vec![
+            pmf(),
+            ln_pmf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert_eq_float::assert_eq_float;
+    use datafusion::{
+        arrow::{
+            array::{Array, ListArray, RecordBatch, UInt64Array},
+            datatypes::{DataType, Field, Float64Type, Schema, SchemaRef},
+        },
+        common::cast::{as_float64_array, as_uint64_array},
+        error::DataFusionError,
+        prelude::{SessionContext, col},
+    };
+    use statrs::distribution::CategoricalError;
+
+    use super::*;
+
+    fn get_schema() -> SchemaRef {
+        SchemaRef::new(Schema::new(vec![
+            Field::new("x", DataType::UInt64, true),
+            Field::new("p", DataType::new_list(DataType::Float64, true), true),
+        ]))
+    }
+
+    type Row = (Option<u64>, Option<Vec<Option<f64>>>);
+
+    fn make_records(rows: Vec<Row>) -> RecordBatch {
+        let mut xs = Vec::new();
+        let mut ps = Vec::new();
+        for row in rows {
+            xs.push(row.0);
+            ps.push(row.1);
+        }
+
+        RecordBatch::try_new(
+            get_schema(),
+            vec![
+                Arc::new(UInt64Array::from(xs)),
+                Arc::new(ListArray::from_iter_primitive::<Float64Type, _, _>(ps)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn categorical_pmf_success() {
+        let pmf = pmf();
+
+        let recs = make_records(vec![
+            (Some(1), Some(vec![Some(0.2), Some(0.3), Some(0.5)])),
+            (Some(1), Some(vec![Some(1.0), Some(2.0), Some(1.0)])),
+            (Some(3), Some(vec![Some(0.2), Some(0.3), Some(0.5)])),
+            (None, Some(vec![Some(0.2), Some(0.3), Some(0.5)])),
+            (Some(1), None),
+            (Some(1), Some(vec![Some(0.2), None, Some(0.5)])),
+        ]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![(pmf.call(vec![col("x"), col("p")])).alias("q")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 6);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.3);
+        assert_eq_float!(res_col.value(1), 0.5);
+        assert_eq!(res_col.value(2), 0.0);
+        assert!(res_col.is_null(3));
+        assert!(res_col.is_null(4));
+        assert!(res_col.is_null(5));
+    }
+
+    #[tokio::test]
+    async fn categorical_pmf_failure_1() {
+        let pmf = pmf();
+
+        let recs = make_records(vec![(Some(0), Some(vec![Some(0.2), Some(-0.3)]))]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![(pmf.call(vec![col("x"), col("p")])).alias("q")])
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("categorical_pmf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<CategoricalError>()
+                    .unwrap();
+                assert_eq!(*be, CategoricalError::ProbMassHasInvalidElements);
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn categorical_ln_pmf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT categorical_ln_pmf(CAST(2 AS BIGINT UNSIGNED), [0.2, 0.3, 0.5])")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), -std::f64::consts::LN_2);
+    }
+
+    #[tokio::test]
+    async fn categorical_cdf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT categorical_cdf(CAST(1 AS BIGINT UNSIGNED), [0.2, 0.3, 0.5])")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.5);
+    }
+
+    #[tokio::test]
+    async fn categorical_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT categorical_sf(CAST(0 AS BIGINT UNSIGNED), [0.2, 0.3, 0.5])")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.8);
+    }
+
+    #[tokio::test]
+    async fn categorical_mean_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT categorical_mean([0.2, 0.3, 0.5])")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.3);
+    }
+
+    #[tokio::test]
+    async fn categorical_mode_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT categorical_mode([0.2, 0.5, 0.3]), categorical_support_max([0.2, 0.5, 0.3])")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(as_uint64_array(res[0].column(0)).unwrap().value(0), 1);
+        assert_eq!(as_uint64_array(res[0].column(1)).unwrap().value(0), 2);
+    }
+}
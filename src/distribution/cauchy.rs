@@ -1,26 +1,44 @@
 //! Module containing functions to the Cauchy Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Cauchy`].
-//! 
+//!
 //! The [Cauchy Distribution](https://en.wikipedia.org/wiki/Cauchy_distribution) has two
 //! parameters:
-//! 
+//!
 //! x0: x0 ∈ R (real numbers)  
 //! γ: 0 < γ
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `cauchy_pdf(x, x0, γ)`  
 //! `cauchy_ln_pdf(x, x0, γ)`  
 //! `cauchy_cdf(x, x0, γ)`  
-//! `cauchy_sf(x, x0, γ)`
-//! 
+//! `cauchy_sf(x, x0, γ)`  
+//! `cauchy_ln_cdf(x, x0, γ)`  
+//! `cauchy_ln_sf(x, x0, γ)`  
+//! `cauchy_quantile(p, x0, γ)`  
+//! `cauchy_inverse_sf(p, x0, γ)`  
+//! `cauchy_interval(level, x0, γ)`  
+//! `cauchy_mean(x0, γ)`  
+//! `cauchy_variance(x0, γ)`  
+//! `cauchy_std_dev(x0, γ)`  
+//! `cauchy_skewness(x0, γ)`  
+//! `cauchy_entropy(x0, γ)`  
+//! `cauchy_mode(x0, γ)`  
+//! `cauchy_median(x0, γ)`  
+//! `cauchy_support_min(x0, γ)`  
+//! `cauchy_support_max(x0, γ)`  
+//! `cauchy_random(x0, γ[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `x`: (-∞, +∞) `Float64`/`DOUBLE`,  
 //!   `x0`: (-∞, +∞) `Float64`/`DOUBLE`,  
-//!   `γ`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `γ`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -39,7 +57,17 @@ use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::Cauchy;
 
 use crate::utils::continuous3f::Continuous3F;
-use crate::utils::evaluator3f::{CdfEvaluator3F, LnPdfEvaluator3F, PdfEvaluator3F, SfEvaluator3F};
+use crate::utils::evaluator3f::{
+    CdfEvaluator3F, InverseCdfEvaluator3F, InverseSfEvaluator3F, LnCdfEvaluator3F,
+    LnPdfEvaluator3F, LnSfEvaluator3F, PdfEvaluator3F, SfEvaluator3F,
+};
+use crate::utils::interval2f::Interval2F;
+use crate::utils::random2f::Random2F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic2f::Statistic2F;
 
 type Pdf = Continuous3F<PdfEvaluator3F<Cauchy>>;
 
@@ -69,19 +97,147 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("cauchy_sf"))
 }
 
+type LnCdf = Continuous3F<LnCdfEvaluator3F<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("cauchy_ln_cdf"))
+}
+
+type LnSf = Continuous3F<LnSfEvaluator3F<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("cauchy_ln_sf"))
+}
+
+type Quantile = Continuous3F<InverseCdfEvaluator3F<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("cauchy_quantile"))
+}
+
+type InverseSf = Continuous3F<InverseSfEvaluator3F<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("cauchy_inverse_sf"))
+}
+
+type Interval = Interval2F<Cauchy>;
+
+/// ScalarUDF for the Cauchy Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("cauchy_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("cauchy_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("cauchy_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("cauchy_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("cauchy_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("cauchy_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("cauchy_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("cauchy_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("cauchy_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<Cauchy>>;
+
+/// ScalarUDF for the Cauchy Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("cauchy_support_max"))
+}
+
+type Random = Random2F<Cauchy>;
+
+/// ScalarUDF for the Cauchy Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("cauchy_random"))
+}
+
 /// Register the functions for the Cauchy Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
     use assert_eq_float::assert_eq_float;
+    use std::sync::Arc;
 
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -150,8 +306,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.12732395447351627);
         assert_eq_float!(res_col.value(1), 0.12732395447351627);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -172,12 +328,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<CauchyError>().unwrap();
-                assert_eq!(*be.as_ref(), CauchyError::ScaleInvalid);
+                assert!(e.to_string().starts_with("cauchy_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<CauchyError>().unwrap();
+                assert_eq!(*be, CauchyError::ScaleInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -229,8 +385,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.1475836176504332);
         assert_eq_float!(res_col.value(1), 0.8524163823495667);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -262,7 +418,64 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.8524163823495667);
         assert_eq_float!(res_col.value(1), 0.1475836176504332);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn cauchy_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT cauchy_quantile(0.75, 0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.0);
+    }
+
+    #[tokio::test]
+    async fn cauchy_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT cauchy_inverse_sf(1e-10, 0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 3183098861.837907);
+    }
+
+    #[tokio::test]
+    async fn cauchy_mean_undefined() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT cauchy_mean(0.0, 1.0), cauchy_entropy(0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert!(as_float64_array(res[0].column(0)).unwrap().is_null(0));
+        assert_eq_float!(
+            as_float64_array(res[0].column(1)).unwrap().value(0),
+            2.5310242469692907
+        );
     }
 }
@@ -1,30 +1,48 @@
 //! Module containing functions to the Chi Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Chi`].
-//! 
+//!
 //! The [Chi Distribution](https://en.wikipedia.org/wiki/Chi_distribution) has one
 //! parameter:
-//! 
+//!
 //! k: k ∈ N (natural numbers)
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `chi_pdf(x, k)`  
 //! `chi_cdf(x, k)`  
-//! `chi_sf(x, k)`
-//! 
+//! `chi_sf(x, k)`  
+//! `chi_ln_cdf(x, k)`  
+//! `chi_ln_sf(x, k)`  
+//! `chi_quantile(p, k)`  
+//! `chi_inverse_sf(p, k)`  
+//! `chi_interval(level, k)`  
+//! `chi_mean(k)`  
+//! `chi_variance(k)`  
+//! `chi_std_dev(k)`  
+//! `chi_skewness(k)`  
+//! `chi_entropy(k)`  
+//! `chi_mode(k)`  
+//! `chi_median(k)`  
+//! `chi_support_min(k)`  
+//! `chi_support_max(k)`  
+//! `chi_random(k[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `x`: [0, +∞) `Float64`/`DOUBLE`,  
-//!   `k`: (0, +∞) `UInt64`/`BIGINT UNSIGNED`,
-//! 
+//!   `k`: (0, +∞) `UInt64`/`BIGINT UNSIGNED`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
 //! async fn main() -> std::io::Result<()> {
 //!     let mut ctx = datafusion::prelude::SessionContext::new();
-//!     datafusion_statrs::distribution::chi::register(&mut ctx)?;
-//!     ctx.sql("SELECT chi_pdf(1.25, CAST(4 AS BIGINT UNSIGNED))").await?
+//!     datafusion_statrs::distribution::geometric::register(&mut ctx)?;
+//!     ctx.sql("SELECT geometric_pmf(3, 0.25)").await?
 //!        .show().await?;
 //!     Ok(())
 //! }
@@ -36,7 +54,17 @@ use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::Chi;
 
 use crate::utils::continuous1f1u::Continuous1F1U;
-use crate::utils::evaluator1f1u::{CdfEvaluator1F1U, LnPdfEvaluator1F1U, PdfEvaluator1F1U, SfEvaluator1F1U};
+use crate::utils::evaluator1f1u::{
+    CdfEvaluator1F1U, InverseCdfEvaluator1F1U, InverseSfEvaluator1F1U, LnCdfEvaluator1F1U,
+    LnPdfEvaluator1F1U, LnSfEvaluator1F1U, PdfEvaluator1F1U, SfEvaluator1F1U,
+};
+use crate::utils::interval1u::Interval1U;
+use crate::utils::random1u::Random1U;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic1u::Statistic1U;
 
 type Pdf = Continuous1F1U<PdfEvaluator1F1U<Chi>>;
 
@@ -66,9 +94,137 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("chi_sf"))
 }
 
+type LnCdf = Continuous1F1U<LnCdfEvaluator1F1U<Chi>>;
+
+/// ScalarUDF for the Chi Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("chi_ln_cdf"))
+}
+
+type LnSf = Continuous1F1U<LnSfEvaluator1F1U<Chi>>;
+
+/// ScalarUDF for the Chi Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("chi_ln_sf"))
+}
+
+type Quantile = Continuous1F1U<InverseCdfEvaluator1F1U<Chi>>;
+
+/// ScalarUDF for the Chi Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("chi_quantile"))
+}
+
+type InverseSf = Continuous1F1U<InverseSfEvaluator1F1U<Chi>>;
+
+/// ScalarUDF for the Chi Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("chi_inverse_sf"))
+}
+
+type Interval = Interval1U<Chi>;
+
+/// ScalarUDF for the Chi Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("chi_interval"))
+}
+
+type Mean = Statistic1U<MeanStatistic<Chi>>;
+
+/// ScalarUDF for the Chi Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("chi_mean"))
+}
+
+type Variance = Statistic1U<VarianceStatistic<Chi>>;
+
+/// ScalarUDF for the Chi Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("chi_variance"))
+}
+
+type StdDev = Statistic1U<StdDevStatistic<Chi>>;
+
+/// ScalarUDF for the Chi Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("chi_std_dev"))
+}
+
+type Skewness = Statistic1U<SkewnessStatistic<Chi>>;
+
+/// ScalarUDF for the Chi Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("chi_skewness"))
+}
+
+type Entropy = Statistic1U<EntropyStatistic<Chi>>;
+
+/// ScalarUDF for the Chi Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("chi_entropy"))
+}
+
+type Mode = Statistic1U<ModeStatistic<Chi>>;
+
+/// ScalarUDF for the Chi Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("chi_mode"))
+}
+
+type Median = Statistic1U<MedianStatistic<Chi>>;
+
+/// ScalarUDF for the Chi Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("chi_median"))
+}
+
+type SupportMin = Statistic1U<SupportMinStatistic<Chi>>;
+
+/// ScalarUDF for the Chi Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("chi_support_min"))
+}
+
+type SupportMax = Statistic1U<SupportMaxStatistic<Chi>>;
+
+/// ScalarUDF for the Chi Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("chi_support_max"))
+}
+
+type Random = Random1U<Chi>;
+
+/// ScalarUDF for the Chi Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("chi_random"))
+}
+
 /// Register the functions for the Binomial Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -78,7 +234,7 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch, UInt64Array},
+            array::{Array, Float64Array, RecordBatch, UInt64Array},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -129,9 +285,7 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pmf.call(vec![col("x"), col("n")])).alias("q"),
-            ])
+            .select(vec![(pmf.call(vec![col("x"), col("n")])).alias("q")])
             .unwrap()
             .collect()
             .await
@@ -143,8 +297,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.17603266338214946);
         assert_eq_float!(res_col.value(1), 0.5828291804965118);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -157,20 +311,18 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pmf.call(vec![col("x"), col("n")])).alias("q"),
-            ])
+            .select(vec![(pmf.call(vec![col("x"), col("n")])).alias("q")])
             .unwrap()
             .collect()
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<ChiError>().unwrap();
-                assert_eq!(*be.as_ref(), ChiError::FreedomInvalid);
+                assert!(e.to_string().starts_with("chi_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<ChiError>().unwrap();
+                assert_eq!(*be, ChiError::FreedomInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -208,9 +360,7 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pmf.call(vec![col("x"), col("n")])).alias("q"),
-            ])
+            .select(vec![(pmf.call(vec![col("x"), col("n")])).alias("q")])
             .unwrap()
             .collect()
             .await
@@ -222,8 +372,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.030859595783726688);
         assert_eq_float!(res_col.value(1), 0.4778328104646076);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -241,9 +391,7 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pmf.call(vec![col("x"), col("n")])).alias("q"),
-            ])
+            .select(vec![(pmf.call(vec![col("x"), col("n")])).alias("q")])
             .unwrap()
             .collect()
             .await
@@ -255,7 +403,72 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.9691404042162733);
         assert_eq_float!(res_col.value(1), 0.5221671895353923);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn chi_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT chi_quantile(0.5, CAST(2 AS BIGINT UNSIGNED))")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.1774100225154747);
+    }
+
+    #[tokio::test]
+    async fn chi_quantile_small_p() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        crate::distribution::chi_squared::register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT chi_quantile(p, CAST(1 AS BIGINT UNSIGNED)), sqrt(chi_squared_quantile(p, 1.0)), chi_quantile(p, CAST(3 AS BIGINT UNSIGNED)) FROM (VALUES (1e-8), (1e-20), (1e-300)) AS t(p)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 3);
+        assert_eq!(res[0].num_rows(), 3);
+        let chi_1 = as_float64_array(res[0].column(0)).unwrap();
+        let chi_squared_1 = as_float64_array(res[0].column(1)).unwrap();
+        let chi_3 = as_float64_array(res[0].column(2)).unwrap();
+        for i in 0..3 {
+            assert!(chi_1.value(i) > 0.0);
+            assert_eq_float!(chi_1.value(i), chi_squared_1.value(i));
+        }
+        // For k = 1 the CDF is erf(x / √2), close to x √(2 / π) for small x.
+        assert_eq_float!(chi_1.value(0) / 1.2533141373155003e-8, 1.0, 1e-7);
+        assert_eq_float!(chi_1.value(1) / 1.2533141373155003e-20, 1.0, 1e-9);
+        assert_eq_float!(chi_3.value(1), 3.3501202635808068e-7, 1e-15);
+        assert!(chi_3.value(2) > 0.0);
+    }
+
+    #[tokio::test]
+    async fn chi_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT chi_inverse_sf(1e-12, CAST(2 AS BIGINT UNSIGNED))")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 7.433844377699677);
     }
 }
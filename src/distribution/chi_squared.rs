@@ -1,28 +1,46 @@
 //! Module containing functions to the Chi-squared Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::ChiSquared`].
-//! 
+//!
 //! The [Chi-squared Distribution](https://en.wikipedia.org/wiki/Chi-squared_distribution) has one
 //! parameter:
-//! 
+//!
 //! k: 0 < k
-//! 
+//!
 //! NB the chi-squared is usually parameterised with k ∈ N, but as the underlying implementation is
 //! built on the continuous Gamma distribution, the implementation in [`statrs::distribution::ChiSquared`]
 //! is generalised to allow k to be continuous.
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `chi_squared_pdf(x, k)`  
 //! `chi_squared_ln_pdf(x, k)`  
 //! `chi_squared_cdf(x, k)`  
-//! `chi_squared_sf(x, k)`
-//! 
+//! `chi_squared_sf(x, k)`  
+//! `chi_squared_ln_cdf(x, k)`  
+//! `chi_squared_ln_sf(x, k)`  
+//! `chi_squared_quantile(p, k)`  
+//! `chi_squared_inverse_sf(p, k)`  
+//! `chi_squared_interval(level, k)`  
+//! `chi_squared_mean(k)`  
+//! `chi_squared_variance(k)`  
+//! `chi_squared_std_dev(k)`  
+//! `chi_squared_skewness(k)`  
+//! `chi_squared_entropy(k)`  
+//! `chi_squared_mode(k)`  
+//! `chi_squared_median(k)`  
+//! `chi_squared_support_min(k)`  
+//! `chi_squared_support_max(k)`  
+//! `chi_squared_random(k[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `x`: [0, +∞) `Float64`/`DOUBLE`,  
-//!   `k`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `k`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -41,7 +59,17 @@ use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::ChiSquared;
 
 use crate::utils::continuous2f::Continuous2F;
-use crate::utils::evaluator2f::{CdfEvaluator2F, LnPdfEvaluator2F, PdfEvaluator2F, SfEvaluator2F};
+use crate::utils::evaluator2f::{
+    CdfEvaluator2F, InverseCdfEvaluator2F, InverseSfEvaluator2F, LnCdfEvaluator2F,
+    LnPdfEvaluator2F, LnSfEvaluator2F, PdfEvaluator2F, SfEvaluator2F,
+};
+use crate::utils::interval1f::Interval1F;
+use crate::utils::random1f::Random1F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic1f::Statistic1F;
 
 type Pdf = Continuous2F<PdfEvaluator2F<ChiSquared>>;
 
@@ -71,9 +99,137 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("chi_squared_sf"))
 }
 
+type LnCdf = Continuous2F<LnCdfEvaluator2F<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("chi_squared_ln_cdf"))
+}
+
+type LnSf = Continuous2F<LnSfEvaluator2F<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("chi_squared_ln_sf"))
+}
+
+type Quantile = Continuous2F<InverseCdfEvaluator2F<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("chi_squared_quantile"))
+}
+
+type InverseSf = Continuous2F<InverseSfEvaluator2F<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("chi_squared_inverse_sf"))
+}
+
+type Interval = Interval1F<ChiSquared>;
+
+/// ScalarUDF for the Chi-squared Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("chi_squared_interval"))
+}
+
+type Mean = Statistic1F<MeanStatistic<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("chi_squared_mean"))
+}
+
+type Variance = Statistic1F<VarianceStatistic<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("chi_squared_variance"))
+}
+
+type StdDev = Statistic1F<StdDevStatistic<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("chi_squared_std_dev"))
+}
+
+type Skewness = Statistic1F<SkewnessStatistic<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("chi_squared_skewness"))
+}
+
+type Entropy = Statistic1F<EntropyStatistic<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("chi_squared_entropy"))
+}
+
+type Mode = Statistic1F<ModeStatistic<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("chi_squared_mode"))
+}
+
+type Median = Statistic1F<MedianStatistic<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("chi_squared_median"))
+}
+
+type SupportMin = Statistic1F<SupportMinStatistic<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("chi_squared_support_min"))
+}
+
+type SupportMax = Statistic1F<SupportMaxStatistic<ChiSquared>>;
+
+/// ScalarUDF for the Chi-squared Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("chi_squared_support_max"))
+}
+
+type Random = Random1F<ChiSquared>;
+
+/// ScalarUDF for the Chi-squared Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("chi_squared_random"))
+}
+
 /// Register the functions for the Chi-squared Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -83,7 +239,7 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -134,9 +290,7 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pdf.call(vec![col("x"), col("n")])).alias("q"),
-            ])
+            .select(vec![(pdf.call(vec![col("x"), col("n")])).alias("q")])
             .unwrap()
             .collect()
             .await
@@ -148,8 +302,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.24197072451914295);
         assert_eq_float!(res_col.value(1), 0.08065690817304756);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -162,20 +316,18 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pdf.call(vec![col("x"), col("n")])).alias("q"),
-            ])
+            .select(vec![(pdf.call(vec![col("x"), col("n")])).alias("q")])
             .unwrap()
             .collect()
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<GammaError>().unwrap();
-                assert_eq!(*be.as_ref(), GammaError::ShapeInvalid);
+                assert!(e.to_string().starts_with("chi_squared_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<GammaError>().unwrap();
+                assert_eq!(*be, GammaError::ShapeInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -213,9 +365,7 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pdf.call(vec![col("x"), col("n")])).alias("q"),
-            ])
+            .select(vec![(pdf.call(vec![col("x"), col("n")])).alias("q")])
             .unwrap()
             .collect()
             .await
@@ -227,8 +377,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.1987480430987988);
         assert_eq_float!(res_col.value(1), 0.037434226752703484);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -246,9 +396,7 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pdf.call(vec![col("x"), col("n")])).alias("q"),
-            ])
+            .select(vec![(pdf.call(vec![col("x"), col("n")])).alias("q")])
             .unwrap()
             .collect()
             .await
@@ -260,7 +408,43 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.8012519569012012);
         assert_eq_float!(res_col.value(1), 0.9625657732472965);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn chi_squared_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT chi_squared_quantile(0.95, 3.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 7.814727903251179);
+    }
+
+    #[tokio::test]
+    async fn chi_squared_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT chi_squared_inverse_sf(1e-12, 3.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 58.91975568320215);
     }
 }
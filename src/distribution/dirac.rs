@@ -1,22 +1,40 @@
 //! Module containing functions to the Dirac Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Dirac`].
-//! 
+//!
 //! The [Dirac Distribution](https://en.wikipedia.org/wiki/Dirac_delta_function) has one
 //! parameter:
-//! 
+//!
 //! a: a ∈ R (real numbers)
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `dirac_cdf(x, a)`  
-//! `dirac_sf(x, a)`
-//! 
+//! `dirac_sf(x, a)`  
+//! `dirac_ln_cdf(x, a)`  
+//! `dirac_ln_sf(x, a)`  
+//! `dirac_quantile(p, a)`  
+//! `dirac_inverse_sf(p, a)`  
+//! `dirac_interval(level, a)`  
+//! `dirac_mean(a)`  
+//! `dirac_variance(a)`  
+//! `dirac_std_dev(a)`  
+//! `dirac_skewness(a)`  
+//! `dirac_entropy(a)`  
+//! `dirac_mode(a)`  
+//! `dirac_median(a)`  
+//! `dirac_support_min(a)`  
+//! `dirac_support_max(a)`  
+//! `dirac_random(a[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `x`: (-∞, +∞) `Float64`/`DOUBLE`,  
-//!   `a`: (-∞, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `a`: (-∞, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -35,7 +53,17 @@ use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::Dirac;
 
 use crate::utils::continuous2f::Continuous2F;
-use crate::utils::evaluator2f::{CdfEvaluator2F, SfEvaluator2F};
+use crate::utils::evaluator2f::{
+    CdfEvaluator2F, InverseCdfEvaluator2F, InverseSfEvaluator2F, LnCdfEvaluator2F, LnSfEvaluator2F,
+    SfEvaluator2F,
+};
+use crate::utils::interval1f::Interval1F;
+use crate::utils::random1f::Random1F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic1f::Statistic1F;
 
 type Cdf = Continuous2F<CdfEvaluator2F<Dirac>>;
 
@@ -51,9 +79,135 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("dirac_sf"))
 }
 
+type LnCdf = Continuous2F<LnCdfEvaluator2F<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("dirac_ln_cdf"))
+}
+
+type LnSf = Continuous2F<LnSfEvaluator2F<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("dirac_ln_sf"))
+}
+
+type Quantile = Continuous2F<InverseCdfEvaluator2F<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("dirac_quantile"))
+}
+
+type InverseSf = Continuous2F<InverseSfEvaluator2F<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("dirac_inverse_sf"))
+}
+
+type Interval = Interval1F<Dirac>;
+
+/// ScalarUDF for the Dirac Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("dirac_interval"))
+}
+
+type Mean = Statistic1F<MeanStatistic<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("dirac_mean"))
+}
+
+type Variance = Statistic1F<VarianceStatistic<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("dirac_variance"))
+}
+
+type StdDev = Statistic1F<StdDevStatistic<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("dirac_std_dev"))
+}
+
+type Skewness = Statistic1F<SkewnessStatistic<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("dirac_skewness"))
+}
+
+type Entropy = Statistic1F<EntropyStatistic<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("dirac_entropy"))
+}
+
+type Mode = Statistic1F<ModeStatistic<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("dirac_mode"))
+}
+
+type Median = Statistic1F<MedianStatistic<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("dirac_median"))
+}
+
+type SupportMin = Statistic1F<SupportMinStatistic<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("dirac_support_min"))
+}
+
+type SupportMax = Statistic1F<SupportMaxStatistic<Dirac>>;
+
+/// ScalarUDF for the Dirac Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("dirac_support_max"))
+}
+
+type Random = Random1F<Dirac>;
+
+/// ScalarUDF for the Dirac Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("dirac_random"))
+}
+
 /// Register the functions for the Dirac Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -62,7 +216,7 @@ mod tests {
 
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -111,9 +265,7 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pmf.call(vec![col("x"), col("p")])).alias("q"),
-            ])
+            .select(vec![(pmf.call(vec![col("x"), col("p")])).alias("q")])
             .unwrap()
             .collect()
             .await
@@ -125,8 +277,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq!(res_col.value(0), 0.0);
         assert_eq!(res_col.value(1), 1.0);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -144,9 +296,7 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pmf.call(vec![col("x"), col("p")])).alias("q"),
-            ])
+            .select(vec![(pmf.call(vec![col("x"), col("p")])).alias("q")])
             .unwrap()
             .collect()
             .await
@@ -158,7 +308,43 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq!(res_col.value(0), 1.0);
         assert_eq!(res_col.value(1), 0.0);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn dirac_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT dirac_quantile(0.3, 1.2)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 1.2);
+    }
+
+    #[tokio::test]
+    async fn dirac_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT dirac_inverse_sf(1e-9, 1.2)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 1.2);
     }
 }
@@ -0,0 +1,216 @@
+//! Module containing functions to the Dirichlet Distribution.
+//!
+//! Implemented by [`statrs::distribution::Dirichlet`].
+//!
+//! The [Dirichlet Distribution](https://en.wikipedia.org/wiki/Dirichlet_distribution) has one
+//! parameter, a vector of concentrations:
+//!
+//! α: [α0, α1, ..., αk-1], 0 < αi, 2 ≤ k
+//!
+//! The density is zero off the open simplex, i.e. unless every `xi` is in (0, 1) and the `xi`
+//! sum to one. `x` and `α` must have the same length.
+//!
+//! Usage:
+//!
+//! `dirichlet_pdf(x, α)`  
+//! `dirichlet_ln_pdf(x, α)`
+//!
+//! with
+//!
+//!   `x`: (0, 1)ᵏ `List<Float64>`/`DOUBLE[]`,  
+//!   `α`: (0, +∞)ᵏ `List<Float64>`/`DOUBLE[]`
+//!
+//! Examples
+//! ```
+//! #[tokio::main(flavor = "current_thread")]
+//! async fn main() -> std::io::Result<()> {
+//!     let mut ctx = datafusion::prelude::SessionContext::new();
+//!     datafusion_statrs::distribution::dirichlet::register(&mut ctx)?;
+//!     ctx.sql("SELECT dirichlet_pdf([0.2, 0.3, 0.5], [2.0, 3.0, 4.0])").await?
+//!        .show().await?;
+//!     Ok(())
+//! }
+//! ```
+
+use datafusion::error::DataFusionError;
+use datafusion::execution::FunctionRegistry;
+use datafusion::logical_expr::ScalarUDF;
+use nalgebra::Dyn;
+use statrs::distribution::Dirichlet;
+
+use crate::utils::continuous2l::Continuous2L;
+use crate::utils::evaluator2l::{LnPdfEvaluator2L, PdfEvaluator2L};
+
+type Pdf = Continuous2L<PdfEvaluator2L<Dirichlet<Dyn>>>;
+
+/// ScalarUDF for the Dirichlet PDF
+pub fn pdf() -> ScalarUDF {
+    ScalarUDF::from(Pdf::new("dirichlet_pdf"))
+}
+
+type LnPdf = Continuous2L<LnPdfEvaluator2L<Dirichlet<Dyn>>>;
+
+/// ScalarUDF for the Dirichlet log PDF
+pub fn ln_pdf() -> ScalarUDF {
+    ScalarUDF::from(LnPdf::new("dirichlet_ln_pdf"))
+}
+
+/// Register the functions for the Dirichlet Distribution
+pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
+    crate::utils::register::register(registry, vec![pdf(), ln_pdf()])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert_eq_float::assert_eq_float;
+    use datafusion::{
+        arrow::{
+            array::{Array, ListArray, RecordBatch},
+            datatypes::{DataType, Field, Float64Type, Schema, SchemaRef},
+        },
+        common::cast::as_float64_array,
+        error::DataFusionError,
+        prelude::{SessionContext, col},
+    };
+    use statrs::distribution::DirichletError;
+
+    use super::*;
+
+    fn get_schema() -> SchemaRef {
+        SchemaRef::new(Schema::new(vec![
+            Field::new("x", DataType::new_list(DataType::Float64, true), true),
+            Field::new("a", DataType::new_list(DataType::Float64, true), true),
+        ]))
+    }
+
+    type Row = (Option<Vec<Option<f64>>>, Option<Vec<Option<f64>>>);
+
+    fn make_records(rows: Vec<Row>) -> RecordBatch {
+        let mut xs = Vec::new();
+        let mut alphas = Vec::new();
+        for row in rows {
+            xs.push(row.0);
+            alphas.push(row.1);
+        }
+
+        RecordBatch::try_new(
+            get_schema(),
+            vec![
+                Arc::new(ListArray::from_iter_primitive::<Float64Type, _, _>(xs)),
+                Arc::new(ListArray::from_iter_primitive::<Float64Type, _, _>(alphas)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn dirichlet_pdf_success() {
+        let pdf = pdf();
+
+        let alpha = Some(vec![Some(2.0), Some(3.0), Some(4.0)]);
+        let recs = make_records(vec![
+            (Some(vec![Some(0.2), Some(0.3), Some(0.5)]), alpha.clone()),
+            (Some(vec![Some(0.2), Some(0.3), Some(0.6)]), alpha.clone()),
+            (None, alpha.clone()),
+            (Some(vec![Some(0.2), Some(0.3), Some(0.5)]), None),
+            (Some(vec![Some(0.2), None, Some(0.5)]), alpha),
+        ]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![(pdf.call(vec![col("x"), col("a")])).alias("q")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 5);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 7.56000000000012);
+        assert_eq!(res_col.value(1), 0.0);
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+        assert!(res_col.is_null(4));
+    }
+
+    #[tokio::test]
+    async fn dirichlet_pdf_failure_1() {
+        let pdf = pdf();
+
+        let recs = make_records(vec![(Some(vec![Some(1.0)]), Some(vec![Some(2.0)]))]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![(pdf.call(vec![col("x"), col("a")])).alias("q")])
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("dirichlet_pdf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<DirichletError>()
+                    .unwrap();
+                assert_eq!(*be, DirichletError::AlphaTooShort);
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn dirichlet_pdf_failure_2() {
+        let pdf = pdf();
+
+        let recs = make_records(vec![(
+            Some(vec![Some(0.4), Some(0.6)]),
+            Some(vec![Some(2.0), Some(3.0), Some(4.0)]),
+        )]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![(pdf.call(vec![col("x"), col("a")])).alias("q")])
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::Execution(msg)) => {
+                assert!(msg.contains("same length"));
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn dirichlet_ln_pdf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT dirichlet_ln_pdf([0.2, 0.3, 0.5], [2.0, 3.0, 4.0])")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 2.0228711901914576);
+    }
+}
@@ -0,0 +1,561 @@
+//! Module containing functions to the Discrete Uniform Distribution.
+//!
+//! Implemented by [`statrs::distribution::DiscreteUniform`].
+//!
+//! The [Discrete Uniform Distribution](https://en.wikipedia.org/wiki/Discrete_uniform_distribution) has two
+//! parameters:
+//!
+//! a: a ∈ Z (integers)  
+//! b: a ≤ b
+//!
+//! Usage:
+//!
+//! `discrete_uniform_pmf(x, a, b)`  
+//! `discrete_uniform_ln_pmf(x, a, b)`  
+//! `discrete_uniform_cdf(x, a, b)`  
+//! `discrete_uniform_sf(x, a, b)`  
+//! `discrete_uniform_ln_cdf(x, a, b)`  
+//! `discrete_uniform_ln_sf(x, a, b)`  
+//! `discrete_uniform_quantile(p, a, b)`  
+//! `discrete_uniform_inverse_sf(p, a, b)`  
+//! `discrete_uniform_interval(level, a, b)`  
+//! `discrete_uniform_mean(a, b)`  
+//! `discrete_uniform_variance(a, b)`  
+//! `discrete_uniform_std_dev(a, b)`  
+//! `discrete_uniform_skewness(a, b)`  
+//! `discrete_uniform_entropy(a, b)`  
+//! `discrete_uniform_mode(a, b)`  
+//! `discrete_uniform_median(a, b)`  
+//! `discrete_uniform_support_min(a, b)`  
+//! `discrete_uniform_support_max(a, b)`  
+//! `discrete_uniform_random(a, b[, seed])`
+//!
+//! with
+//!
+//!   `x`: (-∞, +∞) `Int64`/`BIGINT`,  
+//!   `a`: (-∞, +∞) `Int64`/`BIGINT`,  
+//!   `b`: [a, +∞) `Int64`/`BIGINT`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
+//! Examples
+//! ```
+//! #[tokio::main(flavor = "current_thread")]
+//! async fn main() -> std::io::Result<()> {
+//!     let mut ctx = datafusion::prelude::SessionContext::new();
+//!     datafusion_statrs::distribution::discrete_uniform::register(&mut ctx)?;
+//!     ctx.sql("SELECT discrete_uniform_pmf(3, 1, 6)").await?
+//!        .show().await?;
+//!     Ok(())
+//! }
+//! ```
+
+use datafusion::error::DataFusionError;
+use datafusion::execution::FunctionRegistry;
+use datafusion::logical_expr::ScalarUDF;
+use statrs::distribution::DiscreteUniform;
+
+use crate::utils::discrete3i::{Discrete3I, DiscreteInverse3I};
+use crate::utils::evaluator3i::{
+    CdfEvaluator3I, InverseCdfEvaluator3I, InverseSfEvaluator3I, LnCdfEvaluator3I,
+    LnPmfEvaluator3I, LnSfEvaluator3I, PmfEvaluator3I, SfEvaluator3I,
+};
+use crate::utils::interval2i::Interval2I;
+use crate::utils::random2i::Random2I;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic2i::Statistic2I;
+
+type Pmf = Discrete3I<PmfEvaluator3I<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution PMF
+pub fn pmf() -> ScalarUDF {
+    ScalarUDF::from(Pmf::new("discrete_uniform_pmf"))
+}
+
+type LnPmf = Discrete3I<LnPmfEvaluator3I<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution log PMF
+pub fn ln_pmf() -> ScalarUDF {
+    ScalarUDF::from(LnPmf::new("discrete_uniform_ln_pmf"))
+}
+
+type Cdf = Discrete3I<CdfEvaluator3I<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution CDF
+pub fn cdf() -> ScalarUDF {
+    ScalarUDF::from(Cdf::new("discrete_uniform_cdf"))
+}
+
+type Sf = Discrete3I<SfEvaluator3I<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution SF
+pub fn sf() -> ScalarUDF {
+    ScalarUDF::from(Sf::new("discrete_uniform_sf"))
+}
+
+type LnCdf = Discrete3I<LnCdfEvaluator3I<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("discrete_uniform_ln_cdf"))
+}
+
+type LnSf = Discrete3I<LnSfEvaluator3I<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("discrete_uniform_ln_sf"))
+}
+
+type Quantile = DiscreteInverse3I<InverseCdfEvaluator3I<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("discrete_uniform_quantile"))
+}
+
+type InverseSf = DiscreteInverse3I<InverseSfEvaluator3I<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("discrete_uniform_inverse_sf"))
+}
+
+type Interval = Interval2I<DiscreteUniform>;
+
+/// ScalarUDF for the Discrete Uniform Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("discrete_uniform_interval"))
+}
+
+type Mean = Statistic2I<MeanStatistic<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("discrete_uniform_mean"))
+}
+
+type Variance = Statistic2I<VarianceStatistic<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("discrete_uniform_variance"))
+}
+
+type StdDev = Statistic2I<StdDevStatistic<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("discrete_uniform_std_dev"))
+}
+
+type Skewness = Statistic2I<SkewnessStatistic<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("discrete_uniform_skewness"))
+}
+
+type Entropy = Statistic2I<EntropyStatistic<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("discrete_uniform_entropy"))
+}
+
+type Mode = Statistic2I<ModeStatistic<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("discrete_uniform_mode"))
+}
+
+type Median = Statistic2I<MedianStatistic<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("discrete_uniform_median"))
+}
+
+type SupportMin = Statistic2I<SupportMinStatistic<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("discrete_uniform_support_min"))
+}
+
+type SupportMax = Statistic2I<SupportMaxStatistic<DiscreteUniform>>;
+
+/// ScalarUDF for the Discrete Uniform Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("discrete_uniform_support_max"))
+}
+
+type Random = Random2I<DiscreteUniform>;
+
+/// ScalarUDF for the Discrete Uniform Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("discrete_uniform_random"))
+}
+
+/// Register the functions for the Discrete Uniform Distribution
+pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
+    crate::utils::register::register(
+        registry,
+        vec![
+            pmf(),
+            ln_pmf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert_eq_float::assert_eq_float;
+    use datafusion::{
+        arrow::{
+            array::{Array, Int64Array, RecordBatch},
+            datatypes::{DataType, Field, Schema, SchemaRef},
+        },
+        common::cast::{as_float64_array, as_int64_array, as_struct_array},
+        error::DataFusionError,
+        logical_expr::{ColumnarValue, ScalarFunctionArgs},
+        prelude::{SessionContext, col},
+        scalar::ScalarValue,
+    };
+    use statrs::distribution::DiscreteUniformError;
+
+    use super::*;
+
+    fn get_schema() -> SchemaRef {
+        SchemaRef::new(Schema::new(vec![
+            Field::new("x", DataType::Int64, true),
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+        ]))
+    }
+
+    fn make_records(rows: Vec<(Option<i64>, Option<i64>, Option<i64>)>) -> RecordBatch {
+        let mut xs = Vec::new();
+        let mut lows = Vec::new();
+        let mut highs = Vec::new();
+        for row in rows {
+            xs.push(row.0);
+            lows.push(row.1);
+            highs.push(row.2);
+        }
+
+        RecordBatch::try_new(
+            get_schema(),
+            vec![
+                Arc::new(Int64Array::from(xs)),
+                Arc::new(Int64Array::from(lows)),
+                Arc::new(Int64Array::from(highs)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn discrete_uniform_pmf_success() {
+        let pmf = pmf();
+
+        let recs = make_records(vec![
+            (Some(-2), Some(-3), Some(4)),
+            (Some(5), Some(-3), Some(4)),
+            (None, Some(-3), Some(4)),
+            (Some(0), None, Some(4)),
+        ]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![
+                (pmf.call(vec![col("x"), col("a"), col("b")])).alias("q"),
+            ])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 4);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 0.125);
+        assert_eq!(res_col.value(1), 0.0);
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn discrete_uniform_pmf_failure_1() {
+        let pmf = pmf();
+
+        let recs = make_records(vec![(Some(0), Some(4), Some(-3))]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![
+                (pmf.call(vec![col("x"), col("a"), col("b")])).alias("q"),
+            ])
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("discrete_uniform_pmf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<DiscreteUniformError>()
+                    .unwrap();
+                assert_eq!(*be, DiscreteUniformError::MinMaxInvalid);
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn discrete_uniform_pmf_range_too_wide() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT discrete_uniform_pmf(0, -9223372036854775808, 9223372036854775807)")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                let msg = e.to_string();
+                assert!(msg.starts_with("discrete_uniform_pmf: "), "{msg}");
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[test]
+    fn discrete_uniform_pmf_wrong_argument_type() {
+        let res = pmf().invoke_with_args(ScalarFunctionArgs {
+            args: vec![
+                ColumnarValue::Scalar(ScalarValue::Int64(Some(0))),
+                ColumnarValue::Scalar(ScalarValue::Float64(Some(1.0))),
+                ColumnarValue::Scalar(ScalarValue::Int64(Some(6))),
+            ],
+            arg_fields: vec![],
+            number_rows: 1,
+            return_field: Arc::new(Field::new("q", DataType::Float64, true)),
+        });
+        match res {
+            Err(DataFusionError::Execution(msg)) => {
+                assert_eq!(
+                    msg,
+                    "discrete_uniform_pmf: argument 2 must be Int64, got Float64"
+                );
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn discrete_uniform_ln_pmf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT discrete_uniform_ln_pmf(3, 1, 6)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), -1.791759469228055);
+    }
+
+    #[tokio::test]
+    async fn discrete_uniform_cdf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT discrete_uniform_cdf(-1, -3, 4)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.375);
+    }
+
+    #[tokio::test]
+    async fn discrete_uniform_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT discrete_uniform_sf(-1, -3, 4)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.625);
+    }
+
+    #[tokio::test]
+    async fn discrete_uniform_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT discrete_uniform_quantile(p, -3, 4), discrete_uniform_inverse_sf(p, -3, 4) FROM (VALUES (0.0), (0.3), (0.5), (1.0), (1.5)) AS t(p)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 5);
+        let quantile = as_int64_array(res[0].column(0)).unwrap();
+        let inverse_sf = as_int64_array(res[0].column(1)).unwrap();
+        assert_eq!(quantile.values()[..4], [-3, -1, 0, 4]);
+        assert_eq!(inverse_sf.values()[..4], [4, 2, 0, -3]);
+        assert!(quantile.is_null(4));
+        assert!(inverse_sf.is_null(4));
+    }
+
+    #[tokio::test]
+    async fn discrete_uniform_quantile_wide_range() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT discrete_uniform_quantile(0.25, -1000000000000000, 999999999999999), discrete_uniform_inverse_sf(0.25, -1000000000000000, 999999999999999), discrete_uniform_inverse_sf(0.0, -1000000000000000, 999999999999999)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(
+            as_int64_array(res[0].column(0)).unwrap().value(0),
+            -500000000000001
+        );
+        assert_eq!(
+            as_int64_array(res[0].column(1)).unwrap().value(0),
+            499999999999999
+        );
+        assert_eq!(
+            as_int64_array(res[0].column(2)).unwrap().value(0),
+            999999999999999
+        );
+    }
+
+    #[tokio::test]
+    async fn discrete_uniform_interval_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT discrete_uniform_interval(0.75, -3, 4), discrete_uniform_interval(2.0, -3, 4)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        let interval = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq!(
+            as_int64_array(interval.column_by_name("lower").unwrap())
+                .unwrap()
+                .value(0),
+            -3
+        );
+        assert_eq!(
+            as_int64_array(interval.column_by_name("upper").unwrap())
+                .unwrap()
+                .value(0),
+            3
+        );
+        assert!(res[0].column(1).is_null(0));
+    }
+
+    #[tokio::test]
+    async fn discrete_uniform_median_support_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT discrete_uniform_median(-3, 4), discrete_uniform_support_min(-3, 4), discrete_uniform_support_max(-3, 4)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 3);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(as_int64_array(res[0].column(0)).unwrap().value(0), 0);
+        assert_eq!(as_int64_array(res[0].column(1)).unwrap().value(0), -3);
+        assert_eq!(as_int64_array(res[0].column(2)).unwrap().value(0), 4);
+    }
+
+    #[tokio::test]
+    async fn discrete_uniform_random_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT min(r), max(r) FROM (SELECT discrete_uniform_random(-2, 2) AS r FROM generate_series(1, 1000))")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(as_int64_array(res[0].column(0)).unwrap().value(0), -2);
+        assert_eq!(as_int64_array(res[0].column(1)).unwrap().value(0), 2);
+    }
+}
@@ -0,0 +1,330 @@
+//! Module containing functions to the Empirical Distribution.
+//!
+//! Follows [`statrs::distribution::Empirical`].
+//!
+//! The [Empirical Distribution](https://en.wikipedia.org/wiki/Empirical_distribution_function) of a
+//! sample puts equal mass on each observation. The aggregate `empirical_dist` collects a column into
+//! a state, the sorted sample as a `List<Float64>`, skipping NULLs and NaNs. The scalar functions
+//! evaluate the distribution described by such a state, or by any list of values, which
+//! is sorted first if it is not already. An empty sample gives NULL.
+//!
+//! Usage:
+//!
+//! `empirical_dist(x)` (aggregate)  
+//! `empirical_cdf(state, x)`  
+//! `empirical_sf(state, x)`  
+//! `empirical_ln_cdf(state, x)`  
+//! `empirical_ln_sf(state, x)`
+//!
+//! with
+//!
+//!   `x`: (-∞, +∞) `Float64`/`DOUBLE`,  
+//!   `state`: the result of `empirical_dist` `List<Float64>`/`DOUBLE[]`
+//!
+//! Examples
+//! ```
+//! #[tokio::main(flavor = "current_thread")]
+//! async fn main() -> std::io::Result<()> {
+//!     let mut ctx = datafusion::prelude::SessionContext::new();
+//!     datafusion_statrs::distribution::empirical::register(&mut ctx)?;
+//!     ctx.sql("SELECT empirical_cdf(empirical_dist(x), 2.5)
+//!              FROM (VALUES (1.0), (2.0), (3.0), (4.0)) AS t(x)").await?
+//!        .show().await?;
+//!     Ok(())
+//! }
+//! ```
+
+use datafusion::error::DataFusionError;
+use datafusion::execution::FunctionRegistry;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
+
+use crate::utils::empirical::{
+    CdfEvaluatorEmpirical, Empirical, EmpiricalDist, LnCdfEvaluatorEmpirical,
+    LnSfEvaluatorEmpirical, SfEvaluatorEmpirical,
+};
+
+/// AggregateUDF building the Empirical Distribution of a column
+pub fn dist() -> AggregateUDF {
+    AggregateUDF::from(EmpiricalDist::new("empirical_dist"))
+}
+
+type Cdf = Empirical<CdfEvaluatorEmpirical>;
+
+/// ScalarUDF for the Empirical CDF
+pub fn cdf() -> ScalarUDF {
+    ScalarUDF::from(Cdf::new("empirical_cdf"))
+}
+
+type Sf = Empirical<SfEvaluatorEmpirical>;
+
+/// ScalarUDF for the Empirical SF
+pub fn sf() -> ScalarUDF {
+    ScalarUDF::from(Sf::new("empirical_sf"))
+}
+
+type LnCdf = Empirical<LnCdfEvaluatorEmpirical>;
+
+/// ScalarUDF for the Empirical log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("empirical_ln_cdf"))
+}
+
+type LnSf = Empirical<LnSfEvaluatorEmpirical>;
+
+/// ScalarUDF for the Empirical log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("empirical_ln_sf"))
+}
+
+/// Register the functions for the Empirical Distribution
+pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
+    crate::utils::register::register_aggregates(registry, vec![dist()])?;
+    crate::utils::register::register(registry, vec![cdf(), sf(), ln_cdf(), ln_sf()])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert_eq_float::assert_eq_float;
+    use datafusion::{
+        arrow::{
+            array::{Array, Float64Array, RecordBatch},
+            datatypes::{DataType, Field, Schema, SchemaRef},
+        },
+        common::cast::{as_float64_array, as_list_array},
+        prelude::SessionContext,
+    };
+    use statrs::distribution::ContinuousCDF;
+
+    use super::*;
+
+    fn make_records(name: &str, xs: Vec<Option<f64>>) -> RecordBatch {
+        RecordBatch::try_new(
+            SchemaRef::new(Schema::new(vec![Field::new(name, DataType::Float64, true)])),
+            vec![Arc::new(Float64Array::from(xs))],
+        )
+        .unwrap()
+    }
+
+    fn make_context() -> SessionContext {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let reference = make_records(
+            "x",
+            vec![
+                Some(3.0),
+                Some(1.0),
+                None,
+                Some(4.0),
+                Some(1.0),
+                Some(f64::NAN),
+                Some(5.0),
+            ],
+        );
+        ctx.register_batch("reference", reference).unwrap();
+        let observed = make_records("y", vec![Some(0.0), Some(1.0), Some(3.5), Some(9.0), None]);
+        ctx.register_batch("observed", observed).unwrap();
+        ctx
+    }
+
+    #[tokio::test]
+    async fn empirical_dist_success() {
+        let ctx = make_context();
+        let res = ctx
+            .sql("SELECT empirical_dist(x) FROM reference")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_list_array(res[0].column(0)).unwrap();
+        let sample = res_col.value(0);
+        let sample = as_float64_array(&sample).unwrap();
+        assert_eq!(sample.values().as_ref(), &[1.0, 1.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[tokio::test]
+    async fn empirical_dist_integer() {
+        let ctx = make_context();
+        let res = ctx
+            .sql("SELECT empirical_dist(x) FROM (VALUES (3), (1), (2)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let res_col = as_list_array(res[0].column(0)).unwrap();
+        let sample = res_col.value(0);
+        let sample = as_float64_array(&sample).unwrap();
+        assert_eq!(sample.values().as_ref(), &[1.0, 2.0, 3.0]);
+    }
+
+    #[tokio::test]
+    async fn empirical_unsorted_list() {
+        let ctx = make_context();
+        let res = ctx
+            .sql(
+                "SELECT empirical_cdf([3.0, 1.0, 4.0, 1.0, 5.0], y),
+                        empirical_sf([3.0, 1.0, 4.0, 1.0, 5.0], y)
+                 FROM observed",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res[0].num_rows(), 5);
+        let cdf = as_float64_array(res[0].column(0)).unwrap();
+        let sf = as_float64_array(res[0].column(1)).unwrap();
+
+        let empirical = statrs::distribution::Empirical::from_iter([3.0, 1.0, 4.0, 1.0, 5.0]);
+        for (i, y) in [0.0, 1.0, 3.5, 9.0].into_iter().enumerate() {
+            assert_eq_float!(cdf.value(i), empirical.cdf(y));
+            assert_eq_float!(sf.value(i), empirical.sf(y));
+        }
+        assert!(cdf.is_null(4));
+        assert!(sf.is_null(4));
+    }
+
+    #[tokio::test]
+    async fn empirical_list_column() {
+        let ctx = make_context();
+        let res = ctx
+            .sql(
+                "SELECT empirical_cdf(s, 2.0)
+                 FROM (VALUES ([3.0, 1.0]), ([3.0, 1.0]), ([1.0, 2.0, 5.0, 0.0]), ([3.0, 1.0])) AS t(s)",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res[0].num_rows(), 4);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.5);
+        assert_eq_float!(res_col.value(1), 0.5);
+        assert_eq_float!(res_col.value(2), 0.75);
+        assert_eq_float!(res_col.value(3), 0.5);
+    }
+
+    #[tokio::test]
+    async fn empirical_joined_state() {
+        let ctx = make_context();
+        let res = ctx
+            .sql(
+                "SELECT empirical_cdf(d, y)
+                 FROM observed CROSS JOIN (SELECT empirical_dist(x) AS d FROM reference)",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res[0].num_rows(), 5);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+
+        let empirical = statrs::distribution::Empirical::from_iter([3.0, 1.0, 4.0, 1.0, 5.0]);
+        for (i, y) in [0.0, 1.0, 3.5, 9.0].into_iter().enumerate() {
+            assert_eq_float!(res_col.value(i), empirical.cdf(y));
+        }
+        assert!(res_col.is_null(4));
+    }
+
+    #[tokio::test]
+    async fn empirical_empty_sample() {
+        let ctx = make_context();
+        let res = ctx
+            .sql(
+                "SELECT empirical_cdf((SELECT empirical_dist(x) FROM reference WHERE x > 100.0), y),
+                        empirical_sf([], y)
+                 FROM observed",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res[0].num_rows(), 5);
+        for column in res[0].columns() {
+            assert_eq!(column.null_count(), 5);
+        }
+    }
+
+    #[tokio::test]
+    async fn empirical_cdf_success() {
+        let ctx = make_context();
+        let res = ctx
+            .sql(
+                "SELECT empirical_cdf((SELECT empirical_dist(x) FROM reference), y)
+                 FROM observed",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 5);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+
+        let empirical = statrs::distribution::Empirical::from_iter([3.0, 1.0, 4.0, 1.0, 5.0]);
+        assert_eq_float!(res_col.value(0), empirical.cdf(0.0));
+        assert_eq_float!(res_col.value(1), empirical.cdf(1.0));
+        assert_eq_float!(res_col.value(2), empirical.cdf(3.5));
+        assert_eq_float!(res_col.value(3), empirical.cdf(9.0));
+        assert!(res_col.is_null(4));
+    }
+
+    #[tokio::test]
+    async fn empirical_sf_success() {
+        let ctx = make_context();
+        let res = ctx
+            .sql(
+                "SELECT empirical_sf((SELECT empirical_dist(x) FROM reference), y)
+                 FROM observed",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 5);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.0);
+        assert_eq_float!(res_col.value(1), 0.6);
+        assert_eq_float!(res_col.value(2), 0.4);
+        assert_eq_float!(res_col.value(3), 0.0);
+        assert!(res_col.is_null(4));
+    }
+
+    #[tokio::test]
+    async fn empirical_ln_cdf_success() {
+        let ctx = make_context();
+        let res = ctx
+            .sql(
+                "SELECT empirical_ln_cdf((SELECT empirical_dist(x) FROM reference), y)
+                 FROM observed",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 5);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), f64::NEG_INFINITY);
+        assert_eq_float!(res_col.value(1), -0.916290731874155);
+        assert_eq_float!(res_col.value(2), -0.5108256237659907);
+        assert_eq_float!(res_col.value(3), 0.0);
+        assert!(res_col.is_null(4));
+    }
+}
@@ -1,33 +1,51 @@
 //! Module containing functions to the Erlang Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Erlang`].
-//! 
+//!
 //! The [Erlang Distribution](https://en.wikipedia.org/wiki/Erlang_distribution) has two
 //! parameters:
-//! 
+//!
 //! k: k ∈ N (natural numbers)  
 //! λ: 0 < λ
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `erlang_pdf(x, k, λ)`  
 //! `erlang_ln_pdf(x, k, λ)`  
 //! `erlang_cdf(x, k, λ)`  
-//! `erlang_sf(x, k, λ)`
-//! 
+//! `erlang_sf(x, k, λ)`  
+//! `erlang_ln_cdf(x, k, λ)`  
+//! `erlang_ln_sf(x, k, λ)`  
+//! `erlang_quantile(p, k, λ)`  
+//! `erlang_inverse_sf(p, k, λ)`  
+//! `erlang_interval(level, k, λ)`  
+//! `erlang_mean(k, λ)`  
+//! `erlang_variance(k, λ)`  
+//! `erlang_std_dev(k, λ)`  
+//! `erlang_skewness(k, λ)`  
+//! `erlang_entropy(k, λ)`  
+//! `erlang_mode(k, λ)`  
+//! `erlang_median(k, λ)`  
+//! `erlang_support_min(k, λ)`  
+//! `erlang_support_max(k, λ)`  
+//! `erlang_random(k, λ[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `x`: [0, +∞) `Float64`/`DOUBLE`,  
 //!   `k`: (-∞, +∞) `UInt64`/`BIGINT UNSIGNED`,  
-//!   `λ`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `λ`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
 //! async fn main() -> std::io::Result<()> {
 //!     let mut ctx = datafusion::prelude::SessionContext::new();
 //!     datafusion_statrs::distribution::erlang::register(&mut ctx)?;
-//!     ctx.sql("SELECT erlang_cdf(1.0, CAST(5 AS BIGINT UNSIGNED), 2.0)").await?
+//!     ctx.sql("SELECT erlang_cdf(1.0, 5, 2.0)").await?
 //!        .show().await?;
 //!     Ok(())
 //! }
@@ -39,7 +57,17 @@ use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::Erlang;
 
 use crate::utils::continuous1f1u1f::Continuous1F1U1F;
-use crate::utils::evaluator1f1u1f::{CdfEvaluator1F1U1F, LnPdfEvaluator1F1U1F, PdfEvaluator1F1U1F, SfEvaluator1F1U1F};
+use crate::utils::evaluator1f1u1f::{
+    CdfEvaluator1F1U1F, InverseCdfEvaluator1F1U1F, InverseSfEvaluator1F1U1F, LnCdfEvaluator1F1U1F,
+    LnPdfEvaluator1F1U1F, LnSfEvaluator1F1U1F, PdfEvaluator1F1U1F, SfEvaluator1F1U1F,
+};
+use crate::utils::interval1u1f::Interval1U1F;
+use crate::utils::random1u1f::Random1U1F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic1u1f::Statistic1U1F;
 
 type Pdf = Continuous1F1U1F<PdfEvaluator1F1U1F<Erlang>>;
 
@@ -69,9 +97,137 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("erlang_sf"))
 }
 
+type LnCdf = Continuous1F1U1F<LnCdfEvaluator1F1U1F<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("erlang_ln_cdf"))
+}
+
+type LnSf = Continuous1F1U1F<LnSfEvaluator1F1U1F<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("erlang_ln_sf"))
+}
+
+type Quantile = Continuous1F1U1F<InverseCdfEvaluator1F1U1F<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("erlang_quantile"))
+}
+
+type InverseSf = Continuous1F1U1F<InverseSfEvaluator1F1U1F<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("erlang_inverse_sf"))
+}
+
+type Interval = Interval1U1F<Erlang>;
+
+/// ScalarUDF for the Erlang Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("erlang_interval"))
+}
+
+type Mean = Statistic1U1F<MeanStatistic<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("erlang_mean"))
+}
+
+type Variance = Statistic1U1F<VarianceStatistic<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("erlang_variance"))
+}
+
+type StdDev = Statistic1U1F<StdDevStatistic<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("erlang_std_dev"))
+}
+
+type Skewness = Statistic1U1F<SkewnessStatistic<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("erlang_skewness"))
+}
+
+type Entropy = Statistic1U1F<EntropyStatistic<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("erlang_entropy"))
+}
+
+type Mode = Statistic1U1F<ModeStatistic<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("erlang_mode"))
+}
+
+type Median = Statistic1U1F<MedianStatistic<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("erlang_median"))
+}
+
+type SupportMin = Statistic1U1F<SupportMinStatistic<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("erlang_support_min"))
+}
+
+type SupportMax = Statistic1U1F<SupportMaxStatistic<Erlang>>;
+
+/// ScalarUDF for the Erlang Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("erlang_support_max"))
+}
+
+type Random = Random1U1F<Erlang>;
+
+/// ScalarUDF for the Erlang Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("erlang_random"))
+}
+
 /// Register the functions for the Erlang Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -81,7 +237,7 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch, UInt64Array},
+            array::{Array, Float64Array, RecordBatch, UInt64Array},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -150,8 +306,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.006084381117745331);
         assert_eq_float!(res_col.value(1), 0.018954083116019732);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -172,12 +328,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<GammaError>().unwrap();
-                assert_eq!(*be.as_ref(), GammaError::ShapeInvalid);
+                assert!(e.to_string().starts_with("erlang_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<GammaError>().unwrap();
+                assert_eq!(*be, GammaError::ShapeInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -229,8 +385,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.0021614966897625073);
         assert_eq_float!(res_col.value(1), 0.014387677966970639);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -262,7 +418,60 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.9978385033102375);
         assert_eq_float!(res_col.value(1), 0.9856123220330294);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn erlang_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT erlang_quantile(0.5, CAST(2 AS BIGINT UNSIGNED), 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.6783469900166608);
+    }
+
+    #[tokio::test]
+    async fn erlang_quantile_small_p() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT erlang_quantile(1e-40, CAST(2 AS BIGINT UNSIGNED), 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0) / 1.414213562373095e-20, 1.0, 1e-9);
+    }
+
+    #[tokio::test]
+    async fn erlang_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT erlang_inverse_sf(1e-15, CAST(2 AS BIGINT UNSIGNED), 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 38.20764823059948);
     }
 }
@@ -1,24 +1,45 @@
 //! Module containing functions to the Exponential Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Exp`].
-//! 
+//!
 //! The [Exponential Distribution](https://en.wikipedia.org/wiki/Exponential_distribution) has one
 //! parameter:
-//! 
+//!
 //! λ: 0 < λ
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `exp_pdf(x, λ)`  
 //! `exp_ln_pdf(x, λ)`  
 //! `exp_cdf(x, λ)`  
-//! `exp_sf(x, λ)`
-//! 
+//! `exp_sf(x, λ)`  
+//! `exp_ln_cdf(x, λ)`  
+//! `exp_ln_sf(x, λ)`  
+//! `exp_hazard(x, λ)`  
+//! `exp_cum_hazard(x, λ)`  
+//! `exp_quantile(p, λ)`  
+//! `exp_inverse_sf(p, λ)`  
+//! `exp_interval(level, λ)`  
+//! `exp_mean(λ)`  
+//! `exp_variance(λ)`  
+//! `exp_std_dev(λ)`  
+//! `exp_skewness(λ)`  
+//! `exp_entropy(λ)`  
+//! `exp_mode(λ)`  
+//! `exp_median(λ)`  
+//! `exp_support_min(λ)`  
+//! `exp_support_max(λ)`  
+//! `exp_random(λ[, seed])`  
+//! `exp_fit(x)` (aggregate)
+//!
 //! with
-//! 
+//!
 //!   `x`: [0, +∞) `Float64`/`DOUBLE`,  
-//!   `λ`: (0, +∞) `Float64`/`DOUBLE`,
-//! 
+//!   `λ`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -33,11 +54,23 @@
 
 use datafusion::error::DataFusionError;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
 use statrs::distribution::Exp;
 
 use crate::utils::continuous2f::Continuous2F;
-use crate::utils::evaluator2f::{CdfEvaluator2F, LnPdfEvaluator2F, PdfEvaluator2F, SfEvaluator2F};
+use crate::utils::evaluator2f::{
+    CdfEvaluator2F, CumHazardEvaluator2F, HazardEvaluator2F, InverseCdfEvaluator2F,
+    InverseSfEvaluator2F, LnCdfEvaluator2F, LnPdfEvaluator2F, LnSfEvaluator2F, PdfEvaluator2F,
+    SfEvaluator2F,
+};
+use crate::utils::fit::FitAggregate;
+use crate::utils::interval1f::Interval1F;
+use crate::utils::random1f::Random1F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic1f::Statistic1F;
 
 type Pdf = Continuous2F<PdfEvaluator2F<Exp>>;
 
@@ -67,9 +100,161 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("exp_sf"))
 }
 
+type LnCdf = Continuous2F<LnCdfEvaluator2F<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("exp_ln_cdf"))
+}
+
+type LnSf = Continuous2F<LnSfEvaluator2F<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("exp_ln_sf"))
+}
+
+type Hazard = Continuous2F<HazardEvaluator2F<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution hazard
+pub fn hazard() -> ScalarUDF {
+    ScalarUDF::from(Hazard::new("exp_hazard"))
+}
+
+type CumHazard = Continuous2F<CumHazardEvaluator2F<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution cumulative hazard
+pub fn cum_hazard() -> ScalarUDF {
+    ScalarUDF::from(CumHazard::new("exp_cum_hazard"))
+}
+
+type Quantile = Continuous2F<InverseCdfEvaluator2F<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("exp_quantile"))
+}
+
+type InverseSf = Continuous2F<InverseSfEvaluator2F<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("exp_inverse_sf"))
+}
+
+type Interval = Interval1F<Exp>;
+
+/// ScalarUDF for the Exponential Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("exp_interval"))
+}
+
+type Mean = Statistic1F<MeanStatistic<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("exp_mean"))
+}
+
+type Variance = Statistic1F<VarianceStatistic<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("exp_variance"))
+}
+
+type StdDev = Statistic1F<StdDevStatistic<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("exp_std_dev"))
+}
+
+type Skewness = Statistic1F<SkewnessStatistic<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("exp_skewness"))
+}
+
+type Entropy = Statistic1F<EntropyStatistic<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("exp_entropy"))
+}
+
+type Mode = Statistic1F<ModeStatistic<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("exp_mode"))
+}
+
+type Median = Statistic1F<MedianStatistic<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("exp_median"))
+}
+
+type SupportMin = Statistic1F<SupportMinStatistic<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("exp_support_min"))
+}
+
+type SupportMax = Statistic1F<SupportMaxStatistic<Exp>>;
+
+/// ScalarUDF for the Exponential Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("exp_support_max"))
+}
+
+type Random = Random1F<Exp>;
+
+/// ScalarUDF for the Exponential Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("exp_random"))
+}
+
+type Fit = FitAggregate<Exp>;
+
+/// AggregateUDF for the Exponential Distribution maximum likelihood fit
+pub fn fit() -> AggregateUDF {
+    AggregateUDF::from(Fit::new("exp_fit"))
+}
+
 /// Register the functions for the Exponential Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register_aggregates(registry, vec![fit()])?;
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            hazard(),
+            cum_hazard(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -79,10 +264,10 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
-        common::cast::as_float64_array,
+        common::cast::{as_float64_array, as_struct_array, as_uint64_array},
         error::DataFusionError,
         prelude::{SessionContext, col},
     };
@@ -130,9 +315,7 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pdf.call(vec![col("x"), col("p")])).alias("q"),
-            ])
+            .select(vec![(pdf.call(vec![col("x"), col("p")])).alias("q")])
             .unwrap()
             .collect()
             .await
@@ -144,8 +327,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.19470019576785122);
         assert_eq_float!(res_col.value(1), 0.15163266492815836);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -158,20 +341,18 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pdf.call(vec![col("x"), col("p")])).alias("q"),
-            ])
+            .select(vec![(pdf.call(vec![col("x"), col("p")])).alias("q")])
             .unwrap()
             .collect()
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<ExpError>().unwrap();
-                assert_eq!(*be.as_ref(), ExpError::RateInvalid);
+                assert!(e.to_string().starts_with("exp_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<ExpError>().unwrap();
+                assert_eq!(*be, ExpError::RateInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -209,9 +390,7 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pdf.call(vec![col("x"), col("p")])).alias("q"),
-            ])
+            .select(vec![(pdf.call(vec![col("x"), col("p")])).alias("q")])
             .unwrap()
             .collect()
             .await
@@ -223,8 +402,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.22119921692859512);
         assert_eq_float!(res_col.value(1), 0.3934693402873666);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -242,9 +421,7 @@ mod tests {
         ctx.register_batch("tbl", recs).unwrap();
         let df = ctx.table("tbl").await.unwrap();
         let res = df
-            .select(vec![
-                (pdf.call(vec![col("x"), col("p")])).alias("q"),
-            ])
+            .select(vec![(pdf.call(vec![col("x"), col("p")])).alias("q")])
             .unwrap()
             .collect()
             .await
@@ -256,7 +433,145 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.7788007830714049);
         assert_eq_float!(res_col.value(1), 0.6065306597126334);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn exp_ln_cdf_ln_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT exp_ln_sf(1000.0, 1.5), exp_ln_cdf(1.0, 1.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(
+            as_float64_array(res[0].column(0)).unwrap().value(0),
+            -1500.0
+        );
+        assert_eq_float!(
+            as_float64_array(res[0].column(1)).unwrap().value(0),
+            -0.25248245892545396
+        );
+    }
+
+    #[tokio::test]
+    async fn exp_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT exp_quantile(0.5, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.34657359027997264);
+    }
+
+    #[tokio::test]
+    async fn exp_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT exp_inverse_sf(1e-20, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 23.025850929940457);
+    }
+
+    #[tokio::test]
+    async fn exp_hazard_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT exp_hazard(1000.0, 1.5), exp_cum_hazard(1000.0, 1.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(as_float64_array(res[0].column(0)).unwrap().value(0), 1.5);
+        assert_eq_float!(as_float64_array(res[0].column(1)).unwrap().value(0), 1500.0);
+    }
+
+    #[tokio::test]
+    async fn exp_fit_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT exp_fit(x) FROM (VALUES (0.5), (1.2), (2.3), (3.1), (4.8), (0.9), (1.7)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("rate").unwrap())
+                .unwrap()
+                .value(0),
+            0.4827586206896552
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+                .unwrap()
+                .value(0),
+            -12.097669502598508
+        );
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            7
+        );
+    }
+
+    #[tokio::test]
+    async fn exp_fit_integer() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT exp_fit(x) FROM (VALUES (1), (2), (3)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("rate").unwrap())
+                .unwrap()
+                .value(0),
+            0.5
+        );
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            3
+        );
     }
 }
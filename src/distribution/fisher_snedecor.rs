@@ -1,26 +1,44 @@
 //! Module containing functions to the Fisher-Snedecor (aka F) Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::FisherSnedecor`].
-//! 
+//!
 //! The [Fisher-Snedecor Distribution](https://en.wikipedia.org/wiki/F-distribution) has two
 //! parameters:
-//! 
+//!
 //! d1: 0 < d1
 //! d2: 0 < d2
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `fisher_snedecor_pdf(x, d1, d2)`  
 //! `fisher_snedecor_log_pdf(x, d1, d2)`  
 //! `fisher_snedecor_cdf(x, d1, d2)`  
-//! `fisher_snedecor_sf(x, d1, d2)`
-//! 
+//! `fisher_snedecor_sf(x, d1, d2)`  
+//! `fisher_snedecor_ln_cdf(x, d1, d2)`  
+//! `fisher_snedecor_ln_sf(x, d1, d2)`  
+//! `fisher_snedecor_quantile(p, d1, d2)`  
+//! `fisher_snedecor_inverse_sf(p, d1, d2)`  
+//! `fisher_snedecor_interval(level, d1, d2)`  
+//! `fisher_snedecor_mean(d1, d2)`  
+//! `fisher_snedecor_variance(d1, d2)`  
+//! `fisher_snedecor_std_dev(d1, d2)`  
+//! `fisher_snedecor_skewness(d1, d2)`  
+//! `fisher_snedecor_entropy(d1, d2)`  
+//! `fisher_snedecor_mode(d1, d2)`  
+//! `fisher_snedecor_median(d1, d2)`  
+//! `fisher_snedecor_support_min(d1, d2)`  
+//! `fisher_snedecor_support_max(d1, d2)`  
+//! `fisher_snedecor_random(d1, d2[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `x`: [0, +∞) `Float64`/`DOUBLE`,  
 //!   `d1`: (0, +∞) `Float64`/`DOUBLE`,  
-//!   `d2`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `d2`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -39,7 +57,17 @@ use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::FisherSnedecor;
 
 use crate::utils::continuous3f::Continuous3F;
-use crate::utils::evaluator3f::{CdfEvaluator3F, LnPdfEvaluator3F, PdfEvaluator3F, SfEvaluator3F};
+use crate::utils::evaluator3f::{
+    CdfEvaluator3F, InverseCdfEvaluator3F, InverseSfEvaluator3F, LnCdfEvaluator3F,
+    LnPdfEvaluator3F, LnSfEvaluator3F, PdfEvaluator3F, SfEvaluator3F,
+};
+use crate::utils::interval2f::Interval2F;
+use crate::utils::random2f::Random2F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic2f::Statistic2F;
 
 type Pdf = Continuous3F<PdfEvaluator3F<FisherSnedecor>>;
 
@@ -69,9 +97,137 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("fisher_snedecor_sf"))
 }
 
+type LnCdf = Continuous3F<LnCdfEvaluator3F<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("fisher_snedecor_ln_cdf"))
+}
+
+type LnSf = Continuous3F<LnSfEvaluator3F<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("fisher_snedecor_ln_sf"))
+}
+
+type Quantile = Continuous3F<InverseCdfEvaluator3F<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("fisher_snedecor_quantile"))
+}
+
+type InverseSf = Continuous3F<InverseSfEvaluator3F<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("fisher_snedecor_inverse_sf"))
+}
+
+type Interval = Interval2F<FisherSnedecor>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("fisher_snedecor_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("fisher_snedecor_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("fisher_snedecor_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("fisher_snedecor_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("fisher_snedecor_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("fisher_snedecor_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("fisher_snedecor_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("fisher_snedecor_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("fisher_snedecor_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<FisherSnedecor>>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("fisher_snedecor_support_max"))
+}
+
+type Random = Random2F<FisherSnedecor>;
+
+/// ScalarUDF for the Fisher-Snedecor Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("fisher_snedecor_random"))
+}
+
 /// Register the functions for the Fisher-Snedecor Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -81,7 +237,7 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -150,8 +306,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq!(res_col.value(0), 0.0);
         assert_eq_float!(res_col.value(1), 0.08642373027968221);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -172,12 +328,16 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<FisherSnedecorError>().unwrap();
-                assert_eq!(*be.as_ref(), FisherSnedecorError::Freedom2Invalid);
+                assert!(e.to_string().starts_with("fisher_snedecor_pdf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<FisherSnedecorError>()
+                    .unwrap();
+                assert_eq!(*be, FisherSnedecorError::Freedom2Invalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -229,8 +389,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.0);
         assert_eq_float!(res_col.value(1), 0.22377660964255752);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -262,7 +422,43 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 1.0);
         assert_eq_float!(res_col.value(1), 0.7762233903574425);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn fisher_snedecor_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT fisher_snedecor_quantile(0.95, 5.0, 10.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 3.3258345304130046);
+    }
+
+    #[tokio::test]
+    async fn fisher_snedecor_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT fisher_snedecor_inverse_sf(1e-10, 5.0, 10.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 324.7584619713444);
     }
 }
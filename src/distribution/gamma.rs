@@ -1,28 +1,49 @@
 //! Module containing functions to the Gamma Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Gamma`].
-//! 
+//!
 //! The [Gamma Distribution](https://en.wikipedia.org/wiki/Gamma_distribution) has two
 //! parameters:
-//! 
+//!
 //! α: 0 < α (shape)  
 //! λ: 0 < λ (rate)
-//! 
+//!
 //! NB There are two parameterisations of Gamma (α, θ) and (α, λ) with λ = 1/θ.
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `gamma_pdf(x, α, λ)`  
 //! `gamma_ln_pdf(x, α, λ)`  
 //! `gamma_cdf(x, α, λ)`  
-//! `gamma_sf(x, α, λ)`
-//! 
+//! `gamma_sf(x, α, λ)`  
+//! `gamma_ln_cdf(x, α, λ)`  
+//! `gamma_ln_sf(x, α, λ)`  
+//! `gamma_hazard(x, α, λ)`  
+//! `gamma_cum_hazard(x, α, λ)`  
+//! `gamma_quantile(p, α, λ)`  
+//! `gamma_inverse_sf(p, α, λ)`  
+//! `gamma_interval(level, α, λ)`  
+//! `gamma_mean(α, λ)`  
+//! `gamma_variance(α, λ)`  
+//! `gamma_std_dev(α, λ)`  
+//! `gamma_skewness(α, λ)`  
+//! `gamma_entropy(α, λ)`  
+//! `gamma_mode(α, λ)`  
+//! `gamma_median(α, λ)`  
+//! `gamma_support_min(α, λ)`  
+//! `gamma_support_max(α, λ)`  
+//! `gamma_random(α, λ[, seed])`  
+//! `gamma_fit(x)` (aggregate)
+//!
 //! with
-//! 
+//!
 //!   `x`: [0, +∞) `Float64`/`DOUBLE`,  
 //!   `α`: (0, +∞) `Float64`/`DOUBLE`,  
-//!   `λ`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `λ`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -37,11 +58,23 @@
 
 use datafusion::error::DataFusionError;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
 use statrs::distribution::Gamma;
 
 use super::super::utils::continuous3f::Continuous3F;
-use super::super::utils::evaluator3f::{CdfEvaluator3F, LnPdfEvaluator3F, PdfEvaluator3F, SfEvaluator3F};
+use super::super::utils::evaluator3f::{
+    CdfEvaluator3F, CumHazardEvaluator3F, HazardEvaluator3F, InverseCdfEvaluator3F,
+    InverseSfEvaluator3F, LnCdfEvaluator3F, LnPdfEvaluator3F, LnSfEvaluator3F, PdfEvaluator3F,
+    SfEvaluator3F,
+};
+use super::super::utils::fit::FitAggregate;
+use super::super::utils::interval2f::Interval2F;
+use super::super::utils::random2f::Random2F;
+use super::super::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use super::super::utils::statistic2f::Statistic2F;
 
 type Pdf = Continuous3F<PdfEvaluator3F<Gamma>>;
 
@@ -71,9 +104,161 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("gamma_sf"))
 }
 
+type LnCdf = Continuous3F<LnCdfEvaluator3F<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("gamma_ln_cdf"))
+}
+
+type LnSf = Continuous3F<LnSfEvaluator3F<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("gamma_ln_sf"))
+}
+
+type Hazard = Continuous3F<HazardEvaluator3F<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution hazard
+pub fn hazard() -> ScalarUDF {
+    ScalarUDF::from(Hazard::new("gamma_hazard"))
+}
+
+type CumHazard = Continuous3F<CumHazardEvaluator3F<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution cumulative hazard
+pub fn cum_hazard() -> ScalarUDF {
+    ScalarUDF::from(CumHazard::new("gamma_cum_hazard"))
+}
+
+type Quantile = Continuous3F<InverseCdfEvaluator3F<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("gamma_quantile"))
+}
+
+type InverseSf = Continuous3F<InverseSfEvaluator3F<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("gamma_inverse_sf"))
+}
+
+type Interval = Interval2F<Gamma>;
+
+/// ScalarUDF for the Gamma Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("gamma_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("gamma_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("gamma_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("gamma_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("gamma_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("gamma_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("gamma_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("gamma_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("gamma_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<Gamma>>;
+
+/// ScalarUDF for the Gamma Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("gamma_support_max"))
+}
+
+type Random = Random2F<Gamma>;
+
+/// ScalarUDF for the Gamma Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("gamma_random"))
+}
+
+type Fit = FitAggregate<Gamma>;
+
+/// AggregateUDF for the Gamma Distribution maximum likelihood fit
+pub fn fit() -> AggregateUDF {
+    AggregateUDF::from(Fit::new("gamma_fit"))
+}
+
 /// Register the functions for the Gamma Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register_aggregates(registry, vec![fit()])?;
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            hazard(),
+            cum_hazard(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -83,12 +268,12 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
-        common::cast::as_float64_array,
+        common::cast::{as_float64_array, as_struct_array, as_uint64_array},
         error::DataFusionError,
-        prelude::{SessionContext, col},
+        prelude::{SessionConfig, SessionContext, col},
     };
     use statrs::distribution::GammaError;
 
@@ -152,8 +337,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.006084381117745331);
         assert_eq_float!(res_col.value(1), 0.018954083116019732);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -174,12 +359,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<GammaError>().unwrap();
-                assert_eq!(*be.as_ref(), GammaError::ShapeInvalid);
+                assert!(e.to_string().starts_with("gamma_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<GammaError>().unwrap();
+                assert_eq!(*be, GammaError::ShapeInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -230,9 +415,9 @@ mod tests {
         assert_eq!(res[0].num_rows(), 4);
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.0021614966897625073);
-        assert_eq_float!(res_col.value(1),  0.014387677966970639);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert_eq_float!(res_col.value(1), 0.014387677966970639);
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -264,7 +449,225 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.9978385033102375);
         assert_eq_float!(res_col.value(1), 0.9856123220330294);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn gamma_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT gamma_quantile(0.5, 2.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.6783469900166608);
+    }
+
+    #[tokio::test]
+    async fn gamma_quantile_small_shape() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT gamma_quantile(0.5, 0.001, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0) / 5.244206408277903e-302, 1.0, 1e-9);
+    }
+
+    #[tokio::test]
+    async fn gamma_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT gamma_inverse_sf(1e-15, 2.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 38.20764823059948);
+    }
+
+    #[tokio::test]
+    async fn gamma_variance_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT gamma_variance(2.0, 0.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 8.0);
+    }
+
+    #[tokio::test]
+    async fn gamma_median_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT gamma_median(2.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.6783469900166608);
+    }
+
+    #[tokio::test]
+    async fn gamma_hazard_tail() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT gamma_hazard(1000.0, 2.0, 1.5), gamma_cum_hazard(1000.0, 2.0, 1.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(
+            as_float64_array(res[0].column(0)).unwrap().value(0),
+            1.4990006662226454
+        );
+        assert_eq_float!(
+            as_float64_array(res[0].column(1)).unwrap().value(0),
+            1492.6861131683665
+        );
+    }
+
+    #[tokio::test]
+    async fn gamma_fit_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT gamma_fit(x) FROM (VALUES (0.5), (1.2), (2.3), (3.1), (4.8), (0.9), (1.7)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("shape").unwrap())
+                .unwrap()
+                .value(0),
+            2.273525267339914
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("rate").unwrap())
+                .unwrap()
+                .value(0),
+            1.0975639221640965
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+                .unwrap()
+                .value(0),
+            -11.012280425339627
+        );
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            7
+        );
+    }
+
+    #[tokio::test]
+    async fn gamma_fit_groups() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT g, gamma_cdf(2.0, f['shape'], f['rate']) AS p FROM (
+                    SELECT g, gamma_fit(x) AS f
+                    FROM (VALUES (1, 1.5), (1, 2.5), (1, NULL), (2, -1.0), (2, 3.0), (3, 2.0), (3, 2.0)) AS t(g, x)
+                    GROUP BY g
+                  ) ORDER BY g")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 3);
+        let p = as_float64_array(res[0].column(1)).unwrap();
+        assert!(p.value(0) > 0.5 && p.value(0) < 0.6);
+        assert!(p.is_null(1));
+        assert!(p.is_null(2));
+    }
+
+    /// The shape and rate fitted to `1 + value % 7` over a thousand rows in `partitions`,
+    /// with `value` 500 replaced by `bad` if given.
+    async fn gamma_fit_partitions(partitions: usize, bad: Option<f64>) -> Option<(f64, f64)> {
+        let config = SessionConfig::new()
+            .with_target_partitions(partitions)
+            .with_batch_size(100);
+        let mut ctx = SessionContext::new_with_config(config);
+        register(&mut ctx).unwrap();
+        let bad = bad.map_or(String::from("NULL"), |v| format!("{v:?}"));
+        let res = ctx
+            .sql(&format!(
+                "SELECT gamma_fit(CASE WHEN value = 500 THEN {bad} ELSE CAST(1 + value % 7 AS DOUBLE) END) FROM generate_series(1, 1000)"
+            ))
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        if fit.is_null(0) {
+            return None;
+        }
+        let param = |name| {
+            as_float64_array(fit.column_by_name(name).unwrap())
+                .unwrap()
+                .value(0)
+        };
+        Some((param("shape"), param("rate")))
+    }
+
+    #[tokio::test]
+    async fn gamma_fit_partitioned() {
+        let (shape, rate) = gamma_fit_partitions(1, None).await.unwrap();
+        let (shape_4, rate_4) = gamma_fit_partitions(4, None).await.unwrap();
+        // The partitions may add up the sums in any order.
+        assert_eq_float!(shape_4, shape, 1e-9);
+        assert_eq_float!(rate_4, rate, 1e-9);
+
+        assert!(gamma_fit_partitions(4, Some(0.0)).await.is_none());
+        assert!(gamma_fit_partitions(4, Some(-2.0)).await.is_none());
     }
 }
@@ -1,35 +1,54 @@
 //! Module containing functions to the Geometric Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Geometric`].
-//! 
+//!
 //! The [Geometric Distribution](https://en.wikipedia.org/wiki/Geometric_distribution) has one
 //! parameter:
-//! 
+//!
 //! p: (0, 1]
-//! 
+//!
 //! Note there are two interpretations of the geometric distribution: x is the number of Bernoulli
 //! trials to get one success; or the number of failures before the first success. This implementation
 //! provides the former.
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `geometric_pmf(x, p)`  
 //! `geometric_ln_pmf(x, p)`  
 //! `geometric_cdf(x, p)`  
-//! `geometric_sf(x, p)`
-//! 
+//! `geometric_sf(x, p)`  
+//! `geometric_ln_cdf(x, p)`  
+//! `geometric_ln_sf(x, p)`  
+//! `geometric_quantile(q, p)`  
+//! `geometric_inverse_sf(q, p)`  
+//! `geometric_interval(level, p)`  
+//! `geometric_mean(p)`  
+//! `geometric_variance(p)`  
+//! `geometric_std_dev(p)`  
+//! `geometric_skewness(p)`  
+//! `geometric_entropy(p)`  
+//! `geometric_mode(p)`  
+//! `geometric_median(p)`  
+//! `geometric_support_min(p)`  
+//! `geometric_support_max(p)`  
+//! `geometric_random(p[, seed])`  
+//! `geometric_fit(x)` (aggregate)
+//!
 //! with
-//! 
+//!
 //!   `x`: (1, +∞) `UInt64`/`BIGINT UNSIGNED`,  
-//!   `p`: (0, 1] `Float64`/`DOUBLE`
-//! 
+//!   `p`: (0, 1] `Float64`/`DOUBLE`,  
+//!   `q`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
 //! async fn main() -> std::io::Result<()> {
 //!     let mut ctx = datafusion::prelude::SessionContext::new();
-//!     datafusion_statrs::distribution::chi::register(&mut ctx)?;
-//!     ctx.sql("SELECT chi_pdf(1.25, CAST(4 AS BIGINT UNSIGNED))").await?
+//!     datafusion_statrs::distribution::geometric::register(&mut ctx)?;
+//!     ctx.sql("SELECT geometric_pmf(3, 0.25)").await?
 //!        .show().await?;
 //!     Ok(())
 //! }
@@ -37,11 +56,22 @@
 
 use datafusion::error::DataFusionError;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
 use statrs::distribution::Geometric;
 
-use super::super::utils::discrete1u1f::Discrete1U1F;
-use super::super::utils::evaluator1u1f::{CdfEvaluator1U1F, LnPmfEvaluator1U1F, PmfEvaluator1U1F, SfEvaluator1U1F};
+use super::super::utils::discrete1u1f::{Discrete1U1F, DiscreteInverse1U1F};
+use super::super::utils::evaluator1u1f::{
+    CdfEvaluator1U1F, InverseCdfEvaluator1U1F, InverseSfEvaluator1U1F, LnCdfEvaluator1U1F,
+    LnPmfEvaluator1U1F, LnSfEvaluator1U1F, PmfEvaluator1U1F, SfEvaluator1U1F,
+};
+use super::super::utils::fit::CountFitAggregate;
+use super::super::utils::interval1f::Interval1F;
+use super::super::utils::random1f::Random1F;
+use super::super::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use super::super::utils::statistic1f::Statistic1F;
 
 type Pmf = Discrete1U1F<PmfEvaluator1U1F<Geometric>>;
 
@@ -71,9 +101,145 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("geometric_sf"))
 }
 
+type LnCdf = Discrete1U1F<LnCdfEvaluator1U1F<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("geometric_ln_cdf"))
+}
+
+type LnSf = Discrete1U1F<LnSfEvaluator1U1F<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("geometric_ln_sf"))
+}
+
+type Quantile = DiscreteInverse1U1F<InverseCdfEvaluator1U1F<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("geometric_quantile"))
+}
+
+type InverseSf = DiscreteInverse1U1F<InverseSfEvaluator1U1F<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("geometric_inverse_sf"))
+}
+
+type Interval = Interval1F<Geometric>;
+
+/// ScalarUDF for the Geometric Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("geometric_interval"))
+}
+
+type Mean = Statistic1F<MeanStatistic<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("geometric_mean"))
+}
+
+type Variance = Statistic1F<VarianceStatistic<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("geometric_variance"))
+}
+
+type StdDev = Statistic1F<StdDevStatistic<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("geometric_std_dev"))
+}
+
+type Skewness = Statistic1F<SkewnessStatistic<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("geometric_skewness"))
+}
+
+type Entropy = Statistic1F<EntropyStatistic<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("geometric_entropy"))
+}
+
+type Mode = Statistic1F<ModeStatistic<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("geometric_mode"))
+}
+
+type Median = Statistic1F<MedianStatistic<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("geometric_median"))
+}
+
+type SupportMin = Statistic1F<SupportMinStatistic<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("geometric_support_min"))
+}
+
+type SupportMax = Statistic1F<SupportMaxStatistic<Geometric>>;
+
+/// ScalarUDF for the Geometric Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("geometric_support_max"))
+}
+
+type Random = Random1F<Geometric>;
+
+/// ScalarUDF for the Geometric Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("geometric_random"))
+}
+
+type Fit = CountFitAggregate<Geometric>;
+
+/// AggregateUDF for the Geometric Distribution maximum likelihood fit
+pub fn fit() -> AggregateUDF {
+    AggregateUDF::from(Fit::new("geometric_fit"))
+}
+
 /// Register the functions for the Geometric Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pmf(), ln_pmf(), cdf(), sf()])
+    crate::utils::register::register_aggregates(registry, vec![fit()])?;
+    crate::utils::register::register(
+        registry,
+        vec![
+            pmf(),
+            ln_pmf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -83,10 +249,10 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch, UInt64Array},
+            array::{Array, Float64Array, RecordBatch, UInt64Array},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
-        common::cast::as_float64_array,
+        common::cast::{as_float64_array, as_struct_array, as_uint64_array},
         error::DataFusionError,
         prelude::{SessionContext, col},
     };
@@ -146,8 +312,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.0);
         assert_eq_float!(res_col.value(1), 0.0791015625);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -166,12 +332,16 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<GeometricError>().unwrap();
-                assert_eq!(*be.as_ref(), GeometricError::ProbabilityInvalid);
+                assert!(e.to_string().starts_with("geometric_pmf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<GeometricError>()
+                    .unwrap();
+                assert_eq!(*be, GeometricError::ProbabilityInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -221,8 +391,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.0);
         assert_eq_float!(res_col.value(1), 0.7626953124999999);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -252,7 +422,149 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 1.0);
         assert_eq_float!(res_col.value(1), 0.23730468750000006);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn geometric_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT geometric_quantile(0.5, 0.2)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 4);
+    }
+
+    #[tokio::test]
+    async fn geometric_quantile_one() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT geometric_quantile(1.0, 0.5), geometric_support_max(0.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(
+            as_uint64_array(res[0].column(0)).unwrap().value(0),
+            u64::MAX
+        );
+        assert_eq!(
+            as_uint64_array(res[0].column(1)).unwrap().value(0),
+            u64::MAX
+        );
+    }
+
+    #[tokio::test]
+    async fn geometric_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT geometric_inverse_sf(1e-12, 0.2)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 124);
+    }
+
+    #[tokio::test]
+    async fn geometric_inverse_sf_zero() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT geometric_inverse_sf(0.0, 0.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(
+            as_uint64_array(res[0].column(0)).unwrap().value(0),
+            u64::MAX
+        );
+    }
+
+    #[tokio::test]
+    async fn geometric_median_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT geometric_median(0.5), geometric_median(0.3), geometric_median(1.0), geometric_quantile(0.5, 0.3)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(as_uint64_array(res[0].column(0)).unwrap().value(0), 1);
+        assert_eq!(as_uint64_array(res[0].column(1)).unwrap().value(0), 2);
+        assert_eq!(as_uint64_array(res[0].column(2)).unwrap().value(0), 1);
+        assert_eq!(as_uint64_array(res[0].column(3)).unwrap().value(0), 2);
+    }
+
+    #[tokio::test]
+    async fn geometric_fit_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT geometric_fit(x) FROM (VALUES (1), (3), (2), (1), (5), (2)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("p").unwrap())
+                .unwrap()
+                .value(0),
+            0.42857142857142855
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+                .unwrap()
+                .value(0),
+            -9.560713465806603
+        );
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            6
+        );
+
+        let res = ctx
+            .sql("SELECT geometric_fit(x) FROM (VALUES (1), (0), (2)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert!(res[0].column(0).is_null(0));
     }
 }
@@ -1,26 +1,46 @@
 //! Module containing functions to the Gumbel Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Gumbel`].
-//! 
+//!
 //! The [Gumbel Distribution](https://en.wikipedia.org/wiki/Gumbel_distribution) has two
 //! parameters:
-//! 
+//!
 //! μ: μ ∈ R (real numbers)
 //! β: 0 < β
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `gumbel_pdf(x, μ, β)`  
 //! `gumbel_ln_pdf(x, μ, β)`  
 //! `gumbel_cdf(x, μ, β)`  
-//! `gumbel_sf(x, μ, β)`
-//! 
+//! `gumbel_sf(x, μ, β)`  
+//! `gumbel_ln_cdf(x, μ, β)`  
+//! `gumbel_ln_sf(x, μ, β)`  
+//! `gumbel_hazard(x, μ, β)`  
+//! `gumbel_cum_hazard(x, μ, β)`  
+//! `gumbel_quantile(p, μ, β)`  
+//! `gumbel_inverse_sf(p, μ, β)`  
+//! `gumbel_interval(level, μ, β)`  
+//! `gumbel_mean(μ, β)`  
+//! `gumbel_variance(μ, β)`  
+//! `gumbel_std_dev(μ, β)`  
+//! `gumbel_skewness(μ, β)`  
+//! `gumbel_entropy(μ, β)`  
+//! `gumbel_mode(μ, β)`  
+//! `gumbel_median(μ, β)`  
+//! `gumbel_support_min(μ, β)`  
+//! `gumbel_support_max(μ, β)`  
+//! `gumbel_random(μ, β[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `x`: (-∞, +∞) `Float64`/`DOUBLE`,  
 //!   `μ`: (-∞, +∞) `Float64`/`DOUBLE`,  
-//!   `β`: (0, +∞) `Float64`/`DOUBLE`,
-//! 
+//!   `β`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -39,7 +59,18 @@ use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::Gumbel;
 
 use super::super::utils::continuous3f::Continuous3F;
-use super::super::utils::evaluator3f::{CdfEvaluator3F, LnPdfEvaluator3F, PdfEvaluator3F, SfEvaluator3F};
+use super::super::utils::evaluator3f::{
+    CdfEvaluator3F, CumHazardEvaluator3F, HazardEvaluator3F, InverseCdfEvaluator3F,
+    InverseSfEvaluator3F, LnCdfEvaluator3F, LnPdfEvaluator3F, LnSfEvaluator3F, PdfEvaluator3F,
+    SfEvaluator3F,
+};
+use super::super::utils::interval2f::Interval2F;
+use super::super::utils::random2f::Random2F;
+use super::super::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use super::super::utils::statistic2f::Statistic2F;
 
 type Pdf = Continuous3F<PdfEvaluator3F<Gumbel>>;
 
@@ -69,9 +100,153 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("gumbel_sf"))
 }
 
+type LnCdf = Continuous3F<LnCdfEvaluator3F<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("gumbel_ln_cdf"))
+}
+
+type LnSf = Continuous3F<LnSfEvaluator3F<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("gumbel_ln_sf"))
+}
+
+type Hazard = Continuous3F<HazardEvaluator3F<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution hazard
+pub fn hazard() -> ScalarUDF {
+    ScalarUDF::from(Hazard::new("gumbel_hazard"))
+}
+
+type CumHazard = Continuous3F<CumHazardEvaluator3F<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution cumulative hazard
+pub fn cum_hazard() -> ScalarUDF {
+    ScalarUDF::from(CumHazard::new("gumbel_cum_hazard"))
+}
+
+type Quantile = Continuous3F<InverseCdfEvaluator3F<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("gumbel_quantile"))
+}
+
+type InverseSf = Continuous3F<InverseSfEvaluator3F<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("gumbel_inverse_sf"))
+}
+
+type Interval = Interval2F<Gumbel>;
+
+/// ScalarUDF for the Gumbel Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("gumbel_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("gumbel_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("gumbel_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("gumbel_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("gumbel_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("gumbel_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("gumbel_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("gumbel_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("gumbel_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<Gumbel>>;
+
+/// ScalarUDF for the Gumbel Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("gumbel_support_max"))
+}
+
+type Random = Random2F<Gumbel>;
+
+/// ScalarUDF for the Gumbel Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("gumbel_random"))
+}
+
 /// Register the functions for the Gumbel Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            hazard(),
+            cum_hazard(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -81,7 +256,7 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -150,8 +325,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.0);
         assert_eq_float!(res_col.value(1), 0.0);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -172,12 +347,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<GumbelError>().unwrap();
-                assert_eq!(*be.as_ref(), GumbelError::ScaleInvalid);
+                assert!(e.to_string().starts_with("gumbel_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<GumbelError>().unwrap();
+                assert_eq!(*be, GumbelError::ScaleInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -229,8 +404,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.0);
         assert_eq_float!(res_col.value(1), 0.0);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -262,7 +437,89 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 1.0);
         assert_eq_float!(res_col.value(1), 1.0);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn gumbel_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT gumbel_quantile(0.5, 0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.36651292058166435);
+    }
+
+    #[tokio::test]
+    async fn gumbel_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT gumbel_inverse_sf(1e-20, 0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 46.051701859880914);
+    }
+
+    #[tokio::test]
+    async fn gumbel_hazard_tail() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT gumbel_hazard(2000.0, 0.0, 2.0), gumbel_cum_hazard(2000.0, 0.0, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(as_float64_array(res[0].column(0)).unwrap().value(0), 0.5);
+        assert_eq_float!(as_float64_array(res[0].column(1)).unwrap().value(0), 1000.0);
+    }
+
+    #[tokio::test]
+    async fn gumbel_hazard_nan() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT gumbel_hazard(CAST('NaN' AS DOUBLE), 0.0, 2.0), gumbel_cum_hazard(CAST('NaN' AS DOUBLE), 0.0, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert!(
+            as_float64_array(res[0].column(0))
+                .unwrap()
+                .value(0)
+                .is_nan()
+        );
+        assert!(
+            as_float64_array(res[0].column(1))
+                .unwrap()
+                .value(0)
+                .is_nan()
+        );
     }
 }
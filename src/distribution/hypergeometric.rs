@@ -1,42 +1,57 @@
 //! Module containing functions to the Hypergeometric Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Hypergeometric`].
-//! 
+//!
 //! The [Hypergeometric Distribution](https://en.wikipedia.org/wiki/Hypergeometric_distribution) has three
 //! parameters:
-//! 
+//!
 //!  N: {0, 1, 2, ...}  
 //!  K: {0, 1, 2, ..., N}  
 //!  n: {0, 1, 2, ..., N}  
-//! 
+//!
 //! Note there are two interpretations of the geometric distribution: x is the number of Bernoulli
 //! trials to get one success; or the number of failures before the first success. This implementation
 //! provides the former.
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `hypergeometric_pmf(k, N, K, n)`  
 //! `hypergeometric_ln_pmf(x, N, K, n)`  
 //! `hypergeometric_cdf(x, N, K, n)`  
-//! `hypergeometric_sf(x, N, K, n)`
-//! 
+//! `hypergeometric_sf(x, N, K, n)`  
+//! `hypergeometric_ln_cdf(x, N, K, n)`  
+//! `hypergeometric_ln_sf(x, N, K, n)`  
+//! `hypergeometric_quantile(q, N, K, n)`  
+//! `hypergeometric_inverse_sf(q, N, K, n)`  
+//! `hypergeometric_interval(level, N, K, n)`  
+//! `hypergeometric_mean(N, K, n)`  
+//! `hypergeometric_variance(N, K, n)`  
+//! `hypergeometric_std_dev(N, K, n)`  
+//! `hypergeometric_skewness(N, K, n)`  
+//! `hypergeometric_entropy(N, K, n)`  
+//! `hypergeometric_mode(N, K, n)`  
+//! `hypergeometric_median(N, K, n)`  
+//! `hypergeometric_support_min(N, K, n)`  
+//! `hypergeometric_support_max(N, K, n)`  
+//! `hypergeometric_random(N, K, n[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `k`: [max(0, n + K - N), min(n, K)] `UInt64`/`BIGINT UNSIGNED`,  
 //!   `N`: [0, +∞) `UInt64`/`BIGINT UNSIGNED`,  
 //!   `K`: [0, N] `UInt64`/`BIGINT UNSIGNED`,  
-//!   `n`: [0, N] `UInt64`/`BIGINT UNSIGNED`
-//! 
+//!   `n`: [0, N] `UInt64`/`BIGINT UNSIGNED`,  
+//!   `q`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
 //! async fn main() -> std::io::Result<()> {
 //!     let mut ctx = datafusion::prelude::SessionContext::new();
 //!     datafusion_statrs::distribution::hypergeometric::register(&mut ctx)?;
-//!     ctx.sql("SELECT hypergeometric_ln_pmf(CAST(25 AS BIGINT UNSIGNED),
-//!                                           CAST(500 AS BIGINT UNSIGNED),
-//!                                           CAST(50 AS BIGINT UNSIGNED),
-//!                                           CAST(100 AS BIGINT UNSIGNED))").await?
+//!     ctx.sql("SELECT hypergeometric_ln_pmf(25, 500, 50, 100)").await?
 //!        .show().await?;
 //!     Ok(())
 //! }
@@ -47,8 +62,18 @@ use datafusion::execution::FunctionRegistry;
 use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::Hypergeometric;
 
-use crate::utils::discrete4u::Discrete4U;
-use crate::utils::evaluator4u::{CdfEvaluator4U, LnPmfEvaluator4U, PmfEvaluator4U, SfEvaluator4U};
+use crate::utils::discrete4u::{Discrete4U, DiscreteInverse4U};
+use crate::utils::evaluator4u::{
+    CdfEvaluator4U, InverseCdfEvaluator4U, InverseSfEvaluator4U, LnCdfEvaluator4U,
+    LnPmfEvaluator4U, LnSfEvaluator4U, PmfEvaluator4U, SfEvaluator4U,
+};
+use crate::utils::interval3u::Interval3U;
+use crate::utils::random3u::Random3U;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic3u::Statistic3U;
 
 type Pmf = Discrete4U<PmfEvaluator4U<Hypergeometric>>;
 
@@ -78,10 +103,137 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("hypergeometric_sf"))
 }
 
+type LnCdf = Discrete4U<LnCdfEvaluator4U<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("hypergeometric_ln_cdf"))
+}
+
+type LnSf = Discrete4U<LnSfEvaluator4U<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("hypergeometric_ln_sf"))
+}
+
+type Quantile = DiscreteInverse4U<InverseCdfEvaluator4U<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("hypergeometric_quantile"))
+}
+
+type InverseSf = DiscreteInverse4U<InverseSfEvaluator4U<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("hypergeometric_inverse_sf"))
+}
+
+type Interval = Interval3U<Hypergeometric>;
+
+/// ScalarUDF for the Hypergeometric Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("hypergeometric_interval"))
+}
+
+type Mean = Statistic3U<MeanStatistic<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("hypergeometric_mean"))
+}
+
+type Variance = Statistic3U<VarianceStatistic<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("hypergeometric_variance"))
+}
+
+type StdDev = Statistic3U<StdDevStatistic<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("hypergeometric_std_dev"))
+}
+
+type Skewness = Statistic3U<SkewnessStatistic<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("hypergeometric_skewness"))
+}
+
+type Entropy = Statistic3U<EntropyStatistic<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("hypergeometric_entropy"))
+}
+
+type Mode = Statistic3U<ModeStatistic<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("hypergeometric_mode"))
+}
+
+type Median = Statistic3U<MedianStatistic<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("hypergeometric_median"))
+}
+
+type SupportMin = Statistic3U<SupportMinStatistic<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("hypergeometric_support_min"))
+}
+
+type SupportMax = Statistic3U<SupportMaxStatistic<Hypergeometric>>;
+
+/// ScalarUDF for the Hypergeometric Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("hypergeometric_support_max"))
+}
+
+type Random = Random3U<Hypergeometric>;
+
+/// ScalarUDF for the Hypergeometric Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("hypergeometric_random"))
+}
 
 /// Register the functions for the Hypergeometric Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pmf(), ln_pmf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pmf(),
+            ln_pmf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -91,10 +243,10 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{RecordBatch, UInt64Array},
+            array::{Array, RecordBatch, UInt64Array},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
-        common::cast::as_float64_array,
+        common::cast::{as_float64_array, as_uint64_array},
         error::DataFusionError,
         prelude::{SessionContext, col},
     };
@@ -111,9 +263,9 @@ mod tests {
         ]))
     }
 
-    fn make_records(
-        rows: Vec<(Option<u64>, Option<u64>, Option<u64>, Option<u64>)>,
-    ) -> RecordBatch {
+    type Row = (Option<u64>, Option<u64>, Option<u64>, Option<u64>);
+
+    fn make_records(rows: Vec<Row>) -> RecordBatch {
         let mut xs = Vec::new();
         let mut ps = Vec::new();
         let mut ss = Vec::new();
@@ -166,8 +318,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.0);
         assert_eq_float!(res_col.value(1), 0.016253869969040248);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -188,12 +340,16 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<HypergeometricError>().unwrap();
-                assert_eq!(*be.as_ref(), HypergeometricError::TooManySuccesses);
+                assert!(e.to_string().starts_with("hypergeometric_pmf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<HypergeometricError>()
+                    .unwrap();
+                assert_eq!(*be, HypergeometricError::TooManySuccesses);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -245,8 +401,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.0);
         assert_eq_float!(res_col.value(1), 0.01625386996904021);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -278,7 +434,43 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 1.0);
         assert_eq_float!(res_col.value(1), 0.9837461300309583);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn hypergeometric_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT hypergeometric_quantile(0.95, CAST(500 AS BIGINT UNSIGNED), CAST(50 AS BIGINT UNSIGNED), CAST(100 AS BIGINT UNSIGNED))")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 15);
+    }
+
+    #[tokio::test]
+    async fn hypergeometric_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT hypergeometric_inverse_sf(1e-10, CAST(500 AS BIGINT UNSIGNED), CAST(50 AS BIGINT UNSIGNED), CAST(100 AS BIGINT UNSIGNED))")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 29);
     }
 }
@@ -1,26 +1,44 @@
 //! Module containing functions to the Inverse Gamma Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::InverseGamma`].
-//! 
+//!
 //! The [Inverse Gamma Distribution](https://en.wikipedia.org/wiki/Inverse-gamma_distribution) has two
 //! parameters:
-//! 
+//!
 //! α: 0 < α (shape)
 //! λ: 0 < λ (rate)
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `inverse_gamma_pdf(x, α, λ)`  
 //! `inverse_gamma_ln_pdf(x, α, λ)`  
 //! `inverse_gamma_cdf(x, α, λ)`  
-//! `inverse_gamma_sf(x, α, λ)`
-//! 
+//! `inverse_gamma_sf(x, α, λ)`  
+//! `inverse_gamma_ln_cdf(x, α, λ)`  
+//! `inverse_gamma_ln_sf(x, α, λ)`  
+//! `inverse_gamma_quantile(p, α, λ)`  
+//! `inverse_gamma_inverse_sf(p, α, λ)`  
+//! `inverse_gamma_interval(level, α, λ)`  
+//! `inverse_gamma_mean(α, λ)`  
+//! `inverse_gamma_variance(α, λ)`  
+//! `inverse_gamma_std_dev(α, λ)`  
+//! `inverse_gamma_skewness(α, λ)`  
+//! `inverse_gamma_entropy(α, λ)`  
+//! `inverse_gamma_mode(α, λ)`  
+//! `inverse_gamma_median(α, λ)`  
+//! `inverse_gamma_support_min(α, λ)`  
+//! `inverse_gamma_support_max(α, λ)`  
+//! `inverse_gamma_random(α, λ[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `x`: [0, +∞) `Float64`/`DOUBLE`,  
 //!   `α`: (0, +∞) `Float64`/`DOUBLE`,  
-//!   `λ`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `λ`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -39,7 +57,17 @@ use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::InverseGamma;
 
 use crate::utils::continuous3f::Continuous3F;
-use crate::utils::evaluator3f::{CdfEvaluator3F, LnPdfEvaluator3F, PdfEvaluator3F, SfEvaluator3F};
+use crate::utils::evaluator3f::{
+    CdfEvaluator3F, InverseCdfEvaluator3F, InverseSfEvaluator3F, LnCdfEvaluator3F,
+    LnPdfEvaluator3F, LnSfEvaluator3F, PdfEvaluator3F, SfEvaluator3F,
+};
+use crate::utils::interval2f::Interval2F;
+use crate::utils::random2f::Random2F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic2f::Statistic2F;
 
 type Pdf = Continuous3F<PdfEvaluator3F<InverseGamma>>;
 
@@ -69,9 +97,137 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("inverse_gamma_sf"))
 }
 
+type LnCdf = Continuous3F<LnCdfEvaluator3F<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("inverse_gamma_ln_cdf"))
+}
+
+type LnSf = Continuous3F<LnSfEvaluator3F<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("inverse_gamma_ln_sf"))
+}
+
+type Quantile = Continuous3F<InverseCdfEvaluator3F<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("inverse_gamma_quantile"))
+}
+
+type InverseSf = Continuous3F<InverseSfEvaluator3F<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("inverse_gamma_inverse_sf"))
+}
+
+type Interval = Interval2F<InverseGamma>;
+
+/// ScalarUDF for the Inverse Gamma Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("inverse_gamma_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("inverse_gamma_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("inverse_gamma_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("inverse_gamma_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("inverse_gamma_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("inverse_gamma_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("inverse_gamma_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("inverse_gamma_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("inverse_gamma_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<InverseGamma>>;
+
+/// ScalarUDF for the Inverse Gamma Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("inverse_gamma_support_max"))
+}
+
+type Random = Random2F<InverseGamma>;
+
+/// ScalarUDF for the Inverse Gamma Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("inverse_gamma_random"))
+}
+
 /// Register the functions for the Inverse-Gamma Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -81,7 +237,7 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -150,8 +306,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.006084381117745331);
         assert_eq_float!(res_col.value(1), 0.0004309066907151331);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -172,12 +328,16 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<InverseGammaError>().unwrap();
-                assert_eq!(*be.as_ref(), InverseGammaError::ShapeInvalid);
+                assert!(e.to_string().starts_with("inverse_gamma_pdf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<InverseGammaError>()
+                    .unwrap();
+                assert_eq!(*be, InverseGammaError::ShapeInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -228,9 +388,9 @@ mod tests {
         assert_eq!(res[0].num_rows(), 4);
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.9978385033102375);
-        assert_eq_float!(res_col.value(1),  0.999703522459112);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert_eq_float!(res_col.value(1), 0.999703522459112);
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -262,7 +422,65 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.0021614966897625073);
         assert_eq_float!(res_col.value(1), 0.00029647754088801934);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn inverse_gamma_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT inverse_gamma_quantile(0.5, 3.0, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.7479262863802223);
+    }
+
+    #[tokio::test]
+    async fn inverse_gamma_quantile_small_p() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT inverse_gamma_quantile(1e-12, 3.0, 2.0) AS x, inverse_gamma_cdf(inverse_gamma_quantile(1e-12, 3.0, 2.0), 3.0, 2.0) AS p")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(
+            as_float64_array(res[0].column(0)).unwrap().value(0),
+            0.058733056,
+            1e-9
+        );
+        assert!((as_float64_array(res[0].column(1)).unwrap().value(0) / 1e-12 - 1.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn inverse_gamma_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT inverse_gamma_inverse_sf(1e-10, 3.0, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 2370.762160811859);
     }
 }
@@ -1,28 +1,46 @@
 //! Module containing functions to the Laplace Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Laplace`].
-//! 
+//!
 //! The [Laplace Distribution](https://en.wikipedia.org/wiki/Laplace_distribution) has two
 //! parameters:
-//! 
+//!
 //! μ: μ ∈ R (real numbers)
 //! b: 0 < b
-//! 
+//!
 //! NB There are two parameterisations of Laplace (α, θ) and (α, λ) with λ = 1/θ.
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `laplace_pdf(x, μ, b)`  
 //! `laplace_ln_pdf(x, μ, b)`  
 //! `laplace_cdf(x, μ, b)`  
-//! `laplace_sf(x, μ, b)`
-//! 
+//! `laplace_sf(x, μ, b)`  
+//! `laplace_ln_cdf(x, μ, b)`  
+//! `laplace_ln_sf(x, μ, b)`  
+//! `laplace_quantile(p, μ, b)`  
+//! `laplace_inverse_sf(p, μ, b)`  
+//! `laplace_interval(level, μ, b)`  
+//! `laplace_mean(μ, b)`  
+//! `laplace_variance(μ, b)`  
+//! `laplace_std_dev(μ, b)`  
+//! `laplace_skewness(μ, b)`  
+//! `laplace_entropy(μ, b)`  
+//! `laplace_mode(μ, b)`  
+//! `laplace_median(μ, b)`  
+//! `laplace_support_min(μ, b)`  
+//! `laplace_support_max(μ, b)`  
+//! `laplace_random(μ, b[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `x`: [0, +∞) `Float64`/`DOUBLE`,  
 //!   `μ`: (-∞, +∞) `Float64`/`DOUBLE`,  
-//!   `b`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `b`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -40,9 +58,18 @@ use datafusion::execution::FunctionRegistry;
 use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::Laplace;
 
-
 use crate::utils::continuous3f::Continuous3F;
-use crate::utils::evaluator3f::{CdfEvaluator3F, LnPdfEvaluator3F, PdfEvaluator3F, SfEvaluator3F};
+use crate::utils::evaluator3f::{
+    CdfEvaluator3F, InverseCdfEvaluator3F, InverseSfEvaluator3F, LnCdfEvaluator3F,
+    LnPdfEvaluator3F, LnSfEvaluator3F, PdfEvaluator3F, SfEvaluator3F,
+};
+use crate::utils::interval2f::Interval2F;
+use crate::utils::random2f::Random2F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic2f::Statistic2F;
 
 type Pdf = Continuous3F<PdfEvaluator3F<Laplace>>;
 
@@ -72,9 +99,137 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("laplace_sf"))
 }
 
+type LnCdf = Continuous3F<LnCdfEvaluator3F<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("laplace_ln_cdf"))
+}
+
+type LnSf = Continuous3F<LnSfEvaluator3F<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("laplace_ln_sf"))
+}
+
+type Quantile = Continuous3F<InverseCdfEvaluator3F<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("laplace_quantile"))
+}
+
+type InverseSf = Continuous3F<InverseSfEvaluator3F<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("laplace_inverse_sf"))
+}
+
+type Interval = Interval2F<Laplace>;
+
+/// ScalarUDF for the Laplace Distribution central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("laplace_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("laplace_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("laplace_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("laplace_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("laplace_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("laplace_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("laplace_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("laplace_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("laplace_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<Laplace>>;
+
+/// ScalarUDF for the Laplace Distribution support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("laplace_support_max"))
+}
+
+type Random = Random2F<Laplace>;
+
+/// ScalarUDF for the Laplace Distribution random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("laplace_random"))
+}
+
 /// Register the functions for the Laplace Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -84,7 +239,7 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -153,8 +308,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.0006709252558050237);
         assert_eq_float!(res_col.value(1), 0.03663127777746836);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -175,12 +330,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<LaplaceError>().unwrap();
-                assert_eq!(*be.as_ref(), LaplaceError::ScaleInvalid);
+                assert!(e.to_string().starts_with("laplace_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<LaplaceError>().unwrap();
+                assert_eq!(*be, LaplaceError::ScaleInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -231,9 +386,9 @@ mod tests {
         assert_eq!(res[0].num_rows(), 4);
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.00016773131395125593);
-        assert_eq_float!(res_col.value(1),  0.00915781944436709);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert_eq_float!(res_col.value(1), 0.00915781944436709);
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -265,7 +420,43 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.9998322686860488);
         assert_eq_float!(res_col.value(1), 0.9908421805556329);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn laplace_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT laplace_quantile(0.25, 0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), -std::f64::consts::LN_2);
+    }
+
+    #[tokio::test]
+    async fn laplace_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT laplace_inverse_sf(1e-20, 0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 45.35855467932097);
     }
 }
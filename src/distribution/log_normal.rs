@@ -1,26 +1,47 @@
 //! Module containing functions to the log Normal Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::LogNormal`].
-//! 
+//!
 //! The [log Normal Distribution](https://en.wikipedia.org/wiki/Log-normal_distribution) has two
 //! parameters:
-//! 
-//! μ: μ ∈ R (real numbers) 
+//!
+//! μ: μ ∈ R (real numbers)
 //! σ: 0 < σ (rate)
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `log_normal_pdf(x, μ, σ)`  
 //! `log_normal_ln_pdf(x, μ, σ)`  
 //! `log_normal_cdf(x, μ, σ)`  
-//! `log_normal_sf(x, μ, σ)`
-//! 
+//! `log_normal_sf(x, μ, σ)`  
+//! `log_normal_ln_cdf(x, μ, σ)`  
+//! `log_normal_ln_sf(x, μ, σ)`  
+//! `log_normal_hazard(x, μ, σ)`  
+//! `log_normal_cum_hazard(x, μ, σ)`  
+//! `log_normal_quantile(p, μ, σ)`  
+//! `log_normal_inverse_sf(p, μ, σ)`  
+//! `log_normal_interval(level, μ, σ)`  
+//! `log_normal_mean(μ, σ)`  
+//! `log_normal_variance(μ, σ)`  
+//! `log_normal_std_dev(μ, σ)`  
+//! `log_normal_skewness(μ, σ)`  
+//! `log_normal_entropy(μ, σ)`  
+//! `log_normal_mode(μ, σ)`  
+//! `log_normal_median(μ, σ)`  
+//! `log_normal_support_min(μ, σ)`  
+//! `log_normal_support_max(μ, σ)`  
+//! `log_normal_random(μ, σ[, seed])`  
+//! `log_normal_fit(x)` (aggregate)
+//!
 //! with
-//! 
+//!
 //!   `x`: (-∞, +∞) `Float64`/`DOUBLE`,  
 //!   `μ`: (-∞, +∞) `Float64`/`DOUBLE`,  
-//!   `σ`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `σ`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -35,11 +56,24 @@
 
 use datafusion::error::DataFusionError;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
 use statrs::distribution::LogNormal;
 
 use super::super::utils::continuous3f::Continuous3F;
-use super::super::utils::evaluator3f::{CdfEvaluator3F, LnPdfEvaluator3F, PdfEvaluator3F, SfEvaluator3F};
+use super::super::utils::evaluator3f::{
+    CdfEvaluator3F, CumHazardEvaluator3F, HazardEvaluator3F, InverseCdfEvaluator3F,
+    InverseSfEvaluator3F, LnCdfEvaluator3F, LnPdfEvaluator3F, LnSfEvaluator3F, PdfEvaluator3F,
+    SfEvaluator3F,
+};
+use super::super::utils::fit::FitAggregate;
+use super::super::utils::interval2f::Interval2F;
+use super::super::utils::ln_cdf::LogNormalParams;
+use super::super::utils::random2f::Random2F;
+use super::super::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use super::super::utils::statistic2f::Statistic2F;
 
 type Pdf = Continuous3F<PdfEvaluator3F<LogNormal>>;
 
@@ -69,9 +103,161 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("log_normal_sf"))
 }
 
+type LnCdf = Continuous3F<LnCdfEvaluator3F<LogNormalParams>>;
+
+/// ScalarUDF for the log-Normal log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("log_normal_ln_cdf"))
+}
+
+type LnSf = Continuous3F<LnSfEvaluator3F<LogNormalParams>>;
+
+/// ScalarUDF for the log-Normal log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("log_normal_ln_sf"))
+}
+
+type Hazard = Continuous3F<HazardEvaluator3F<LogNormalParams>>;
+
+/// ScalarUDF for the log-Normal hazard
+pub fn hazard() -> ScalarUDF {
+    ScalarUDF::from(Hazard::new("log_normal_hazard"))
+}
+
+type CumHazard = Continuous3F<CumHazardEvaluator3F<LogNormalParams>>;
+
+/// ScalarUDF for the log-Normal cumulative hazard
+pub fn cum_hazard() -> ScalarUDF {
+    ScalarUDF::from(CumHazard::new("log_normal_cum_hazard"))
+}
+
+type Quantile = Continuous3F<InverseCdfEvaluator3F<LogNormal>>;
+
+/// ScalarUDF for the log-Normal quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("log_normal_quantile"))
+}
+
+type InverseSf = Continuous3F<InverseSfEvaluator3F<LogNormal>>;
+
+/// ScalarUDF for the log-Normal inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("log_normal_inverse_sf"))
+}
+
+type Interval = Interval2F<LogNormal>;
+
+/// ScalarUDF for the log-Normal central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("log_normal_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<LogNormal>>;
+
+/// ScalarUDF for the log-Normal mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("log_normal_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<LogNormal>>;
+
+/// ScalarUDF for the log-Normal variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("log_normal_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<LogNormal>>;
+
+/// ScalarUDF for the log-Normal standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("log_normal_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<LogNormal>>;
+
+/// ScalarUDF for the log-Normal skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("log_normal_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<LogNormal>>;
+
+/// ScalarUDF for the log-Normal entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("log_normal_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<LogNormal>>;
+
+/// ScalarUDF for the log-Normal mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("log_normal_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<LogNormal>>;
+
+/// ScalarUDF for the log-Normal median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("log_normal_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<LogNormal>>;
+
+/// ScalarUDF for the log-Normal support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("log_normal_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<LogNormal>>;
+
+/// ScalarUDF for the log-Normal support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("log_normal_support_max"))
+}
+
+type Random = Random2F<LogNormal>;
+
+/// ScalarUDF for the log-Normal random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("log_normal_random"))
+}
+
+type Fit = FitAggregate<LogNormal>;
+
+/// AggregateUDF for the log-Normal maximum likelihood fit
+pub fn fit() -> AggregateUDF {
+    AggregateUDF::from(Fit::new("log_normal_fit"))
+}
+
 /// Register the functions for the log-Normal Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register_aggregates(registry, vec![fit()])?;
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            hazard(),
+            cum_hazard(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -81,10 +267,10 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
-        common::cast::as_float64_array,
+        common::cast::{as_float64_array, as_struct_array, as_uint64_array},
         error::DataFusionError,
         prelude::{SessionContext, col},
     };
@@ -150,8 +336,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 8.585534942652241e-32);
         assert_eq_float!(res_col.value(1), 2.587690993483153e-19);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -172,12 +358,16 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<LogNormalError>().unwrap();
-                assert_eq!(*be.as_ref(), LogNormalError::ScaleInvalid);
+                assert!(e.to_string().starts_with("log_normal_pdf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<LogNormalError>()
+                    .unwrap();
+                assert_eq!(*be, LogNormalError::ScaleInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -229,8 +419,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 1.7764821120334314e-33);
         assert_eq_float!(res_col.value(1), 1.3862566635680422e-20);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -262,7 +452,130 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 1.0);
         assert_eq_float!(res_col.value(1), 1.0);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn log_normal_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT log_normal_quantile(0.975, 0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 7.099071384231337);
+    }
+
+    #[tokio::test]
+    async fn log_normal_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT log_normal_inverse_sf(1e-12, 0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1135.108846481543);
+    }
+
+    #[tokio::test]
+    async fn log_normal_ln_cdf_ln_sf_large_location() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        crate::distribution::normal::register(&mut ctx).unwrap();
+        let res = ctx
+            .sql(
+                "SELECT log_normal_ln_cdf(1.0, 1000.0, 1.0), normal_ln_cdf(0.0, 1000.0, 1.0),
+                        log_normal_ln_sf(exp(704.0), 700.0, 0.1), normal_ln_sf(704.0, 700.0, 0.1)",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 4);
+        assert_eq!(res[0].num_rows(), 1);
+        let value = |i: usize| as_float64_array(res[0].column(i)).unwrap().value(0);
+        assert_eq_float!(value(0), value(1), 1e-6);
+        assert_eq_float!(value(2), value(3), 1e-8);
+    }
+
+    #[tokio::test]
+    async fn log_normal_hazard_tail() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT log_normal_hazard(1e20, 0.0, 1.0), log_normal_cum_hazard(1e20, 0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(
+            as_float64_array(res[0].column(0)).unwrap().value(0),
+            4.607339615383751e-19
+        );
+        assert_eq_float!(
+            as_float64_array(res[0].column(1)).unwrap().value(0),
+            1065.1287963223679
+        );
+    }
+
+    #[tokio::test]
+    async fn log_normal_fit_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT log_normal_fit(x) FROM (VALUES (0.5), (1.2), (2.3), (3.1), (4.8), (0.9), (1.7)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("location").unwrap())
+                .unwrap()
+                .value(0),
+            0.4924813234254862
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("scale").unwrap())
+                .unwrap()
+                .value(0),
+            0.7114332222956965
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+                .unwrap()
+                .value(0),
+            -10.996622951674272
+        );
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            7
+        );
     }
 }
@@ -1,4 +1,14 @@
-use datafusion::{error::DataFusionError, execution::FunctionRegistry};
+use std::sync::Arc;
+
+use datafusion::{
+    error::DataFusionError, execution::FunctionRegistry, logical_expr::ScalarUDF,
+    prelude::SessionContext,
+};
+
+use crate::options::{InvalidParams, StatrsOptions};
+use crate::utils::invalid_params::InvalidParamsAs;
+use crate::utils::null_as_nan::NullAsNan;
+use crate::utils::register::Collector;
 
 /// Bernoulli Distribution
 pub mod bernoulli;
@@ -6,6 +16,8 @@ pub mod bernoulli;
 pub mod beta;
 /// Binomial Distribution
 pub mod binomial;
+/// Categorical Distribution
+pub mod categorical;
 /// Cauchy Distribution
 pub mod cauchy;
 /// Chi Distribution
@@ -14,6 +26,12 @@ pub mod chi;
 pub mod chi_squared;
 /// Dirac Distribution
 pub mod dirac;
+/// Dirichlet Distribution
+pub mod dirichlet;
+/// Discrete Uniform Distribution
+pub mod discrete_uniform;
+/// Empirical Distribution
+pub mod empirical;
 /// Erlang Distribution
 pub mod erlang;
 /// Exponential Distribution
@@ -34,6 +52,12 @@ pub mod inverse_gamma;
 pub mod laplace;
 /// LogNormal Distribution
 pub mod log_normal;
+/// Multinomial Distribution
+pub mod multinomial;
+/// Multivariate Normal Distribution
+pub mod mvnormal;
+/// Multivariate Student's T Distribution
+pub mod mvstudent;
 /// Negative Binomial Distribution
 pub mod negative_binomial;
 /// Normal (aka Gaussian) Distribution
@@ -51,16 +75,19 @@ pub mod uniform;
 /// Weibull Distribution
 pub mod weibull;
 
-
 /// Register the functions for all the supported distributions.
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
     bernoulli::register(registry)?;
     beta::register(registry)?;
     binomial::register(registry)?;
     cauchy::register(registry)?;
+    categorical::register(registry)?;
     chi::register(registry)?;
     chi_squared::register(registry)?;
     dirac::register(registry)?;
+    dirichlet::register(registry)?;
+    discrete_uniform::register(registry)?;
+    empirical::register(registry)?;
     erlang::register(registry)?;
     exp::register(registry)?;
     fisher_snedecor::register(registry)?;
@@ -71,6 +98,9 @@ pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionErr
     inverse_gamma::register(registry)?;
     laplace::register(registry)?;
     log_normal::register(registry)?;
+    multinomial::register(registry)?;
+    mvnormal::register(registry)?;
+    mvstudent::register(registry)?;
     negative_binomial::register(registry)?;
     normal::register(registry)?;
     pareto::register(registry)?;
@@ -80,4 +110,309 @@ pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionErr
     uniform::register(registry)?;
     weibull::register(registry)?;
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Register the functions for all the supported distributions, adjusted by `options`.
+pub fn register_with_options(
+    registry: &mut dyn FunctionRegistry,
+    options: &StatrsOptions,
+) -> Result<(), DataFusionError> {
+    let mut collector = Collector::default();
+    register(&mut collector)?;
+
+    let udfs = collector
+        .udfs
+        .into_iter()
+        .map(|udf| {
+            let udf = if options.null_as_nan {
+                Arc::new(ScalarUDF::from(NullAsNan::new(udf)))
+            } else {
+                udf
+            };
+            if options.invalid_params != InvalidParams::Error {
+                ScalarUDF::from(InvalidParamsAs::new(udf, options.invalid_params))
+            } else {
+                udf.as_ref().clone()
+            }
+        })
+        .collect();
+    let udafs = collector
+        .udafs
+        .into_iter()
+        .map(|udaf| udaf.as_ref().clone())
+        .collect();
+    crate::utils::register::register(registry, udfs)?;
+    crate::utils::register::register_aggregates(registry, udafs)
+}
+
+/// Register the functions for all the supported distributions, adjusted by the
+/// [`StatrsOptions`] of the session, or the defaults if it has none. The options are read
+/// once, so setting them afterwards fails.
+pub fn register_for_session(ctx: &mut SessionContext) -> Result<(), DataFusionError> {
+    let options = ctx
+        .state()
+        .config_options()
+        .extensions
+        .get::<StatrsOptions>()
+        .cloned()
+        .unwrap_or_default();
+    register_with_options(ctx, &options)?;
+    if let Some(options) = ctx
+        .state_ref()
+        .write()
+        .config_mut()
+        .options_mut()
+        .extensions
+        .get_mut::<StatrsOptions>()
+    {
+        options.set_registered();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::{
+        arrow::array::{Array, Float64Array},
+        common::cast::as_float64_array,
+        prelude::{SessionConfig, SessionContext},
+    };
+    use statrs::distribution::NormalError;
+
+    use super::*;
+
+    const NULL_QUERY: &str =
+        "SELECT normal_pdf(CAST(NULL AS DOUBLE), 0.0, 1.0), normal_pdf(0.0, 0.0, 1.0)";
+
+    #[tokio::test]
+    async fn register_null_propagates() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx.sql(NULL_QUERY).await.unwrap().collect().await.unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert!(as_float64_array(res[0].column(0)).unwrap().is_null(0));
+        assert!(!as_float64_array(res[0].column(1)).unwrap().is_null(0));
+    }
+
+    #[tokio::test]
+    async fn register_with_options_null_as_nan() {
+        let mut ctx = SessionContext::new();
+        let options = StatrsOptions {
+            null_as_nan: true,
+            ..Default::default()
+        };
+        register_with_options(&mut ctx, &options).unwrap();
+        let res = ctx.sql(NULL_QUERY).await.unwrap().collect().await.unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert!(
+            as_float64_array(res[0].column(0))
+                .unwrap()
+                .value(0)
+                .is_nan()
+        );
+        assert!(
+            !as_float64_array(res[0].column(1))
+                .unwrap()
+                .value(0)
+                .is_nan()
+        );
+    }
+
+    #[tokio::test]
+    async fn register_for_session_null_as_nan() {
+        let config = SessionConfig::new().with_option_extension(StatrsOptions::default());
+        let mut ctx = SessionContext::new_with_config(config);
+        ctx.sql("SET statrs.null_as_nan = true").await.unwrap();
+        register_for_session(&mut ctx).unwrap();
+        let res = ctx.sql(NULL_QUERY).await.unwrap().collect().await.unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert!(
+            as_float64_array(res[0].column(0))
+                .unwrap()
+                .value(0)
+                .is_nan()
+        );
+    }
+
+    #[tokio::test]
+    async fn register_for_session_null_as_nan_set_after_registration() {
+        let config = SessionConfig::new().with_option_extension(StatrsOptions::default());
+        let mut ctx = SessionContext::new_with_config(config);
+        register_for_session(&mut ctx).unwrap();
+        let res = ctx.sql("SET statrs.null_as_nan = true").await;
+        match res {
+            Err(DataFusionError::Configuration(msg)) => {
+                assert!(msg.starts_with("statrs.null_as_nan is read"), "{msg}");
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res.map(|_| ()));
+            }
+        }
+        let res = ctx.sql(NULL_QUERY).await.unwrap().collect().await.unwrap();
+        assert!(as_float64_array(res[0].column(0)).unwrap().is_null(0));
+    }
+
+    /// The values of a seeded random column over eight rows, read four at a time.
+    async fn seeded_random(options: &StatrsOptions) -> Vec<f64> {
+        let mut ctx = SessionContext::new_with_config(SessionConfig::new().with_batch_size(4));
+        register_with_options(&mut ctx, options).unwrap();
+        let res = ctx
+            .sql("SELECT normal_random(0.0, 1.0, value) FROM generate_series(1, 8)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        res.iter()
+            .flat_map(|b| as_float64_array(b.column(0)).unwrap().values().to_vec())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn register_with_options_null_as_nan_seeded_random() {
+        let options = StatrsOptions {
+            null_as_nan: true,
+            ..Default::default()
+        };
+        let values = seeded_random(&options).await;
+        assert_eq!(values.len(), 8);
+        assert_ne!(values[..4], values[4..]);
+        assert_eq!(values, seeded_random(&StatrsOptions::default()).await);
+    }
+
+    const INVALID_QUERY: &str = "SELECT normal_cdf(x, 0.0, s) FROM (VALUES (0.0, 1.0), (0.0, -1.0), (0.0, NULL)) AS t(x, s)";
+
+    async fn invalid_params(policy: &str) -> datafusion::error::Result<Float64Array> {
+        let config = SessionConfig::new().with_option_extension(StatrsOptions::default());
+        let mut ctx = SessionContext::new_with_config(config);
+        ctx.sql(&format!("SET statrs.invalid_params = '{policy}'"))
+            .await?;
+        register_for_session(&mut ctx)?;
+        let res = ctx.sql(INVALID_QUERY).await?.collect().await?;
+        assert_eq!(res.len(), 1);
+        Ok(as_float64_array(res[0].column(0))?.clone())
+    }
+
+    #[tokio::test]
+    async fn register_invalid_params_error() {
+        let res = invalid_params("error").await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("normal_cdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<NormalError>().unwrap();
+                assert_eq!(*be, NormalError::StandardDeviationInvalid);
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn register_invalid_params_null() {
+        let res_col = invalid_params("null").await.unwrap();
+        assert_eq!(res_col.len(), 3);
+        assert_eq!(res_col.value(0), 0.5);
+        assert!(res_col.is_null(1));
+        assert!(res_col.is_null(2));
+    }
+
+    #[tokio::test]
+    async fn register_invalid_params_nan() {
+        let res_col = invalid_params("NaN").await.unwrap();
+        assert_eq!(res_col.len(), 3);
+        assert_eq!(res_col.value(0), 0.5);
+        assert!(res_col.value(1).is_nan());
+        assert!(res_col.is_null(2));
+    }
+
+    #[tokio::test]
+    async fn register_invalid_params_scalar() {
+        let config = SessionConfig::new().with_option_extension(StatrsOptions::default());
+        let mut ctx = SessionContext::new_with_config(config);
+        ctx.sql("SET statrs.invalid_params = 'null'").await.unwrap();
+        register_for_session(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_cdf(0.0, 0.0, -1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert!(as_float64_array(res[0].column(0)).unwrap().is_null(0));
+    }
+
+    #[tokio::test]
+    async fn register_invalid_params_seeded_random() {
+        let options = StatrsOptions {
+            invalid_params: InvalidParams::Null,
+            ..Default::default()
+        };
+        let values = seeded_random(&options).await;
+        assert_eq!(values.len(), 8);
+        assert_ne!(values[..4], values[4..]);
+        assert_eq!(values, seeded_random(&StatrsOptions::default()).await);
+
+        let mut ctx = SessionContext::new();
+        register_with_options(&mut ctx, &options).unwrap();
+        let res = ctx
+            .sql("SELECT normal_random(0.0, s, id) FROM (VALUES (1, 1.0), (2, 1.0), (3, -1.0), (4, 1.0)) AS t(id, s)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.len(), 4);
+        assert!(res_col.is_null(2));
+        assert_ne!(res_col.value(0), res_col.value(1));
+        assert_ne!(res_col.value(1), res_col.value(3));
+    }
+
+    #[tokio::test]
+    async fn register_invalid_params_argument_errors() {
+        let options = StatrsOptions {
+            invalid_params: InvalidParams::Null,
+            ..Default::default()
+        };
+        let mut ctx = SessionContext::new();
+        register_with_options(&mut ctx, &options).unwrap();
+        let res = ctx
+            .sql("SELECT mvnormal_pdf(x, [0.0, 0.0], [1.0, 0.0, 0.0, 1.0]) FROM (VALUES ([1.0, 1.0]), ([1.0, 1.0, 1.0])) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert!(matches!(res, Err(DataFusionError::Execution(_))), "{res:?}");
+    }
+
+    #[tokio::test]
+    async fn register_invalid_params_set_after_registration() {
+        let config = SessionConfig::new().with_option_extension(StatrsOptions::default());
+        let mut ctx = SessionContext::new_with_config(config);
+        register_for_session(&mut ctx).unwrap();
+        let res = ctx.sql("SET statrs.invalid_params = 'null'").await;
+        match res {
+            Err(DataFusionError::Configuration(msg)) => {
+                assert!(msg.starts_with("statrs.invalid_params is read"), "{msg}");
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res.map(|_| ()));
+            }
+        }
+        let res = ctx.sql(INVALID_QUERY).await.unwrap().collect().await;
+        assert!(matches!(res, Err(DataFusionError::External(_))));
+    }
+
+    #[tokio::test]
+    async fn register_invalid_params_unknown() {
+        let config = SessionConfig::new().with_option_extension(StatrsOptions::default());
+        let ctx = SessionContext::new_with_config(config);
+        let res = ctx.sql("SET statrs.invalid_params = 'ignore'").await;
+        assert!(matches!(res, Err(DataFusionError::Configuration(_))));
+    }
+}
@@ -0,0 +1,309 @@
+//! Module containing functions to the Multinomial Distribution.
+//!
+//! Implemented by [`statrs::distribution::Multinomial`].
+//!
+//! The [Multinomial Distribution](https://en.wikipedia.org/wiki/Multinomial_distribution) has two
+//! parameters:
+//!
+//! n: {0, 1, 2, ...} (number of trials)  
+//! p: [p0, p1, ..., pk-1], 0 ≤ pi, 0 < Σ pi, 2 ≤ k
+//!
+//! The number of trials is taken to be the sum of the counts, and the probabilities are
+//! normalized by their total. The counts and probabilities must have the same length.
+//!
+//! Usage:
+//!
+//! `multinomial_pmf(x, p)`  
+//! `multinomial_ln_pmf(x, p)`
+//!
+//! with
+//!
+//!   `x`: {0, 1, 2, ...}ᵏ `List<UInt64>`/`BIGINT UNSIGNED[]`,  
+//!   `p`: [0, +∞)ᵏ `List<Float64>`/`DOUBLE[]`
+//!
+//! Examples
+//! ```
+//! #[tokio::main(flavor = "current_thread")]
+//! async fn main() -> std::io::Result<()> {
+//!     let mut ctx = datafusion::prelude::SessionContext::new();
+//!     datafusion_statrs::distribution::multinomial::register(&mut ctx)?;
+//!     ctx.sql("SELECT multinomial_ln_pmf([1, 2, 1], [0.2, 0.3, 0.5])").await?
+//!        .show().await?;
+//!     Ok(())
+//! }
+//! ```
+
+use datafusion::error::DataFusionError;
+use datafusion::execution::FunctionRegistry;
+use datafusion::logical_expr::ScalarUDF;
+use nalgebra::Dyn;
+use statrs::distribution::Multinomial;
+
+use crate::utils::discrete1lu1l::Discrete1LU1L;
+use crate::utils::evaluator1lu1l::{LnPmfEvaluator1LU1L, PmfEvaluator1LU1L};
+
+type Pmf = Discrete1LU1L<PmfEvaluator1LU1L<Multinomial<Dyn>>>;
+
+/// ScalarUDF for the Multinomial Distribution PMF
+pub fn pmf() -> ScalarUDF {
+    ScalarUDF::from(Pmf::new("multinomial_pmf"))
+}
+
+type LnPmf = Discrete1LU1L<LnPmfEvaluator1LU1L<Multinomial<Dyn>>>;
+
+/// ScalarUDF for the Multinomial Distribution log PMF
+pub fn ln_pmf() -> ScalarUDF {
+    ScalarUDF::from(LnPmf::new("multinomial_ln_pmf"))
+}
+
+/// Register the functions for the Multinomial Distribution
+pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
+    crate::utils::register::register(registry, vec![pmf(), ln_pmf()])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert_eq_float::assert_eq_float;
+    use datafusion::{
+        arrow::{
+            array::{Array, ListArray, RecordBatch},
+            datatypes::{DataType, Field, Float64Type, Int64Type, Schema, SchemaRef, UInt64Type},
+        },
+        common::cast::as_float64_array,
+        error::DataFusionError,
+        prelude::{SessionContext, col},
+    };
+    use statrs::distribution::MultinomialError;
+
+    use super::*;
+
+    fn get_schema() -> SchemaRef {
+        SchemaRef::new(Schema::new(vec![
+            Field::new("x", DataType::new_list(DataType::UInt64, true), true),
+            Field::new("p", DataType::new_list(DataType::Float64, true), true),
+        ]))
+    }
+
+    type Row = (Option<Vec<Option<u64>>>, Option<Vec<Option<f64>>>);
+
+    fn make_records(rows: Vec<Row>) -> RecordBatch {
+        let mut xs = Vec::new();
+        let mut ps = Vec::new();
+        for row in rows {
+            xs.push(row.0);
+            ps.push(row.1);
+        }
+
+        RecordBatch::try_new(
+            get_schema(),
+            vec![
+                Arc::new(ListArray::from_iter_primitive::<UInt64Type, _, _>(xs)),
+                Arc::new(ListArray::from_iter_primitive::<Float64Type, _, _>(ps)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn multinomial_pmf_success() {
+        let pmf = pmf();
+
+        let recs = make_records(vec![
+            (
+                Some(vec![Some(1), Some(2), Some(1)]),
+                Some(vec![Some(0.2), Some(0.3), Some(0.5)]),
+            ),
+            (
+                Some(vec![Some(0), Some(0), Some(0)]),
+                Some(vec![Some(0.2), Some(0.3), Some(0.5)]),
+            ),
+            (None, Some(vec![Some(0.2), Some(0.3), Some(0.5)])),
+            (Some(vec![Some(1), Some(2), Some(1)]), None),
+            (
+                Some(vec![Some(1), None, Some(1)]),
+                Some(vec![Some(0.2), Some(0.3), Some(0.5)]),
+            ),
+        ]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![(pmf.call(vec![col("x"), col("p")])).alias("q")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 5);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.108);
+        assert_eq_float!(res_col.value(1), 1.0);
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+        assert!(res_col.is_null(4));
+    }
+
+    #[tokio::test]
+    async fn multinomial_pmf_failure_1() {
+        let pmf = pmf();
+
+        let recs = make_records(vec![(Some(vec![Some(3)]), Some(vec![Some(1.0)]))]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![(pmf.call(vec![col("x"), col("p")])).alias("q")])
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("multinomial_pmf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<MultinomialError>()
+                    .unwrap();
+                assert_eq!(*be, MultinomialError::NotEnoughProbabilities);
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn multinomial_pmf_sliced_signed_counts() {
+        let pmf = pmf();
+
+        // The second row of a list whose first row is negative, which lies outside the
+        // slice's offsets.
+        let recs = RecordBatch::try_new(
+            SchemaRef::new(Schema::new(vec![
+                Field::new("x", DataType::new_list(DataType::Int64, true), true),
+                Field::new("p", DataType::new_list(DataType::Float64, true), true),
+            ])),
+            vec![
+                Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                    Some(vec![Some(-1), Some(2), Some(1)]),
+                    Some(vec![Some(1), Some(2), Some(1)]),
+                ])),
+                Arc::new(ListArray::from_iter_primitive::<Float64Type, _, _>(vec![
+                    Some(vec![Some(0.2), Some(0.3), Some(0.5)]),
+                    Some(vec![Some(0.2), Some(0.3), Some(0.5)]),
+                ])),
+            ],
+        )
+        .unwrap()
+        .slice(1, 1);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![(pmf.call(vec![col("x"), col("p")])).alias("q")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.108);
+    }
+
+    #[tokio::test]
+    async fn multinomial_pmf_failure_2() {
+        let pmf = pmf();
+
+        let recs = make_records(vec![(
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(0.2), Some(0.3), Some(0.5)]),
+        )]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![(pmf.call(vec![col("x"), col("p")])).alias("q")])
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::Execution(msg)) => {
+                assert!(msg.contains("same length"));
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn multinomial_ln_pmf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql(
+                "SELECT multinomial_ln_pmf(arrow_cast([1, 2, 1], 'List(UInt64)'), [0.2, 0.3, 0.5])",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), -2.2256240518579173);
+    }
+
+    #[tokio::test]
+    async fn multinomial_ln_pmf_large_counts() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT multinomial_ln_pmf(arrow_cast([1200, 1800], 'List(UInt64)'), [0.4, 0.6]), multinomial_pmf(arrow_cast([1200, 1800], 'List(UInt64)'), [0.4, 0.6])")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        // ln 3000! is about 2e4, so the log-factorials cancel down to a few ulps of that.
+        let ln_pmf = as_float64_array(res[0].column(0)).unwrap().value(0);
+        assert!((ln_pmf + 4.208652102170705).abs() < 1e-11);
+        let pmf = as_float64_array(res[0].column(1)).unwrap().value(0);
+        assert!((pmf - 0.014866393218457225).abs() < 1e-13);
+    }
+
+    #[tokio::test]
+    async fn multinomial_ln_pmf_zero_probability() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT multinomial_ln_pmf(arrow_cast([1, 0, 2], 'List(UInt64)'), [0.5, 0.0, 0.5]), multinomial_pmf(arrow_cast([1, 0, 2], 'List(UInt64)'), [0.5, 0.0, 0.5]), multinomial_pmf(arrow_cast([1, 1, 1], 'List(UInt64)'), [0.5, 0.0, 0.5])")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(
+            as_float64_array(res[0].column(0)).unwrap().value(0),
+            -0.9808292530117262
+        );
+        assert_eq_float!(as_float64_array(res[0].column(1)).unwrap().value(0), 0.375);
+        assert_eq!(as_float64_array(res[0].column(2)).unwrap().value(0), 0.0);
+    }
+}
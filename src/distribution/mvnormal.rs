@@ -0,0 +1,264 @@
+//! Module containing functions to the Multivariate Normal Distribution.
+//!
+//! Implemented by [`statrs::distribution::MultivariateNormal`].
+//!
+//! The [Multivariate Normal Distribution](https://en.wikipedia.org/wiki/Multivariate_normal_distribution)
+//! has two parameters:
+//!
+//! μ: μ ∈ Rᵈ (mean)  
+//! Σ: d×d symmetric positive definite matrix (covariance), flattened
+//!
+//! `x` and `μ` must have `d` elements and `Σ` must have `d²`. When `μ` and `Σ` are literals the
+//! distribution, including the factorization of `Σ`, is built once per batch rather than per row.
+//!
+//! Usage:
+//!
+//! `mvnormal_pdf(x, μ, Σ)`  
+//! `mvnormal_ln_pdf(x, μ, Σ)`
+//!
+//! with
+//!
+//!   `x`: Rᵈ `FixedSizeList<Float64, d>`/`DOUBLE[]`,  
+//!   `μ`: Rᵈ `FixedSizeList<Float64, d>`/`DOUBLE[]`,  
+//!   `Σ`: Rᵈˣᵈ `FixedSizeList<Float64, d²>`/`DOUBLE[]`
+//!
+//! `List<Float64>` arguments are accepted as well.
+//!
+//! Examples
+//! ```
+//! #[tokio::main(flavor = "current_thread")]
+//! async fn main() -> std::io::Result<()> {
+//!     let mut ctx = datafusion::prelude::SessionContext::new();
+//!     datafusion_statrs::distribution::mvnormal::register(&mut ctx)?;
+//!     ctx.sql("SELECT mvnormal_pdf([1.0, 1.0], [0.0, 0.0], [1.0, 0.0, 0.0, 1.0])").await?
+//!        .show().await?;
+//!     Ok(())
+//! }
+//! ```
+
+use datafusion::error::DataFusionError;
+use datafusion::execution::FunctionRegistry;
+use datafusion::logical_expr::ScalarUDF;
+use nalgebra::Dyn;
+use statrs::distribution::MultivariateNormal;
+
+use crate::utils::continuous3l::Continuous3L;
+use crate::utils::evaluator3l::{LnPdfEvaluator3L, PdfEvaluator3L};
+
+type Pdf = Continuous3L<PdfEvaluator3L<MultivariateNormal<Dyn>>>;
+
+/// ScalarUDF for the Multivariate Normal PDF
+pub fn pdf() -> ScalarUDF {
+    ScalarUDF::from(Pdf::new("mvnormal_pdf"))
+}
+
+type LnPdf = Continuous3L<LnPdfEvaluator3L<MultivariateNormal<Dyn>>>;
+
+/// ScalarUDF for the Multivariate Normal log PDF
+pub fn ln_pdf() -> ScalarUDF {
+    ScalarUDF::from(LnPdf::new("mvnormal_ln_pdf"))
+}
+
+/// Register the functions for the Multivariate Normal Distribution
+pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
+    crate::utils::register::register(registry, vec![pdf(), ln_pdf()])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert_eq_float::assert_eq_float;
+    use datafusion::{
+        arrow::{
+            array::{Array, FixedSizeListArray, RecordBatch},
+            datatypes::{DataType, Field, Float64Type, Schema, SchemaRef},
+        },
+        common::cast::as_float64_array,
+        error::DataFusionError,
+        prelude::{SessionContext, col},
+    };
+    use statrs::distribution::MultivariateNormalError;
+
+    use super::*;
+
+    fn get_schema() -> SchemaRef {
+        SchemaRef::new(Schema::new(vec![
+            Field::new(
+                "x",
+                DataType::new_fixed_size_list(DataType::Float64, 2, true),
+                true,
+            ),
+            Field::new(
+                "m",
+                DataType::new_fixed_size_list(DataType::Float64, 2, true),
+                true,
+            ),
+            Field::new(
+                "s",
+                DataType::new_fixed_size_list(DataType::Float64, 4, true),
+                true,
+            ),
+        ]))
+    }
+
+    type Vector = Option<Vec<Option<f64>>>;
+
+    fn make_records(rows: Vec<(Vector, Vector, Vector)>) -> RecordBatch {
+        let mut xs = Vec::new();
+        let mut ms = Vec::new();
+        let mut ss = Vec::new();
+        for row in rows {
+            xs.push(row.0);
+            ms.push(row.1);
+            ss.push(row.2);
+        }
+
+        RecordBatch::try_new(
+            get_schema(),
+            vec![
+                Arc::new(FixedSizeListArray::from_iter_primitive::<Float64Type, _, _>(xs, 2)),
+                Arc::new(FixedSizeListArray::from_iter_primitive::<Float64Type, _, _>(ms, 2)),
+                Arc::new(FixedSizeListArray::from_iter_primitive::<Float64Type, _, _>(ss, 4)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn v(xs: &[f64]) -> Vector {
+        Some(xs.iter().copied().map(Some).collect())
+    }
+
+    #[tokio::test]
+    async fn mvnormal_pdf_success() {
+        let pdf = pdf();
+
+        let recs = make_records(vec![
+            (v(&[1.0, 1.0]), v(&[0.0, 0.0]), v(&[1.0, 0.0, 0.0, 1.0])),
+            (v(&[0.0, 0.0]), v(&[1.0, 2.0]), v(&[2.0, 1.0, 1.0, 2.0])),
+            (None, v(&[1.0, 2.0]), v(&[2.0, 1.0, 1.0, 2.0])),
+            (v(&[0.0, 0.0]), None, v(&[2.0, 1.0, 1.0, 2.0])),
+        ]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![
+                (pdf.call(vec![col("x"), col("m"), col("s")])).alias("q"),
+            ])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 4);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.05854983152431917);
+        assert_eq_float!(res_col.value(1), 0.0338037609915729);
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn mvnormal_pdf_failure_1() {
+        let pdf = pdf();
+
+        let recs = make_records(vec![(
+            v(&[1.0, 1.0]),
+            v(&[0.0, 0.0]),
+            v(&[1.0, 0.5, 0.0, 1.0]),
+        )]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![
+                (pdf.call(vec![col("x"), col("m"), col("s")])).alias("q"),
+            ])
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("mvnormal_pdf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<MultivariateNormalError>()
+                    .unwrap();
+                assert_eq!(*be, MultivariateNormalError::CovInvalid);
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn mvnormal_pdf_failure_2() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT mvnormal_pdf([1.0, 1.0, 1.0], [0.0, 0.0], [1.0, 0.0, 0.0, 1.0])")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::Execution(msg)) => {
+                assert!(msg.contains("dimension"));
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn mvnormal_ln_pdf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT mvnormal_ln_pdf(arrow_cast([0.5, -1.0], 'FixedSizeList(2, Float64)'), [0.0, 0.0], [1.0, 0.5, 0.5, 1.0])")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), -2.860702696850122);
+    }
+
+    #[tokio::test]
+    async fn mvnormal_ln_pdf_literal_parameters() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let recs = make_records(vec![
+            (v(&[1.0, 1.0]), None, None),
+            (v(&[0.5, -1.0]), None, None),
+            (None, None, None),
+        ]);
+        ctx.register_batch("tbl", recs).unwrap();
+        let res = ctx
+            .sql("SELECT mvnormal_ln_pdf(x, [0.0, 0.0], [1.0, 0.5, 0.5, 1.0]) FROM tbl")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 3);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), -2.3607026968501215);
+        assert_eq_float!(res_col.value(1), -2.860702696850122);
+        assert!(res_col.is_null(2));
+    }
+}
@@ -0,0 +1,261 @@
+//! Module containing functions to the Multivariate Student's T Distribution.
+//!
+//! Implemented by [`statrs::distribution::MultivariateStudent`].
+//!
+//! The [Multivariate Student's T Distribution](https://en.wikipedia.org/wiki/Multivariate_t-distribution)
+//! has three parameters:
+//!
+//! μ: μ ∈ Rᵈ (location)  
+//! Σ: d×d symmetric positive definite matrix (scale), flattened  
+//! ν: 0 < ν (degrees of freedom)
+//!
+//! `x` and `μ` must have `d` elements and `Σ` must have `d²`. When `μ`, `Σ` and `ν` are literals
+//! the distribution, including the factorization of `Σ`, is built once per batch rather than
+//! per row.
+//!
+//! Usage:
+//!
+//! `mvstudent_pdf(x, μ, Σ, ν)`  
+//! `mvstudent_ln_pdf(x, μ, Σ, ν)`
+//!
+//! with
+//!
+//!   `x`: Rᵈ `FixedSizeList<Float64, d>`/`DOUBLE[]`,  
+//!   `μ`: Rᵈ `FixedSizeList<Float64, d>`/`DOUBLE[]`,  
+//!   `Σ`: Rᵈˣᵈ `FixedSizeList<Float64, d²>`/`DOUBLE[]`,  
+//!   `ν`: (0, +∞] `Float64`/`DOUBLE`
+//!
+//! `List<Float64>` arguments are accepted as well.
+//!
+//! Examples
+//! ```
+//! #[tokio::main(flavor = "current_thread")]
+//! async fn main() -> std::io::Result<()> {
+//!     let mut ctx = datafusion::prelude::SessionContext::new();
+//!     datafusion_statrs::distribution::mvstudent::register(&mut ctx)?;
+//!     ctx.sql("SELECT mvstudent_pdf([1.0, 1.0], [0.0, 0.0], [1.0, 0.0, 0.0, 1.0], 3.0)").await?
+//!        .show().await?;
+//!     Ok(())
+//! }
+//! ```
+
+use datafusion::error::DataFusionError;
+use datafusion::execution::FunctionRegistry;
+use datafusion::logical_expr::ScalarUDF;
+use nalgebra::Dyn;
+use statrs::distribution::MultivariateStudent;
+
+use crate::utils::continuous3l1f::Continuous3L1F;
+use crate::utils::evaluator3l1f::{LnPdfEvaluator3L1F, PdfEvaluator3L1F};
+
+type Pdf = Continuous3L1F<PdfEvaluator3L1F<MultivariateStudent<Dyn>>>;
+
+/// ScalarUDF for the Multivariate Student's T PDF
+pub fn pdf() -> ScalarUDF {
+    ScalarUDF::from(Pdf::new("mvstudent_pdf"))
+}
+
+type LnPdf = Continuous3L1F<LnPdfEvaluator3L1F<MultivariateStudent<Dyn>>>;
+
+/// ScalarUDF for the Multivariate Student's T log PDF
+pub fn ln_pdf() -> ScalarUDF {
+    ScalarUDF::from(LnPdf::new("mvstudent_ln_pdf"))
+}
+
+/// Register the functions for the Multivariate Student's T Distribution
+pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
+    crate::utils::register::register(registry, vec![pdf(), ln_pdf()])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert_eq_float::assert_eq_float;
+    use datafusion::{
+        arrow::{
+            array::{Array, FixedSizeListArray, Float64Array, RecordBatch},
+            datatypes::{DataType, Field, Float64Type, Schema, SchemaRef},
+        },
+        common::cast::as_float64_array,
+        error::DataFusionError,
+        prelude::{SessionContext, col},
+    };
+    use statrs::distribution::MultivariateStudentError;
+
+    use super::*;
+
+    fn get_schema() -> SchemaRef {
+        SchemaRef::new(Schema::new(vec![
+            Field::new(
+                "x",
+                DataType::new_fixed_size_list(DataType::Float64, 2, true),
+                true,
+            ),
+            Field::new(
+                "m",
+                DataType::new_fixed_size_list(DataType::Float64, 2, true),
+                true,
+            ),
+            Field::new(
+                "s",
+                DataType::new_fixed_size_list(DataType::Float64, 4, true),
+                true,
+            ),
+            Field::new("n", DataType::Float64, true),
+        ]))
+    }
+
+    type Vector = Option<Vec<Option<f64>>>;
+
+    fn make_records(rows: Vec<(Vector, Vector, Vector, Option<f64>)>) -> RecordBatch {
+        let mut xs = Vec::new();
+        let mut ms = Vec::new();
+        let mut ss = Vec::new();
+        let mut ns = Vec::new();
+        for row in rows {
+            xs.push(row.0);
+            ms.push(row.1);
+            ss.push(row.2);
+            ns.push(row.3);
+        }
+
+        RecordBatch::try_new(
+            get_schema(),
+            vec![
+                Arc::new(FixedSizeListArray::from_iter_primitive::<Float64Type, _, _>(xs, 2)),
+                Arc::new(FixedSizeListArray::from_iter_primitive::<Float64Type, _, _>(ms, 2)),
+                Arc::new(FixedSizeListArray::from_iter_primitive::<Float64Type, _, _>(ss, 4)),
+                Arc::new(Float64Array::from(ns)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn v(xs: &[f64]) -> Vector {
+        Some(xs.iter().copied().map(Some).collect())
+    }
+
+    #[tokio::test]
+    async fn mvstudent_pdf_success() {
+        let pdf = pdf();
+
+        let recs = make_records(vec![
+            (
+                v(&[1.0, 1.0]),
+                v(&[0.0, 0.0]),
+                v(&[1.0, 0.0, 0.0, 1.0]),
+                Some(3.0),
+            ),
+            (
+                v(&[0.0, 0.0]),
+                v(&[1.0, 2.0]),
+                v(&[2.0, 1.0, 1.0, 2.0]),
+                Some(5.0),
+            ),
+            (None, v(&[1.0, 2.0]), v(&[2.0, 1.0, 1.0, 2.0]), Some(5.0)),
+            (
+                v(&[0.0, 0.0]),
+                v(&[1.0, 2.0]),
+                v(&[2.0, 1.0, 1.0, 2.0]),
+                None,
+            ),
+        ]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![
+                (pdf.call(vec![col("x"), col("m"), col("s"), col("n")])).alias("q"),
+            ])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 4);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.04438111997242799);
+        assert_eq_float!(res_col.value(1), 0.02830162546893214);
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn mvstudent_pdf_failure_1() {
+        let pdf = pdf();
+
+        let recs = make_records(vec![(
+            v(&[1.0, 1.0]),
+            v(&[0.0, 0.0]),
+            v(&[1.0, 0.0, 0.0, 1.0]),
+            Some(-1.0),
+        )]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![
+                (pdf.call(vec![col("x"), col("m"), col("s"), col("n")])).alias("q"),
+            ])
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("mvstudent_pdf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<MultivariateStudentError>()
+                    .unwrap();
+                assert_eq!(*be, MultivariateStudentError::FreedomInvalid);
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn mvstudent_pdf_failure_2() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT mvstudent_pdf([1.0, 1.0], [0.0, 0.0], [1.0, 0.0, 0.0], 3.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::Execution(msg)) => {
+                assert!(msg.contains("2x2"));
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn mvstudent_ln_pdf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT mvstudent_ln_pdf(arrow_cast([0.5, -1.0], 'FixedSizeList(2, Float64)'), [0.0, 0.0], [1.0, 0.5, 0.5, 1.0], 4)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), -3.0726330183187755);
+    }
+}
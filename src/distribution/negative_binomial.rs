@@ -1,33 +1,52 @@
 //! Module containing functions to the Negative Binomial Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Binomial`].
-//! 
+//!
 //! The [Negative Binomial Distribution](https://en.wikipedia.org/wiki/Negative_binomial_distribution) has two
 //! parameters:
-//! 
+//!
 //! r: 0 < r  
 //! p: 0 ≤ p ≤ 1
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `negative_binomial_pmf(x, r, p)`  
 //! `negative_binomial_ln_pmf(x, r, p)`  
 //! `negative_binomial_cdf(x, r, p)`  
-//! `negative_binomial_sf(x, r, p)`
-//! 
+//! `negative_binomial_sf(x, r, p)`  
+//! `negative_binomial_ln_cdf(x, r, p)`  
+//! `negative_binomial_ln_sf(x, r, p)`  
+//! `negative_binomial_quantile(q, r, p)`  
+//! `negative_binomial_inverse_sf(q, r, p)`  
+//! `negative_binomial_interval(level, r, p)`  
+//! `negative_binomial_mean(r, p)`  
+//! `negative_binomial_variance(r, p)`  
+//! `negative_binomial_std_dev(r, p)`  
+//! `negative_binomial_skewness(r, p)`  
+//! `negative_binomial_entropy(r, p)`  
+//! `negative_binomial_mode(r, p)`  
+//! `negative_binomial_median(r, p)`  
+//! `negative_binomial_support_min(r, p)`  
+//! `negative_binomial_support_max(r, p)`  
+//! `negative_binomial_random(r, p[, seed])`  
+//! `negative_binomial_fit(x)` (aggregate)
+//!
 //! with
-//! 
+//!
 //!   `x`: 0 ≤ x ≤ n `UInt64`/`BIGINT UNSIGNED`,  
 //!   `r`: 0 < r `Float64`/`DOUBLE`,  
-//!   `p`: [0, 1] `Float64`/`DOUBLE`
-//! 
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `q`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
 //! async fn main() -> std::io::Result<()> {
 //!     let mut ctx = datafusion::prelude::SessionContext::new();
 //!     datafusion_statrs::distribution::negative_binomial::register(&mut ctx)?;
-//!     ctx.sql("SELECT negative_binomial_cdf(CAST(2 AS BIGINT UNSIGNED), 5.0, 0.2)").await?
+//!     ctx.sql("SELECT negative_binomial_cdf(2, 5.0, 0.2)").await?
 //!        .show().await?;
 //!     Ok(())
 //! }
@@ -35,11 +54,22 @@
 
 use datafusion::error::DataFusionError;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
 use statrs::distribution::NegativeBinomial;
 
-use crate::utils::discrete1u2f::Discrete1U2F;
-use crate::utils::evaluator1u2f::{CdfEvaluator1U2F, LnPmfEvaluator1U2F, PmfEvaluator1U2F, SfEvaluator1U2F};
+use crate::utils::discrete1u2f::{Discrete1U2F, DiscreteInverse1U2F};
+use crate::utils::evaluator1u2f::{
+    CdfEvaluator1U2F, InverseCdfEvaluator1U2F, InverseSfEvaluator1U2F, LnCdfEvaluator1U2F,
+    LnPmfEvaluator1U2F, LnSfEvaluator1U2F, PmfEvaluator1U2F, SfEvaluator1U2F,
+};
+use crate::utils::fit::CountFitAggregate;
+use crate::utils::interval2f::Interval2F;
+use crate::utils::random2f::Random2F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic2f::Statistic2F;
 
 type Pmf = Discrete1U2F<PmfEvaluator1U2F<NegativeBinomial>>;
 
@@ -69,9 +99,145 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("negative_binomial_sf"))
 }
 
+type LnCdf = Discrete1U2F<LnCdfEvaluator1U2F<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("negative_binomial_ln_cdf"))
+}
+
+type LnSf = Discrete1U2F<LnSfEvaluator1U2F<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("negative_binomial_ln_sf"))
+}
+
+type Quantile = DiscreteInverse1U2F<InverseCdfEvaluator1U2F<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("negative_binomial_quantile"))
+}
+
+type InverseSf = DiscreteInverse1U2F<InverseSfEvaluator1U2F<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("negative_binomial_inverse_sf"))
+}
+
+type Interval = Interval2F<NegativeBinomial>;
+
+/// ScalarUDF for the Negative Binomial central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("negative_binomial_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("negative_binomial_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("negative_binomial_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("negative_binomial_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("negative_binomial_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("negative_binomial_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("negative_binomial_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("negative_binomial_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("negative_binomial_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<NegativeBinomial>>;
+
+/// ScalarUDF for the Negative Binomial support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("negative_binomial_support_max"))
+}
+
+type Random = Random2F<NegativeBinomial>;
+
+/// ScalarUDF for the Negative Binomial random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("negative_binomial_random"))
+}
+
+type Fit = CountFitAggregate<NegativeBinomial>;
+
+/// AggregateUDF for the Negative Binomial Distribution maximum likelihood fit
+pub fn fit() -> AggregateUDF {
+    AggregateUDF::from(Fit::new("negative_binomial_fit"))
+}
+
 /// Register the functions for the Negative Binomial Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pmf(), ln_pmf(), cdf(), sf()])
+    crate::utils::register::register_aggregates(registry, vec![fit()])?;
+    crate::utils::register::register(
+        registry,
+        vec![
+            pmf(),
+            ln_pmf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -81,12 +247,12 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch, UInt64Array},
+            array::{Array, Float64Array, RecordBatch, UInt64Array},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
-        common::cast::as_float64_array,
+        common::cast::{as_float64_array, as_struct_array, as_uint64_array},
         error::DataFusionError,
-        prelude::{SessionContext, col},
+        prelude::{SessionConfig, SessionContext, col},
     };
     use statrs::distribution::NegativeBinomialError;
 
@@ -150,8 +316,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.035156249999999827);
         assert_eq_float!(res_col.value(1), 0.05273437499999992);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -172,12 +338,16 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<NegativeBinomialError>().unwrap();
-                assert_eq!(*be.as_ref(), NegativeBinomialError::PInvalid);
+                assert!(e.to_string().starts_with("negative_binomial_pmf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<NegativeBinomialError>()
+                    .unwrap();
+                assert_eq!(*be, NegativeBinomialError::PInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -229,8 +399,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.050781250000000056);
         assert_eq_float!(res_col.value(1), 0.10351562499999896);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -262,7 +432,190 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.94921875);
         assert_eq_float!(res_col.value(1), 0.896484375000001);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn negative_binomial_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT negative_binomial_quantile(0.5, 4.0, 0.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 3);
+    }
+
+    #[tokio::test]
+    async fn negative_binomial_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT negative_binomial_inverse_sf(1e-10, 4.0, 0.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 44);
+    }
+
+    #[tokio::test]
+    async fn negative_binomial_mode_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT negative_binomial_mode(3.0, 0.5), negative_binomial_median(3.0, 0.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(as_uint64_array(res[0].column(0)).unwrap().value(0), 2);
+        assert_eq!(as_uint64_array(res[0].column(1)).unwrap().value(0), 2);
+    }
+
+    #[tokio::test]
+    async fn negative_binomial_fit_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT negative_binomial_fit(x) FROM (VALUES (0), (1), (0), (3), (7), (2), (0), (5), (1), (9)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        // Newton's method stops within a relative 1e-12 of the estimates.
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("r").unwrap())
+                .unwrap()
+                .value(0),
+            0.8636965832201111,
+            1e-12
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("p").unwrap())
+                .unwrap()
+                .value(0),
+            0.23574457207397545,
+            1e-12
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+                .unwrap()
+                .value(0),
+            -21.877678521531376,
+            1e-12
+        );
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            10
+        );
+    }
+
+    #[tokio::test]
+    async fn negative_binomial_fit_zero_heavy() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT negative_binomial_fit(x) FROM (VALUES (0), (0), (0), (100)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert!(!fit.is_null(0));
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("r").unwrap())
+                .unwrap()
+                .value(0),
+            0.05073333087831112,
+            1e-12
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("p").unwrap())
+                .unwrap()
+                .value(0),
+            0.0020252233820147547,
+            1e-14
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+                .unwrap()
+                .value(0),
+            -8.787065585502053,
+            1e-10
+        );
+    }
+
+    #[tokio::test]
+    async fn negative_binomial_fit_underdispersed() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT negative_binomial_fit(x) FROM (VALUES (2), (3), (2), (3)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert!(res[0].column(0).is_null(0));
+    }
+
+    /// The fit of `value * value % 11` over a thousand rows in `partitions`, as `r`, `p` and
+    /// the log-likelihood.
+    async fn negative_binomial_fit_partitions(partitions: usize) -> (f64, f64, f64) {
+        let config = SessionConfig::new()
+            .with_target_partitions(partitions)
+            .with_batch_size(100);
+        let mut ctx = SessionContext::new_with_config(config);
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT negative_binomial_fit(value * value % 11) FROM generate_series(1, 1000)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert!(fit.is_valid(0));
+        let field = |name| {
+            as_float64_array(fit.column_by_name(name).unwrap())
+                .unwrap()
+                .value(0)
+        };
+        (field("r"), field("p"), field("ln_likelihood"))
+    }
+
+    #[tokio::test]
+    async fn negative_binomial_fit_partitioned() {
+        let (r, p, ln_likelihood) = negative_binomial_fit_partitions(1).await;
+        let (r_4, p_4, ln_likelihood_4) = negative_binomial_fit_partitions(4).await;
+        // The partitions merge the same histogram whatever the order of the rows.
+        assert_eq!(r_4, r);
+        assert_eq!(p_4, p);
+        assert_eq!(ln_likelihood_4, ln_likelihood);
     }
 }
@@ -1,26 +1,45 @@
 //! Module containing functions to the Normal Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Normal`].
-//! 
+//!
 //! The [Normal Distribution](https://en.wikipedia.org/wiki/Normal_distribution) has two
 //! parameters:
-//! 
-//! μ: μ ∈ R (real numbers) 
+//!
+//! μ: μ ∈ R (real numbers)
 //! σ: 0 < σ (rate)
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `normal_pdf(x, μ, σ)`  
 //! `normal_ln_pdf(x, μ, σ)`  
 //! `normal_cdf(x, μ, σ)`  
-//! `normal_sf(x, μ, σ)`
-//! 
+//! `normal_sf(x, μ, σ)`  
+//! `normal_ln_cdf(x, μ, σ)`  
+//! `normal_ln_sf(x, μ, σ)`  
+//! `normal_quantile(p, μ, σ)`  
+//! `normal_inverse_sf(p, μ, σ)`  
+//! `normal_interval(level, μ, σ)`  
+//! `normal_mean(μ, σ)`  
+//! `normal_variance(μ, σ)`  
+//! `normal_std_dev(μ, σ)`  
+//! `normal_skewness(μ, σ)`  
+//! `normal_entropy(μ, σ)`  
+//! `normal_mode(μ, σ)`  
+//! `normal_median(μ, σ)`  
+//! `normal_support_min(μ, σ)`  
+//! `normal_support_max(μ, σ)`  
+//! `normal_random(μ, σ[, seed])`  
+//! `normal_fit(x)` (aggregate)
+//!
 //! with
-//! 
+//!
 //!   `x`: (-∞, +∞) `Float64`/`DOUBLE`,  
 //!   `μ`: (-∞, +∞) `Float64`/`DOUBLE`,  
-//!   `σ`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `σ`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -35,11 +54,22 @@
 
 use datafusion::error::DataFusionError;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
 use statrs::distribution::Normal;
 
 use crate::utils::continuous3f::Continuous3F;
-use crate::utils::evaluator3f::{CdfEvaluator3F, LnPdfEvaluator3F, PdfEvaluator3F, SfEvaluator3F};
+use crate::utils::evaluator3f::{
+    CdfEvaluator3F, InverseCdfEvaluator3F, InverseSfEvaluator3F, LnCdfEvaluator3F,
+    LnPdfEvaluator3F, LnSfEvaluator3F, PdfEvaluator3F, SfEvaluator3F,
+};
+use crate::utils::fit::FitAggregate;
+use crate::utils::interval2f::Interval2F;
+use crate::utils::random2f::Random2F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic2f::Statistic2F;
 
 type Pdf = Continuous3F<PdfEvaluator3F<Normal>>;
 
@@ -69,9 +99,145 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("normal_sf"))
 }
 
+type LnCdf = Continuous3F<LnCdfEvaluator3F<Normal>>;
+
+/// ScalarUDF for the Normal log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("normal_ln_cdf"))
+}
+
+type LnSf = Continuous3F<LnSfEvaluator3F<Normal>>;
+
+/// ScalarUDF for the Normal log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("normal_ln_sf"))
+}
+
+type Quantile = Continuous3F<InverseCdfEvaluator3F<Normal>>;
+
+/// ScalarUDF for the Normal quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("normal_quantile"))
+}
+
+type InverseSf = Continuous3F<InverseSfEvaluator3F<Normal>>;
+
+/// ScalarUDF for the Normal inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("normal_inverse_sf"))
+}
+
+type Interval = Interval2F<Normal>;
+
+/// ScalarUDF for the Normal central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("normal_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<Normal>>;
+
+/// ScalarUDF for the Normal mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("normal_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<Normal>>;
+
+/// ScalarUDF for the Normal variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("normal_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<Normal>>;
+
+/// ScalarUDF for the Normal standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("normal_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<Normal>>;
+
+/// ScalarUDF for the Normal skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("normal_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<Normal>>;
+
+/// ScalarUDF for the Normal entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("normal_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<Normal>>;
+
+/// ScalarUDF for the Normal mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("normal_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<Normal>>;
+
+/// ScalarUDF for the Normal median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("normal_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<Normal>>;
+
+/// ScalarUDF for the Normal support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("normal_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<Normal>>;
+
+/// ScalarUDF for the Normal support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("normal_support_max"))
+}
+
+type Random = Random2F<Normal>;
+
+/// ScalarUDF for the Normal random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("normal_random"))
+}
+
+type Fit = FitAggregate<Normal>;
+
+/// AggregateUDF for the Normal maximum likelihood fit
+pub fn fit() -> AggregateUDF {
+    AggregateUDF::from(Fit::new("normal_fit"))
+}
+
 /// Register the functions for the Normal Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register_aggregates(registry, vec![fit()])?;
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -81,12 +247,13 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
-            datatypes::{DataType, Field, Schema, SchemaRef},
+            array::{Array, AsArray, Float64Array, Int64Array, RecordBatch},
+            datatypes::{DataType, Field, Int64Type, Schema, SchemaRef},
         },
-        common::cast::as_float64_array,
+        common::cast::{as_float64_array, as_struct_array, as_uint64_array},
+        datasource::MemTable,
         error::DataFusionError,
-        prelude::{SessionContext, col},
+        prelude::{SessionConfig, SessionContext, col},
     };
     use statrs::distribution::NormalError;
 
@@ -150,8 +317,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 2.0209084334147568e-14);
         assert_eq_float!(res_col.value(1), 0.0005353209030595414);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -172,12 +339,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<NormalError>().unwrap();
-                assert_eq!(*be.as_ref(), NormalError::StandardDeviationInvalid);
+                assert!(e.to_string().starts_with("normal_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<NormalError>().unwrap();
+                assert_eq!(*be, NormalError::StandardDeviationInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -229,8 +396,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 6.220960574599358e-16);
         assert_eq_float!(res_col.value(1), 3.167124183566376e-5);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -262,7 +429,493 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.9999999999999993);
         assert_eq_float!(res_col.value(1), 0.9999683287581643);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn normal_ln_cdf_ln_sf_tails() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_ln_sf(40.0, 0.0, 1.0), normal_ln_cdf(-40.0, 0.0, 1.0), normal_ln_cdf(1.0, 1.0, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 3);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(
+            as_float64_array(res[0].column(0)).unwrap().value(0),
+            -804.6084420137538
+        );
+        assert_eq_float!(
+            as_float64_array(res[0].column(1)).unwrap().value(0),
+            -804.6084420137538
+        );
+        assert_eq_float!(
+            as_float64_array(res[0].column(2)).unwrap().value(0),
+            -std::f64::consts::LN_2
+        );
+    }
+
+    #[tokio::test]
+    async fn normal_cdf_numeric_arguments() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_cdf(CAST(1.5 AS DECIMAL(10, 2)), CAST(1.5 AS FLOAT), 2)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 0.5);
+    }
+
+    #[tokio::test]
+    async fn normal_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_quantile(0.975, 0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.959963984540054);
+    }
+
+    #[tokio::test]
+    async fn normal_quantile_failure() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_quantile(0.5, 0.0, -1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("normal_quantile: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<NormalError>().unwrap();
+                assert_eq!(*be, NormalError::StandardDeviationInvalid);
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn normal_quantile_out_of_range() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_quantile(1.5, 0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert!(res_col.value(0).is_nan());
+    }
+
+    #[tokio::test]
+    async fn normal_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_inverse_sf(1e-300, 0.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 37.0470962993612);
+    }
+
+    #[tokio::test]
+    async fn normal_moments_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_mean(1.0, 2.0), normal_variance(1.0, 2.0), normal_std_dev(1.0, 2.0), normal_skewness(1.0, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 4);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(as_float64_array(res[0].column(0)).unwrap().value(0), 1.0);
+        assert_eq_float!(as_float64_array(res[0].column(1)).unwrap().value(0), 4.0);
+        assert_eq_float!(as_float64_array(res[0].column(2)).unwrap().value(0), 2.0);
+        assert_eq_float!(as_float64_array(res[0].column(3)).unwrap().value(0), 0.0);
+    }
+
+    #[tokio::test]
+    async fn normal_moments_failure() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_mean(0.0, -1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("normal_mean: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<NormalError>().unwrap();
+                assert_eq!(*be, NormalError::StandardDeviationInvalid);
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn normal_mean_columns() {
+        let mean = mean();
+
+        let recs = make_records(vec![
+            (Some(0.0), Some(1.0), Some(2.0)),
+            (Some(0.0), None, Some(2.0)),
+            (Some(0.0), Some(-3.0), Some(0.5)),
+        ]);
+
+        let ctx = SessionContext::new();
+        ctx.register_batch("tbl", recs).unwrap();
+        let df = ctx.table("tbl").await.unwrap();
+        let res = df
+            .select(vec![(mean.call(vec![col("s"), col("r")])).alias("q")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 3);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.0);
+        assert!(res_col.is_null(1));
+        assert_eq_float!(res_col.value(2), -3.0);
+    }
+
+    #[tokio::test]
+    async fn normal_median_support_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_mode(1.0, 2.0), normal_median(1.0, 2.0), normal_support_min(1.0, 2.0), normal_support_max(1.0, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 4);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(as_float64_array(res[0].column(0)).unwrap().value(0), 1.0);
+        assert_eq_float!(as_float64_array(res[0].column(1)).unwrap().value(0), 1.0);
+        assert_eq!(
+            as_float64_array(res[0].column(2)).unwrap().value(0),
+            f64::NEG_INFINITY
+        );
+        assert_eq!(
+            as_float64_array(res[0].column(3)).unwrap().value(0),
+            f64::INFINITY
+        );
+    }
+
+    #[tokio::test]
+    async fn normal_random_seeded() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_random(0.0, 1.0, 42) AS a, normal_random(0.0, 1.0, 42) AS b, normal_random(0.0, 1.0, 43) AS c")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 3);
+        assert_eq!(res[0].num_rows(), 1);
+        let first = as_float64_array(res[0].column(0)).unwrap().value(0);
+        assert_eq!(as_float64_array(res[0].column(1)).unwrap().value(0), first);
+        assert_ne!(as_float64_array(res[0].column(2)).unwrap().value(0), first);
+    }
+
+    #[tokio::test]
+    async fn normal_random_seeded_batching() {
+        let sql = "SELECT normal_random(1.0, 2.0, value) FROM generate_series(1, 10)";
+        let mut values = Vec::new();
+        for batch_size in [3, 8192] {
+            let mut ctx =
+                SessionContext::new_with_config(SessionConfig::new().with_batch_size(batch_size));
+            register(&mut ctx).unwrap();
+            let res = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+            let column: Vec<f64> = res
+                .iter()
+                .flat_map(|b| as_float64_array(b.column(0)).unwrap().values().to_vec())
+                .collect();
+            values.push(column);
+        }
+        assert_eq!(values[0].len(), 10);
+        assert_eq!(values[0], values[1]);
+    }
+
+    #[tokio::test]
+    async fn normal_random_seeded_literal() {
+        // A literal seed is the seed of every row, like a column of it.
+        let sql = "SELECT normal_random(1.0, 2.0, 42), normal_random(1.0, 2.0, value * 0 + 42), normal_random(value, 2.0, 42) - value FROM generate_series(1, 1000)";
+        let mut ctx = SessionContext::new_with_config(SessionConfig::new().with_batch_size(64));
+        register(&mut ctx).unwrap();
+        let res = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+        let column = |j: usize| -> Vec<f64> {
+            res.iter()
+                .flat_map(|b| as_float64_array(b.column(j)).unwrap().values().to_vec())
+                .collect()
+        };
+        let (literal, seeds, shifted) = (column(0), column(1), column(2));
+        assert_eq!(literal.len(), 1000);
+        assert!(literal.iter().all(|&v| v == literal[0]));
+        assert_eq!(literal, seeds);
+        for v in shifted {
+            assert_eq_float!(v, literal[0] - 1.0, 1e-12);
+        }
+    }
+
+    /// `sql` over an eight-partition table `t` of ids, read in `target_partitions`, as
+    /// (id, value) sorted by id.
+    async fn random_partitioned(sql: &str, target_partitions: usize) -> Vec<(i64, f64)> {
+        let config = SessionConfig::new()
+            .with_target_partitions(target_partitions)
+            .with_batch_size(500);
+        let mut ctx = SessionContext::new_with_config(config);
+        register(&mut ctx).unwrap();
+        let schema = SchemaRef::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+        let partitions = (0..8)
+            .map(|p| {
+                let ids = Int64Array::from_iter_values(p * 2500..(p + 1) * 2500);
+                vec![RecordBatch::try_new(schema.clone(), vec![Arc::new(ids)]).unwrap()]
+            })
+            .collect();
+        let table = MemTable::try_new(schema, partitions).unwrap();
+        ctx.register_table("t", Arc::new(table)).unwrap();
+        let res = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+        let mut rows: Vec<(i64, f64)> = res
+            .iter()
+            .flat_map(|b| {
+                let ids = b.column(0).as_primitive::<Int64Type>().values().to_vec();
+                let values = as_float64_array(b.column(1)).unwrap().values().to_vec();
+                ids.into_iter().zip(values)
+            })
+            .collect();
+        rows.sort_unstable_by_key(|&(id, _)| id);
+        rows
+    }
+
+    #[tokio::test]
+    async fn normal_random_seeded_partitioned() {
+        // Every row gets the same value on every run, however the table is partitioned.
+        for sql in [
+            "SELECT id, normal_random(0.0, 1.0, id) FROM t",
+            "SELECT id, normal_random(0.0, 1.0, id * 7919 + 42) FROM t WHERE id % 3 <> 0",
+            "SELECT id, normal_random(CAST(id AS DOUBLE), 1.0, 42) FROM t",
+        ] {
+            let first = random_partitioned(sql, 8).await;
+            assert!(first.len() > 10000);
+            for _ in 0..3 {
+                assert_eq!(first, random_partitioned(sql, 8).await);
+            }
+            assert_eq!(first, random_partitioned(sql, 1).await);
+        }
+    }
+
+    #[tokio::test]
+    async fn normal_random_failure() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_random(0.0, -1.0, 42)")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("normal_random: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<NormalError>().unwrap();
+                assert_eq!(*be, NormalError::StandardDeviationInvalid);
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn normal_random_unseeded() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT avg(r), min(r) < max(r) FROM (SELECT normal_random(5.0, 1.0) AS r FROM generate_series(1, 1000))")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let mean = as_float64_array(res[0].column(0)).unwrap().value(0);
+        assert!((mean - 5.0).abs() < 0.2);
+        assert!(res[0].column(1).as_boolean().value(0));
+    }
+
+    #[tokio::test]
+    async fn normal_interval_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_interval(0.95, 1.0, 2.0) AS i, normal_interval(0.95, 1.0, 2.0)['upper'] AS u")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        let interval = as_struct_array(res[0].column(0)).unwrap();
+        let lower = as_float64_array(interval.column_by_name("lower").unwrap()).unwrap();
+        let upper = as_float64_array(interval.column_by_name("upper").unwrap()).unwrap();
+        assert_eq_float!(lower.value(0), -2.919927969080108);
+        assert_eq_float!(upper.value(0), 4.919927969080108);
+        assert_eq_float!(
+            as_float64_array(res[0].column(1)).unwrap().value(0),
+            4.919927969080108
+        );
+    }
+
+    #[tokio::test]
+    async fn normal_interval_failure() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_interval(0.9, 0.0, -1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("normal_interval: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<NormalError>().unwrap();
+                assert_eq!(*be, NormalError::StandardDeviationInvalid);
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn normal_fit_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_fit(x) FROM (VALUES (0.5), (1.2), (2.3), (3.1), (4.8), (0.9), (1.7)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("mean").unwrap())
+                .unwrap()
+                .value(0),
+            2.0714285714285716
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("std_dev").unwrap())
+                .unwrap()
+                .value(0),
+            1.3781087306411595
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+                .unwrap()
+                .value(0),
+            -12.177554251503276
+        );
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            7
+        );
+    }
+
+    #[tokio::test]
+    async fn normal_fit_partitioned() {
+        let config = SessionConfig::new()
+            .with_target_partitions(4)
+            .with_batch_size(100);
+        let mut ctx = SessionContext::new_with_config(config);
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT normal_fit(CAST(value AS DOUBLE)) FROM generate_series(1, 10000)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("mean").unwrap())
+                .unwrap()
+                .value(0),
+            5000.5
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("std_dev").unwrap())
+                .unwrap()
+                .value(0),
+            2886.751331514372
+        );
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            10000
+        );
     }
 }
@@ -1,28 +1,46 @@
 //! Module containing functions to the Pareto Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Pareto`].
-//! 
+//!
 //! The [Pareto Distribution](https://en.wikipedia.org/wiki/Pareto_distribution) has two
 //! parameters:
-//! 
+//!
 //! x_m: 0 < x_m  
 //! α: 0 < α
-//! 
+//!
 //! NB There are two parameterisations of Pareto (α, θ) and (α, λ) with λ = 1/θ.
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `pareto_pdf(x, x_m, α)`  
 //! `pareto_ln_pdf(x, x_m, α)`  
 //! `pareto_cdf(x, x_m, α)`  
-//! `pareto_sf(x, x_m, α)`
-//! 
+//! `pareto_sf(x, x_m, α)`  
+//! `pareto_ln_cdf(x, x_m, α)`  
+//! `pareto_ln_sf(x, x_m, α)`  
+//! `pareto_quantile(p, x_m, α)`  
+//! `pareto_inverse_sf(p, x_m, α)`  
+//! `pareto_interval(level, x_m, α)`  
+//! `pareto_mean(x_m, α)`  
+//! `pareto_variance(x_m, α)`  
+//! `pareto_std_dev(x_m, α)`  
+//! `pareto_skewness(x_m, α)`  
+//! `pareto_entropy(x_m, α)`  
+//! `pareto_mode(x_m, α)`  
+//! `pareto_median(x_m, α)`  
+//! `pareto_support_min(x_m, α)`  
+//! `pareto_support_max(x_m, α)`  
+//! `pareto_random(x_m, α[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `x`: [x_m, +∞) `Float64`/`DOUBLE`,  
 //!   `x_m`: (0, +∞) `Float64`/`DOUBLE`,  
-//!   `α`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `α`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -41,7 +59,17 @@ use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::Pareto;
 
 use super::super::utils::continuous3f::Continuous3F;
-use super::super::utils::evaluator3f::{CdfEvaluator3F, LnPdfEvaluator3F, PdfEvaluator3F, SfEvaluator3F};
+use super::super::utils::evaluator3f::{
+    CdfEvaluator3F, InverseCdfEvaluator3F, InverseSfEvaluator3F, LnCdfEvaluator3F,
+    LnPdfEvaluator3F, LnSfEvaluator3F, PdfEvaluator3F, SfEvaluator3F,
+};
+use super::super::utils::interval2f::Interval2F;
+use super::super::utils::random2f::Random2F;
+use super::super::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use super::super::utils::statistic2f::Statistic2F;
 
 type Pdf = Continuous3F<PdfEvaluator3F<Pareto>>;
 
@@ -71,9 +99,137 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("pareto_sf"))
 }
 
+type LnCdf = Continuous3F<LnCdfEvaluator3F<Pareto>>;
+
+/// ScalarUDF for the Pareto log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("pareto_ln_cdf"))
+}
+
+type LnSf = Continuous3F<LnSfEvaluator3F<Pareto>>;
+
+/// ScalarUDF for the Pareto log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("pareto_ln_sf"))
+}
+
+type Quantile = Continuous3F<InverseCdfEvaluator3F<Pareto>>;
+
+/// ScalarUDF for the Pareto quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("pareto_quantile"))
+}
+
+type InverseSf = Continuous3F<InverseSfEvaluator3F<Pareto>>;
+
+/// ScalarUDF for the Pareto inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("pareto_inverse_sf"))
+}
+
+type Interval = Interval2F<Pareto>;
+
+/// ScalarUDF for the Pareto central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("pareto_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<Pareto>>;
+
+/// ScalarUDF for the Pareto mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("pareto_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<Pareto>>;
+
+/// ScalarUDF for the Pareto variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("pareto_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<Pareto>>;
+
+/// ScalarUDF for the Pareto standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("pareto_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<Pareto>>;
+
+/// ScalarUDF for the Pareto skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("pareto_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<Pareto>>;
+
+/// ScalarUDF for the Pareto entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("pareto_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<Pareto>>;
+
+/// ScalarUDF for the Pareto mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("pareto_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<Pareto>>;
+
+/// ScalarUDF for the Pareto median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("pareto_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<Pareto>>;
+
+/// ScalarUDF for the Pareto support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("pareto_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<Pareto>>;
+
+/// ScalarUDF for the Pareto support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("pareto_support_max"))
+}
+
+type Random = Random2F<Pareto>;
+
+/// ScalarUDF for the Pareto random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("pareto_random"))
+}
+
 /// Register the functions for the Pareto Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -83,7 +239,7 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -152,8 +308,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.058162803693881215);
         assert_eq_float!(res_col.value(1), 0.044005586839669666);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -174,12 +330,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<ParetoError>().unwrap();
-                assert_eq!(*be.as_ref(), ParetoError::ScaleInvalid);
+                assert!(e.to_string().starts_with("pareto_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<ParetoError>().unwrap();
+                assert_eq!(*be, ParetoError::ScaleInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -231,8 +387,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.06939514089790044);
         assert_eq_float!(res_col.value(1), 0.11988826320660662);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -264,7 +420,43 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.9306048591020996);
         assert_eq_float!(res_col.value(1), 0.8801117367933934);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn pareto_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT pareto_quantile(0.5, 1.0, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), std::f64::consts::SQRT_2);
+    }
+
+    #[tokio::test]
+    async fn pareto_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT pareto_inverse_sf(1e-10, 1.0, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 100000.0);
     }
 }
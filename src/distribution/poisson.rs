@@ -1,31 +1,50 @@
 //! Module containing functions to the Poisson Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Poisson`].
-//! 
+//!
 //! The [Poisson Distribution](https://en.wikipedia.org/wiki/Poisson_distribution) has two
 //! parameters:
-//! 
+//!
 //! λ: 0 < λ
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `poisson_pmf(x, λ)`  
 //! `poisson_ln_pmf(x, λ)`  
 //! `poisson_cdf(x, λ)`  
-//! `poisson_sf(x, λ)`
-//! 
+//! `poisson_sf(x, λ)`  
+//! `poisson_ln_cdf(x, λ)`  
+//! `poisson_ln_sf(x, λ)`  
+//! `poisson_quantile(q, λ)`  
+//! `poisson_inverse_sf(q, λ)`  
+//! `poisson_interval(level, λ)`  
+//! `poisson_mean(λ)`  
+//! `poisson_variance(λ)`  
+//! `poisson_std_dev(λ)`  
+//! `poisson_skewness(λ)`  
+//! `poisson_entropy(λ)`  
+//! `poisson_mode(λ)`  
+//! `poisson_median(λ)`  
+//! `poisson_support_min(λ)`  
+//! `poisson_support_max(λ)`  
+//! `poisson_random(λ[, seed])`  
+//! `poisson_fit(x)` (aggregate)
+//!
 //! with
-//! 
+//!
 //!   `x`: 0 ≤ x `UInt64`/`BIGINT UNSIGNED`,  
-//!   `λ`: 0 < λ `Float64`/`DOUBLE`
-//! 
+//!   `λ`: 0 < λ `Float64`/`DOUBLE`,  
+//!   `q`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
 //! async fn main() -> std::io::Result<()> {
 //!     let mut ctx = datafusion::prelude::SessionContext::new();
 //!     datafusion_statrs::distribution::poisson::register(&mut ctx)?;
-//!     ctx.sql("SELECT poisson_cdf(CAST(2 AS BIGINT UNSIGNED), 0.2)").await?
+//!     ctx.sql("SELECT poisson_cdf(2, 0.2)").await?
 //!        .show().await?;
 //!     Ok(())
 //! }
@@ -33,11 +52,22 @@
 
 use datafusion::error::DataFusionError;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
 use statrs::distribution::Poisson;
 
-use super::super::utils::discrete1u1f::Discrete1U1F;
-use super::super::utils::evaluator1u1f::{CdfEvaluator1U1F, LnPmfEvaluator1U1F, PmfEvaluator1U1F, SfEvaluator1U1F};
+use super::super::utils::discrete1u1f::{Discrete1U1F, DiscreteInverse1U1F};
+use super::super::utils::evaluator1u1f::{
+    CdfEvaluator1U1F, InverseCdfEvaluator1U1F, InverseSfEvaluator1U1F, LnCdfEvaluator1U1F,
+    LnPmfEvaluator1U1F, LnSfEvaluator1U1F, PmfEvaluator1U1F, SfEvaluator1U1F,
+};
+use super::super::utils::fit::CountFitAggregate;
+use super::super::utils::interval1f::Interval1F;
+use super::super::utils::random1f::Random1F;
+use super::super::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use super::super::utils::statistic1f::Statistic1F;
 
 type Pmf = Discrete1U1F<PmfEvaluator1U1F<Poisson>>;
 
@@ -67,9 +97,145 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("poisson_sf"))
 }
 
+type LnCdf = Discrete1U1F<LnCdfEvaluator1U1F<Poisson>>;
+
+/// ScalarUDF for the Poisson log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("poisson_ln_cdf"))
+}
+
+type LnSf = Discrete1U1F<LnSfEvaluator1U1F<Poisson>>;
+
+/// ScalarUDF for the Poisson log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("poisson_ln_sf"))
+}
+
+type Quantile = DiscreteInverse1U1F<InverseCdfEvaluator1U1F<Poisson>>;
+
+/// ScalarUDF for the Poisson quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("poisson_quantile"))
+}
+
+type InverseSf = DiscreteInverse1U1F<InverseSfEvaluator1U1F<Poisson>>;
+
+/// ScalarUDF for the Poisson inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("poisson_inverse_sf"))
+}
+
+type Interval = Interval1F<Poisson>;
+
+/// ScalarUDF for the Poisson central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("poisson_interval"))
+}
+
+type Mean = Statistic1F<MeanStatistic<Poisson>>;
+
+/// ScalarUDF for the Poisson mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("poisson_mean"))
+}
+
+type Variance = Statistic1F<VarianceStatistic<Poisson>>;
+
+/// ScalarUDF for the Poisson variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("poisson_variance"))
+}
+
+type StdDev = Statistic1F<StdDevStatistic<Poisson>>;
+
+/// ScalarUDF for the Poisson standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("poisson_std_dev"))
+}
+
+type Skewness = Statistic1F<SkewnessStatistic<Poisson>>;
+
+/// ScalarUDF for the Poisson skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("poisson_skewness"))
+}
+
+type Entropy = Statistic1F<EntropyStatistic<Poisson>>;
+
+/// ScalarUDF for the Poisson entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("poisson_entropy"))
+}
+
+type Mode = Statistic1F<ModeStatistic<Poisson>>;
+
+/// ScalarUDF for the Poisson mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("poisson_mode"))
+}
+
+type Median = Statistic1F<MedianStatistic<Poisson>>;
+
+/// ScalarUDF for the Poisson median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("poisson_median"))
+}
+
+type SupportMin = Statistic1F<SupportMinStatistic<Poisson>>;
+
+/// ScalarUDF for the Poisson support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("poisson_support_min"))
+}
+
+type SupportMax = Statistic1F<SupportMaxStatistic<Poisson>>;
+
+/// ScalarUDF for the Poisson support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("poisson_support_max"))
+}
+
+type Random = Random1F<Poisson>;
+
+/// ScalarUDF for the Poisson random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("poisson_random"))
+}
+
+type Fit = CountFitAggregate<Poisson>;
+
+/// AggregateUDF for the Poisson Distribution maximum likelihood fit
+pub fn fit() -> AggregateUDF {
+    AggregateUDF::from(Fit::new("poisson_fit"))
+}
+
 /// Register the functions for the Poisson Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pmf(), ln_pmf(), cdf(), sf()])
+    crate::utils::register::register_aggregates(registry, vec![fit()])?;
+    crate::utils::register::register(
+        registry,
+        vec![
+            pmf(),
+            ln_pmf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -79,10 +245,10 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch, UInt64Array},
+            array::{Array, Float64Array, RecordBatch, UInt64Array},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
-        common::cast::as_float64_array,
+        common::cast::{as_float64_array, as_struct_array, as_uint64_array},
         error::DataFusionError,
         prelude::{SessionContext, col},
     };
@@ -142,8 +308,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.7788007830714049);
         assert_eq_float!(res_col.value(1), 6.337896997651408e-6);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -162,12 +328,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<PoissonError>().unwrap();
-                assert_eq!(*be.as_ref(), PoissonError::LambdaInvalid);
+                assert!(e.to_string().starts_with("poisson_pmf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<PoissonError>().unwrap();
+                assert_eq!(*be, PoissonError::LambdaInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -217,8 +383,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.7788007830714048);
         assert_eq_float!(res_col.value(1), 0.9999997261864366);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -248,7 +414,241 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.2211992169285952);
         assert_eq_float!(res_col.value(1), 2.738135633828412e-7);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn poisson_ln_sf_tail() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT poisson_ln_sf(200, 4.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(
+            as_float64_array(res[0].column(0)).unwrap().value(0),
+            -570.6932087915926
+        );
+    }
+
+    #[tokio::test]
+    async fn poisson_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT poisson_quantile(0.99, 10.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 18);
+    }
+
+    #[tokio::test]
+    async fn poisson_quantile_one() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT poisson_quantile(1.0, 3.0), poisson_support_max(3.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(
+            as_uint64_array(res[0].column(0)).unwrap().value(0),
+            u64::MAX
+        );
+        assert_eq!(
+            as_uint64_array(res[0].column(1)).unwrap().value(0),
+            u64::MAX
+        );
+    }
+
+    #[tokio::test]
+    async fn poisson_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT poisson_inverse_sf(1e-12, 10.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(res_col.value(0), 39);
+    }
+
+    #[tokio::test]
+    async fn poisson_inverse_sf_zero() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT poisson_inverse_sf(0.0, 3.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(
+            as_uint64_array(res[0].column(0)).unwrap().value(0),
+            u64::MAX
+        );
+    }
+
+    #[tokio::test]
+    async fn poisson_median_small_rate() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT poisson_median(0.01), poisson_median(0.05), poisson_median(0.7), poisson_median(4.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq!(as_uint64_array(res[0].column(0)).unwrap().value(0), 0);
+        assert_eq!(as_uint64_array(res[0].column(1)).unwrap().value(0), 0);
+        assert_eq!(as_uint64_array(res[0].column(2)).unwrap().value(0), 1);
+        assert_eq!(as_uint64_array(res[0].column(3)).unwrap().value(0), 4);
+    }
+
+    #[tokio::test]
+    async fn poisson_random_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT poisson_random(3.0, 7) AS a, poisson_random(3.0, 7) AS b, poisson_random(NULL, 7), poisson_random(3.0, NULL)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 4);
+        assert_eq!(res[0].num_rows(), 1);
+        let first = as_uint64_array(res[0].column(0)).unwrap().value(0);
+        assert_eq!(as_uint64_array(res[0].column(1)).unwrap().value(0), first);
+        assert!(res[0].column(2).is_null(0));
+        assert!(res[0].column(3).is_null(0));
+    }
+
+    #[tokio::test]
+    async fn poisson_interval_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT poisson_interval(level, 4.5) FROM (VALUES (0.95), (1.5), (NULL), (0.0)) AS t(level)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 4);
+        let interval = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq!(
+            interval.data_type(),
+            &DataType::Struct(
+                vec![
+                    Field::new("lower", DataType::UInt64, false),
+                    Field::new("upper", DataType::UInt64, false),
+                ]
+                .into()
+            )
+        );
+        let lower = as_uint64_array(interval.column_by_name("lower").unwrap()).unwrap();
+        let upper = as_uint64_array(interval.column_by_name("upper").unwrap()).unwrap();
+        assert_eq!((lower.value(0), upper.value(0)), (1, 9));
+        assert!(interval.is_null(1));
+        assert!(interval.is_null(2));
+        assert_eq!((lower.value(3), upper.value(3)), (4, 4));
+    }
+
+    #[tokio::test]
+    async fn poisson_fit_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT poisson_fit(x) FROM (VALUES (2), (0), (3), (NULL), (1), (4), (2), (2)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("lambda").unwrap())
+                .unwrap()
+                .value(0),
+            2.0
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+                .unwrap()
+                .value(0),
+            -11.345194313416602
+        );
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            7
+        );
+    }
+
+    #[tokio::test]
+    async fn poisson_fit_no_estimate() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT poisson_fit(x) FROM (VALUES (0), (0), (0)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert!(res[0].column(0).is_null(0));
+
+        let res = ctx
+            .sql("SELECT poisson_fit(x) FROM (VALUES (2), (-1)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        match res {
+            Err(DataFusionError::Execution(msg)) => {
+                assert_eq!(msg, "poisson_fit: argument 1 must not be negative, got -1");
+            }
+            _ => {
+                panic!("unexpected result: {:?}", res);
+            }
+        }
     }
 }
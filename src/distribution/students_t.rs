@@ -1,28 +1,46 @@
 //! Module containing functions to the Student's T Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::StudentsT`].
-//! 
+//!
 //! The [Student's T Distribution](https://en.wikipedia.org/wiki/Student%27s_t-distribution) has three
 //! parameters:
-//! 
-//! μ: μ ∈ R (real numbers) 
+//!
+//! μ: μ ∈ R (real numbers)
 //! σ: 0 < σ  
 //! ν: 0 < ν
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `students_t_pdf(x, μ, σ, ν)`  
 //! `students_t_ln_pdf(x, μ, σ, ν)`  
 //! `students_t_cdf(x, μ, σ, ν)`  
-//! `students_t_sf(x, μ, σ, ν)`
-//! 
+//! `students_t_sf(x, μ, σ, ν)`  
+//! `students_t_ln_cdf(x, μ, σ, ν)`  
+//! `students_t_ln_sf(x, μ, σ, ν)`  
+//! `students_t_quantile(p, μ, σ, ν)`  
+//! `students_t_inverse_sf(p, μ, σ, ν)`  
+//! `students_t_interval(level, μ, σ, ν)`  
+//! `students_t_mean(μ, σ, ν)`  
+//! `students_t_variance(μ, σ, ν)`  
+//! `students_t_std_dev(μ, σ, ν)`  
+//! `students_t_skewness(μ, σ, ν)`  
+//! `students_t_entropy(μ, σ, ν)`  
+//! `students_t_mode(μ, σ, ν)`  
+//! `students_t_median(μ, σ, ν)`  
+//! `students_t_support_min(μ, σ, ν)`  
+//! `students_t_support_max(μ, σ, ν)`  
+//! `students_t_random(μ, σ, ν[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `x`: (-∞, +∞) `Float64`/`DOUBLE`,  
 //!   `μ`: (-∞, +∞) `Float64`/`DOUBLE`,  
 //!   `σ`: (0, +∞) `Float64`/`DOUBLE`,  
-//!   `ν`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `ν`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -41,7 +59,17 @@ use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::StudentsT;
 
 use crate::utils::continuous4f::Continuous4F;
-use crate::utils::evaluator4f::{CdfEvaluator4F, LnPdfEvaluator4F, PdfEvaluator4F, SfEvaluator4F};
+use crate::utils::evaluator4f::{
+    CdfEvaluator4F, InverseCdfEvaluator4F, InverseSfEvaluator4F, LnCdfEvaluator4F,
+    LnPdfEvaluator4F, LnSfEvaluator4F, PdfEvaluator4F, SfEvaluator4F,
+};
+use crate::utils::interval3f::Interval3F;
+use crate::utils::random3f::Random3F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic3f::Statistic3F;
 
 type Pdf = Continuous4F<PdfEvaluator4F<StudentsT>>;
 
@@ -71,9 +99,137 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("students_t_sf"))
 }
 
+type LnCdf = Continuous4F<LnCdfEvaluator4F<StudentsT>>;
+
+/// ScalarUDF for the Student's T log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("students_t_ln_cdf"))
+}
+
+type LnSf = Continuous4F<LnSfEvaluator4F<StudentsT>>;
+
+/// ScalarUDF for the Student's T log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("students_t_ln_sf"))
+}
+
+type Quantile = Continuous4F<InverseCdfEvaluator4F<StudentsT>>;
+
+/// ScalarUDF for the Student's T quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("students_t_quantile"))
+}
+
+type InverseSf = Continuous4F<InverseSfEvaluator4F<StudentsT>>;
+
+/// ScalarUDF for the Student's T inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("students_t_inverse_sf"))
+}
+
+type Interval = Interval3F<StudentsT>;
+
+/// ScalarUDF for the Student's T central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("students_t_interval"))
+}
+
+type Mean = Statistic3F<MeanStatistic<StudentsT>>;
+
+/// ScalarUDF for the Student's T mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("students_t_mean"))
+}
+
+type Variance = Statistic3F<VarianceStatistic<StudentsT>>;
+
+/// ScalarUDF for the Student's T variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("students_t_variance"))
+}
+
+type StdDev = Statistic3F<StdDevStatistic<StudentsT>>;
+
+/// ScalarUDF for the Student's T standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("students_t_std_dev"))
+}
+
+type Skewness = Statistic3F<SkewnessStatistic<StudentsT>>;
+
+/// ScalarUDF for the Student's T skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("students_t_skewness"))
+}
+
+type Entropy = Statistic3F<EntropyStatistic<StudentsT>>;
+
+/// ScalarUDF for the Student's T entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("students_t_entropy"))
+}
+
+type Mode = Statistic3F<ModeStatistic<StudentsT>>;
+
+/// ScalarUDF for the Student's T mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("students_t_mode"))
+}
+
+type Median = Statistic3F<MedianStatistic<StudentsT>>;
+
+/// ScalarUDF for the Student's T median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("students_t_median"))
+}
+
+type SupportMin = Statistic3F<SupportMinStatistic<StudentsT>>;
+
+/// ScalarUDF for the Student's T support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("students_t_support_min"))
+}
+
+type SupportMax = Statistic3F<SupportMaxStatistic<StudentsT>>;
+
+/// ScalarUDF for the Student's T support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("students_t_support_max"))
+}
+
+type Random = Random3F<StudentsT>;
+
+/// ScalarUDF for the Student's T random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("students_t_random"))
+}
+
 /// Register the functions for the Student's T Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -83,7 +239,7 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -103,9 +259,9 @@ mod tests {
         ]))
     }
 
-    fn make_records(
-        rows: Vec<(Option<f64>, Option<f64>, Option<f64>, Option<f64>)>,
-    ) -> RecordBatch {
+    type Row = (Option<f64>, Option<f64>, Option<f64>, Option<f64>);
+
+    fn make_records(rows: Vec<Row>) -> RecordBatch {
         let mut xs = Vec::new();
         let mut ls = Vec::new();
         let mut ss = Vec::new();
@@ -158,8 +314,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.019588300688233265);
         assert_eq_float!(res_col.value(1), 0.07489644380795071);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -180,12 +336,16 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<StudentsTError>().unwrap();
-                assert_eq!(*be.as_ref(), StudentsTError::FreedomInvalid);
+                assert!(e.to_string().starts_with("students_t_pdf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<StudentsTError>()
+                    .unwrap();
+                assert_eq!(*be, StudentsTError::FreedomInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -237,8 +397,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.039583424160565546);
         assert_eq_float!(res_col.value(1), 0.07797913037736928);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -270,7 +430,43 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.9604165758394344);
         assert_eq_float!(res_col.value(1), 0.9220208696226307);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn students_t_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT students_t_quantile(0.95, 0.0, 1.0, 10.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.8124611228116765);
+    }
+
+    #[tokio::test]
+    async fn students_t_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT students_t_inverse_sf(1e-12, 0.0, 1.0, 10.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 40.5320961786626);
     }
 }
@@ -1,28 +1,46 @@
 //! Module containing functions to the Triangular Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Triangular`].
-//! 
+//!
 //! The [Triangular Distribution](https://en.wikipedia.org/wiki/Triangular_distribution) has three
 //! parameters:
-//! 
-//! a: a ∈ R (real numbers) 
-//! b: a < b 
+//!
+//! a: a ∈ R (real numbers)
+//! b: a < b
 //! c: a ≤ c ≤ b
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `triangular_pdf(x, a, b, c)`  
 //! `triangular_ln_pdf(x, a, b, c)`  
 //! `triangular_cdf(x, a, b, c)`  
-//! `triangular_sf(x, a, b, c)`
-//! 
+//! `triangular_sf(x, a, b, c)`  
+//! `triangular_ln_cdf(x, a, b, c)`  
+//! `triangular_ln_sf(x, a, b, c)`  
+//! `triangular_quantile(p, a, b, c)`  
+//! `triangular_inverse_sf(p, a, b, c)`  
+//! `triangular_interval(level, a, b, c)`  
+//! `triangular_mean(a, b, c)`  
+//! `triangular_variance(a, b, c)`  
+//! `triangular_std_dev(a, b, c)`  
+//! `triangular_skewness(a, b, c)`  
+//! `triangular_entropy(a, b, c)`  
+//! `triangular_mode(a, b, c)`  
+//! `triangular_median(a, b, c)`  
+//! `triangular_support_min(a, b, c)`  
+//! `triangular_support_max(a, b, c)`  
+//! `triangular_random(a, b, c[, seed])`
+//!
 //! with
-//! 
+//!
 //!   `x`: [a, b] `Float64`/`DOUBLE`,  
 //!   `a`: (-∞, +∞) `Float64`/`DOUBLE`,  
 //!   `b`: (a, +∞) `Float64`/`DOUBLE`,  
-//!   `c`: [a, b] `Float64`/`DOUBLE`
-//! 
+//!   `c`: [a, b] `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -41,7 +59,17 @@ use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::Triangular;
 
 use crate::utils::continuous4f::Continuous4F;
-use crate::utils::evaluator4f::{CdfEvaluator4F, LnPdfEvaluator4F, PdfEvaluator4F, SfEvaluator4F};
+use crate::utils::evaluator4f::{
+    CdfEvaluator4F, InverseCdfEvaluator4F, InverseSfEvaluator4F, LnCdfEvaluator4F,
+    LnPdfEvaluator4F, LnSfEvaluator4F, PdfEvaluator4F, SfEvaluator4F,
+};
+use crate::utils::interval3f::Interval3F;
+use crate::utils::random3f::Random3F;
+use crate::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use crate::utils::statistic3f::Statistic3F;
 
 type Pdf = Continuous4F<PdfEvaluator4F<Triangular>>;
 
@@ -71,9 +99,137 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("triangular_sf"))
 }
 
+type LnCdf = Continuous4F<LnCdfEvaluator4F<Triangular>>;
+
+/// ScalarUDF for the Triangular log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("triangular_ln_cdf"))
+}
+
+type LnSf = Continuous4F<LnSfEvaluator4F<Triangular>>;
+
+/// ScalarUDF for the Triangular log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("triangular_ln_sf"))
+}
+
+type Quantile = Continuous4F<InverseCdfEvaluator4F<Triangular>>;
+
+/// ScalarUDF for the Triangular quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("triangular_quantile"))
+}
+
+type InverseSf = Continuous4F<InverseSfEvaluator4F<Triangular>>;
+
+/// ScalarUDF for the Triangular inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("triangular_inverse_sf"))
+}
+
+type Interval = Interval3F<Triangular>;
+
+/// ScalarUDF for the Triangular central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("triangular_interval"))
+}
+
+type Mean = Statistic3F<MeanStatistic<Triangular>>;
+
+/// ScalarUDF for the Triangular mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("triangular_mean"))
+}
+
+type Variance = Statistic3F<VarianceStatistic<Triangular>>;
+
+/// ScalarUDF for the Triangular variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("triangular_variance"))
+}
+
+type StdDev = Statistic3F<StdDevStatistic<Triangular>>;
+
+/// ScalarUDF for the Triangular standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("triangular_std_dev"))
+}
+
+type Skewness = Statistic3F<SkewnessStatistic<Triangular>>;
+
+/// ScalarUDF for the Triangular skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("triangular_skewness"))
+}
+
+type Entropy = Statistic3F<EntropyStatistic<Triangular>>;
+
+/// ScalarUDF for the Triangular entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("triangular_entropy"))
+}
+
+type Mode = Statistic3F<ModeStatistic<Triangular>>;
+
+/// ScalarUDF for the Triangular mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("triangular_mode"))
+}
+
+type Median = Statistic3F<MedianStatistic<Triangular>>;
+
+/// ScalarUDF for the Triangular median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("triangular_median"))
+}
+
+type SupportMin = Statistic3F<SupportMinStatistic<Triangular>>;
+
+/// ScalarUDF for the Triangular support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("triangular_support_min"))
+}
+
+type SupportMax = Statistic3F<SupportMaxStatistic<Triangular>>;
+
+/// ScalarUDF for the Triangular support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("triangular_support_max"))
+}
+
+type Random = Random3F<Triangular>;
+
+/// ScalarUDF for the Triangular random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("triangular_random"))
+}
+
 /// Register the functions for the Triangular Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -83,7 +239,7 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -103,9 +259,9 @@ mod tests {
         ]))
     }
 
-    fn make_records(
-        rows: Vec<(Option<f64>, Option<f64>, Option<f64>, Option<f64>)>,
-    ) -> RecordBatch {
+    type Row = (Option<f64>, Option<f64>, Option<f64>, Option<f64>);
+
+    fn make_records(rows: Vec<Row>) -> RecordBatch {
         let mut xs = Vec::new();
         let mut mns = Vec::new();
         let mut mxs = Vec::new();
@@ -158,8 +314,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.3333333333333333);
         assert_eq_float!(res_col.value(1), 0.16666666666666666);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -180,12 +336,16 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<TriangularError>().unwrap();
-                assert_eq!(*be.as_ref(), TriangularError::ModeOutOfRange);
+                assert!(e.to_string().starts_with("triangular_pdf: "), "{e}");
+                let be = e
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<TriangularError>()
+                    .unwrap();
+                assert_eq!(*be, TriangularError::ModeOutOfRange);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -237,8 +397,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.6666666666666667);
         assert_eq_float!(res_col.value(1), 0.9166666666666666);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -270,7 +430,43 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.3333333333333333);
         assert_eq_float!(res_col.value(1), 0.08333333333333333);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn triangular_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT triangular_quantile(0.5, 0.0, 2.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.0);
+    }
+
+    #[tokio::test]
+    async fn triangular_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT triangular_inverse_sf(0.02, 0.0, 2.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.8);
     }
 }
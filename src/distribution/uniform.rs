@@ -13,13 +13,31 @@
 //! `uniform_pmf(x, a, b)`  
 //! `uniform_ln_pmf(x, a, b)`  
 //! `uniform_cdf(x, a, b)`  
-//! `uniform_sf(x, a, b)`
+//! `uniform_sf(x, a, b)`  
+//! `uniform_ln_cdf(x, a, b)`  
+//! `uniform_ln_sf(x, a, b)`  
+//! `uniform_quantile(p, a, b)`  
+//! `uniform_inverse_sf(p, a, b)`  
+//! `uniform_interval(level, a, b)`  
+//! `uniform_mean(a, b)`  
+//! `uniform_variance(a, b)`  
+//! `uniform_std_dev(a, b)`  
+//! `uniform_skewness(a, b)`  
+//! `uniform_entropy(a, b)`  
+//! `uniform_mode(a, b)`  
+//! `uniform_median(a, b)`  
+//! `uniform_support_min(a, b)`  
+//! `uniform_support_max(a, b)`  
+//! `uniform_random(a, b[, seed])`
 //!
 //! with
 //!
 //!   `x`: (-∞, +∞) `Float64`/`DOUBLE`,  
 //!   `a`: (-∞, +∞) `Float64`/`DOUBLE`,  
-//!   `b`: (a, +∞) `Float64`/`DOUBLE`
+//!   `b`: (a, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
 //!
 //! Examples
 //! ```
@@ -39,7 +57,17 @@ use datafusion::logical_expr::ScalarUDF;
 use statrs::distribution::Uniform;
 
 use super::super::utils::continuous3f::Continuous3F;
-use super::super::utils::evaluator3f::{CdfEvaluator3F, LnPdfEvaluator3F, PdfEvaluator3F, SfEvaluator3F};
+use super::super::utils::evaluator3f::{
+    CdfEvaluator3F, InverseCdfEvaluator3F, InverseSfEvaluator3F, LnCdfEvaluator3F,
+    LnPdfEvaluator3F, LnSfEvaluator3F, PdfEvaluator3F, SfEvaluator3F,
+};
+use super::super::utils::interval2f::Interval2F;
+use super::super::utils::random2f::Random2F;
+use super::super::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use super::super::utils::statistic2f::Statistic2F;
 
 type Pdf = Continuous3F<PdfEvaluator3F<Uniform>>;
 
@@ -69,9 +97,137 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("uniform_sf"))
 }
 
+type LnCdf = Continuous3F<LnCdfEvaluator3F<Uniform>>;
+
+/// ScalarUDF for the Uniform log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("uniform_ln_cdf"))
+}
+
+type LnSf = Continuous3F<LnSfEvaluator3F<Uniform>>;
+
+/// ScalarUDF for the Uniform log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("uniform_ln_sf"))
+}
+
+type Quantile = Continuous3F<InverseCdfEvaluator3F<Uniform>>;
+
+/// ScalarUDF for the Uniform quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("uniform_quantile"))
+}
+
+type InverseSf = Continuous3F<InverseSfEvaluator3F<Uniform>>;
+
+/// ScalarUDF for the Uniform inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("uniform_inverse_sf"))
+}
+
+type Interval = Interval2F<Uniform>;
+
+/// ScalarUDF for the Uniform central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("uniform_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<Uniform>>;
+
+/// ScalarUDF for the Uniform mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("uniform_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<Uniform>>;
+
+/// ScalarUDF for the Uniform variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("uniform_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<Uniform>>;
+
+/// ScalarUDF for the Uniform standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("uniform_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<Uniform>>;
+
+/// ScalarUDF for the Uniform skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("uniform_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<Uniform>>;
+
+/// ScalarUDF for the Uniform entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("uniform_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<Uniform>>;
+
+/// ScalarUDF for the Uniform mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("uniform_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<Uniform>>;
+
+/// ScalarUDF for the Uniform median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("uniform_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<Uniform>>;
+
+/// ScalarUDF for the Uniform support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("uniform_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<Uniform>>;
+
+/// ScalarUDF for the Uniform support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("uniform_support_max"))
+}
+
+type Random = Random2F<Uniform>;
+
+/// ScalarUDF for the Uniform random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("uniform_random"))
+}
+
 /// Register the functions for the Uniform Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -81,7 +237,7 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
         common::cast::as_float64_array,
@@ -150,8 +306,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.4444444444444444);
         assert_eq_float!(res_col.value(1), 0.4444444444444444);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -172,12 +328,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<UniformError>().unwrap();
-                assert_eq!(*be.as_ref(), UniformError::MaxNotGreaterThanMin);
+                assert!(e.to_string().starts_with("uniform_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<UniformError>().unwrap();
+                assert_eq!(*be, UniformError::MaxNotGreaterThanMin);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -229,8 +385,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.4444444444444444);
         assert_eq_float!(res_col.value(1), 0.6666666666666666);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -262,7 +418,43 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.5555555555555556);
         assert_eq_float!(res_col.value(1), 0.3333333333333333);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn uniform_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT uniform_quantile(0.25, 0.0, 4.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 1.0);
+    }
+
+    #[tokio::test]
+    async fn uniform_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT uniform_inverse_sf(0.25, 0.0, 4.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 3.0);
     }
 }
@@ -1,26 +1,47 @@
 //! Module containing functions to the Weibull Distribution.
-//! 
+//!
 //! Implemented by [`statrs::distribution::Weibull`].
-//! 
+//!
 //! The [Weibull Distribution](https://en.wikipedia.org/wiki/Weibull_distribution) has two
 //! parameters:
-//! 
+//!
 //! λ: 0 < λ  
 //! k: 0 < k
-//! 
+//!
 //! Usage:
-//! 
+//!
 //! `weibull_pdf(x, λ, k)`  
 //! `weibull_ln_pdf(x, λ, k)`  
 //! `weibull_cdf(x, λ, k)`  
-//! `weibull_sf(x, λ, k)`
-//! 
+//! `weibull_sf(x, λ, k)`  
+//! `weibull_ln_cdf(x, λ, k)`  
+//! `weibull_ln_sf(x, λ, k)`  
+//! `weibull_hazard(x, λ, k)`  
+//! `weibull_cum_hazard(x, λ, k)`  
+//! `weibull_quantile(p, λ, k)`  
+//! `weibull_inverse_sf(p, λ, k)`  
+//! `weibull_interval(level, λ, k)`  
+//! `weibull_mean(λ, k)`  
+//! `weibull_variance(λ, k)`  
+//! `weibull_std_dev(λ, k)`  
+//! `weibull_skewness(λ, k)`  
+//! `weibull_entropy(λ, k)`  
+//! `weibull_mode(λ, k)`  
+//! `weibull_median(λ, k)`  
+//! `weibull_support_min(λ, k)`  
+//! `weibull_support_max(λ, k)`  
+//! `weibull_random(λ, k[, seed])`  
+//! `weibull_fit(x)` (aggregate)
+//!
 //! with
-//! 
+//!
 //!   `x`: [0, +∞) `Float64`/`DOUBLE`,  
 //!   `λ`: (0, +∞) `Float64`/`DOUBLE`,  
-//!   `k`: (0, +∞) `Float64`/`DOUBLE`
-//! 
+//!   `k`: (0, +∞) `Float64`/`DOUBLE`,  
+//!   `p`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `level`: [0, 1] `Float64`/`DOUBLE`,  
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
 //! Examples
 //! ```
 //! #[tokio::main(flavor = "current_thread")]
@@ -35,11 +56,23 @@
 
 use datafusion::error::DataFusionError;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
 use statrs::distribution::Weibull;
 
 use super::super::utils::continuous3f::Continuous3F;
-use super::super::utils::evaluator3f::{CdfEvaluator3F, LnPdfEvaluator3F, PdfEvaluator3F, SfEvaluator3F};
+use super::super::utils::evaluator3f::{
+    CdfEvaluator3F, CumHazardEvaluator3F, HazardEvaluator3F, InverseCdfEvaluator3F,
+    InverseSfEvaluator3F, LnCdfEvaluator3F, LnPdfEvaluator3F, LnSfEvaluator3F, PdfEvaluator3F,
+    SfEvaluator3F,
+};
+use super::super::utils::fit::FitAggregate;
+use super::super::utils::interval2f::Interval2F;
+use super::super::utils::random2f::Random2F;
+use super::super::utils::statistic::{
+    EntropyStatistic, MeanStatistic, MedianStatistic, ModeStatistic, SkewnessStatistic,
+    StdDevStatistic, SupportMaxStatistic, SupportMinStatistic, VarianceStatistic,
+};
+use super::super::utils::statistic2f::Statistic2F;
 
 type Pdf = Continuous3F<PdfEvaluator3F<Weibull>>;
 
@@ -69,9 +102,161 @@ pub fn sf() -> ScalarUDF {
     ScalarUDF::from(Sf::new("weibull_sf"))
 }
 
+type LnCdf = Continuous3F<LnCdfEvaluator3F<Weibull>>;
+
+/// ScalarUDF for the Weibull log CDF
+pub fn ln_cdf() -> ScalarUDF {
+    ScalarUDF::from(LnCdf::new("weibull_ln_cdf"))
+}
+
+type LnSf = Continuous3F<LnSfEvaluator3F<Weibull>>;
+
+/// ScalarUDF for the Weibull log SF
+pub fn ln_sf() -> ScalarUDF {
+    ScalarUDF::from(LnSf::new("weibull_ln_sf"))
+}
+
+type Hazard = Continuous3F<HazardEvaluator3F<Weibull>>;
+
+/// ScalarUDF for the Weibull hazard
+pub fn hazard() -> ScalarUDF {
+    ScalarUDF::from(Hazard::new("weibull_hazard"))
+}
+
+type CumHazard = Continuous3F<CumHazardEvaluator3F<Weibull>>;
+
+/// ScalarUDF for the Weibull cumulative hazard
+pub fn cum_hazard() -> ScalarUDF {
+    ScalarUDF::from(CumHazard::new("weibull_cum_hazard"))
+}
+
+type Quantile = Continuous3F<InverseCdfEvaluator3F<Weibull>>;
+
+/// ScalarUDF for the Weibull quantile (inverse CDF)
+pub fn quantile() -> ScalarUDF {
+    ScalarUDF::from(Quantile::new("weibull_quantile"))
+}
+
+type InverseSf = Continuous3F<InverseSfEvaluator3F<Weibull>>;
+
+/// ScalarUDF for the Weibull inverse SF
+pub fn inverse_sf() -> ScalarUDF {
+    ScalarUDF::from(InverseSf::new("weibull_inverse_sf"))
+}
+
+type Interval = Interval2F<Weibull>;
+
+/// ScalarUDF for the Weibull central interval
+pub fn interval() -> ScalarUDF {
+    ScalarUDF::from(Interval::new("weibull_interval"))
+}
+
+type Mean = Statistic2F<MeanStatistic<Weibull>>;
+
+/// ScalarUDF for the Weibull mean
+pub fn mean() -> ScalarUDF {
+    ScalarUDF::from(Mean::new("weibull_mean"))
+}
+
+type Variance = Statistic2F<VarianceStatistic<Weibull>>;
+
+/// ScalarUDF for the Weibull variance
+pub fn variance() -> ScalarUDF {
+    ScalarUDF::from(Variance::new("weibull_variance"))
+}
+
+type StdDev = Statistic2F<StdDevStatistic<Weibull>>;
+
+/// ScalarUDF for the Weibull standard deviation
+pub fn std_dev() -> ScalarUDF {
+    ScalarUDF::from(StdDev::new("weibull_std_dev"))
+}
+
+type Skewness = Statistic2F<SkewnessStatistic<Weibull>>;
+
+/// ScalarUDF for the Weibull skewness
+pub fn skewness() -> ScalarUDF {
+    ScalarUDF::from(Skewness::new("weibull_skewness"))
+}
+
+type Entropy = Statistic2F<EntropyStatistic<Weibull>>;
+
+/// ScalarUDF for the Weibull entropy
+pub fn entropy() -> ScalarUDF {
+    ScalarUDF::from(Entropy::new("weibull_entropy"))
+}
+
+type Mode = Statistic2F<ModeStatistic<Weibull>>;
+
+/// ScalarUDF for the Weibull mode
+pub fn mode() -> ScalarUDF {
+    ScalarUDF::from(Mode::new("weibull_mode"))
+}
+
+type Median = Statistic2F<MedianStatistic<Weibull>>;
+
+/// ScalarUDF for the Weibull median
+pub fn median() -> ScalarUDF {
+    ScalarUDF::from(Median::new("weibull_median"))
+}
+
+type SupportMin = Statistic2F<SupportMinStatistic<Weibull>>;
+
+/// ScalarUDF for the Weibull support minimum
+pub fn support_min() -> ScalarUDF {
+    ScalarUDF::from(SupportMin::new("weibull_support_min"))
+}
+
+type SupportMax = Statistic2F<SupportMaxStatistic<Weibull>>;
+
+/// ScalarUDF for the Weibull support maximum
+pub fn support_max() -> ScalarUDF {
+    ScalarUDF::from(SupportMax::new("weibull_support_max"))
+}
+
+type Random = Random2F<Weibull>;
+
+/// ScalarUDF for the Weibull random variates
+pub fn random() -> ScalarUDF {
+    ScalarUDF::from(Random::new("weibull_random"))
+}
+
+type Fit = FitAggregate<Weibull>;
+
+/// AggregateUDF for the Weibull maximum likelihood fit
+pub fn fit() -> AggregateUDF {
+    AggregateUDF::from(Fit::new("weibull_fit"))
+}
+
 /// Register the functions for the Weibull Distribution
 pub fn register(registry: &mut dyn FunctionRegistry) -> Result<(), DataFusionError> {
-    crate::utils::register::register(registry, vec![pdf(), ln_pdf(), cdf(), sf()])
+    crate::utils::register::register_aggregates(registry, vec![fit()])?;
+    crate::utils::register::register(
+        registry,
+        vec![
+            pdf(),
+            ln_pdf(),
+            cdf(),
+            sf(),
+            ln_cdf(),
+            ln_sf(),
+            hazard(),
+            cum_hazard(),
+            quantile(),
+            inverse_sf(),
+            interval(),
+            mean(),
+            variance(),
+            std_dev(),
+            skewness(),
+            entropy(),
+            mode(),
+            median(),
+            support_min(),
+            support_max(),
+            random(),
+        ],
+    )
 }
 
 #[cfg(test)]
@@ -81,10 +266,10 @@ mod tests {
     use assert_eq_float::assert_eq_float;
     use datafusion::{
         arrow::{
-            array::{Float64Array, RecordBatch},
+            array::{Array, Float64Array, RecordBatch},
             datatypes::{DataType, Field, Schema, SchemaRef},
         },
-        common::cast::as_float64_array,
+        common::cast::{as_float64_array, as_struct_array, as_uint64_array},
         error::DataFusionError,
         prelude::{SessionContext, col},
     };
@@ -150,8 +335,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.7357588823428847);
         assert_eq_float!(res_col.value(1), 0.44626032029685964);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -172,12 +357,12 @@ mod tests {
             .await;
         match res {
             Err(DataFusionError::External(e)) => {
-                let be = e.downcast::<WeibullError>().unwrap();
-                assert_eq!(*be.as_ref(), WeibullError::ShapeInvalid);
+                assert!(e.to_string().starts_with("weibull_pdf: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<WeibullError>().unwrap();
+                assert_eq!(*be, WeibullError::ShapeInvalid);
             }
             _ => {
-                println!("unexpected result: {:?}", res);
-                assert!(false);
+                panic!("unexpected result: {:?}", res);
             }
         }
     }
@@ -229,8 +414,8 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.6321205588285577);
         assert_eq_float!(res_col.value(1), 0.7768698398515702);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
     }
 
     #[tokio::test]
@@ -262,7 +447,104 @@ mod tests {
         let res_col = as_float64_array(res[0].column(0)).unwrap();
         assert_eq_float!(res_col.value(0), 0.36787944117144233);
         assert_eq_float!(res_col.value(1), 0.22313016014842982);
-        assert!(res_col.value(2).is_nan());
-        assert!(res_col.value(3).is_nan());
+        assert!(res_col.is_null(2));
+        assert!(res_col.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn weibull_quantile_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT weibull_quantile(0.5, 2.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 0.8325546111576977);
+    }
+
+    #[tokio::test]
+    async fn weibull_inverse_sf_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT weibull_inverse_sf(1e-20, 2.0, 1.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let res_col = as_float64_array(res[0].column(0)).unwrap();
+        assert_eq_float!(res_col.value(0), 6.786140424415112);
+    }
+
+    #[tokio::test]
+    async fn weibull_hazard_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT weibull_hazard(3.0, 2.0, 1.5), weibull_cum_hazard(3.0, 2.0, 1.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_columns(), 2);
+        assert_eq!(res[0].num_rows(), 1);
+        assert_eq_float!(
+            as_float64_array(res[0].column(0)).unwrap().value(0),
+            2.6666666666666665
+        );
+        assert_eq_float!(as_float64_array(res[0].column(1)).unwrap().value(0), 4.0);
+    }
+
+    #[tokio::test]
+    async fn weibull_fit_success() {
+        let mut ctx = SessionContext::new();
+        register(&mut ctx).unwrap();
+        let res = ctx
+            .sql("SELECT weibull_fit(x) FROM (VALUES (0.5), (1.2), (2.3), (3.1), (4.8), (0.9), (1.7)) AS t(x)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].num_rows(), 1);
+        let fit = as_struct_array(res[0].column(0)).unwrap();
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("shape").unwrap())
+                .unwrap()
+                .value(0),
+            1.5830658179381882
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("scale").unwrap())
+                .unwrap()
+                .value(0),
+            2.3209840775425135
+        );
+        assert_eq_float!(
+            as_float64_array(fit.column_by_name("ln_likelihood").unwrap())
+                .unwrap()
+                .value(0),
+            -11.10490647189182
+        );
+        assert_eq!(
+            as_uint64_array(fit.column_by_name("n").unwrap())
+                .unwrap()
+                .value(0),
+            7
+        );
     }
 }
@@ -4,4 +4,10 @@
 /// Wrappers for all the individual distributions.
 pub mod distribution;
 
+/// Table functions sampling and tabulating the distributions.
+pub mod table;
+
+/// Options controlling the behavior of the functions.
+pub mod options;
+
 mod utils;
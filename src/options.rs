@@ -0,0 +1,189 @@
+//! Options controlling the behavior of the statrs functions.
+//!
+//! [`StatrsOptions`] is a DataFusion [`ConfigExtension`] under the `statrs` prefix, so it can
+//! be attached to a session and set like any other option:
+//!
+//! ```
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> datafusion::error::Result<()> {
+//! use datafusion::prelude::{SessionConfig, SessionContext};
+//! use datafusion_statrs::options::StatrsOptions;
+//!
+//! let config = SessionConfig::new().with_option_extension(StatrsOptions::default());
+//! let mut ctx = SessionContext::new_with_config(config);
+//! ctx.sql("SET statrs.null_as_nan = true").await?;
+//! ctx.sql("SET statrs.invalid_params = 'null'").await?;
+//! datafusion_statrs::distribution::register_for_session(&mut ctx)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The options are read when the functions are registered, so they must be set beforehand.
+//! Once [`register_for_session`](crate::distribution::register_for_session) has read them,
+//! setting them fails rather than being silently ignored.
+
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use datafusion::config::{ConfigEntry, ConfigExtension, ConfigField, ExtensionOptions, Visit};
+use datafusion::error::DataFusionError;
+
+/// What a function returns for a row whose distribution parameters are invalid, e.g. a
+/// negative standard deviation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InvalidParams {
+    /// Fail the query (`'error'`).
+    #[default]
+    Error,
+    /// Return NULL for the row (`'null'`).
+    Null,
+    /// Return NaN for the row (`'nan'`), or NULL if the result is not a `Float64`.
+    Nan,
+}
+
+impl FromStr for InvalidParams {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Ok(InvalidParams::Error),
+            "null" => Ok(InvalidParams::Null),
+            "nan" => Ok(InvalidParams::Nan),
+            _ => Err(DataFusionError::Configuration(format!(
+                "invalid value for statrs.invalid_params: '{s}', expected 'error', 'null' or 'nan'"
+            ))),
+        }
+    }
+}
+
+impl Display for InvalidParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidParams::Error => write!(f, "error"),
+            InvalidParams::Null => write!(f, "null"),
+            InvalidParams::Nan => write!(f, "nan"),
+        }
+    }
+}
+
+impl ConfigField for InvalidParams {
+    fn visit<V: Visit>(&self, v: &mut V, key: &str, description: &'static str) {
+        v.some(key, self, description)
+    }
+
+    fn set(&mut self, _key: &str, value: &str) -> datafusion::error::Result<()> {
+        *self = value.parse()?;
+        Ok(())
+    }
+}
+
+/// Options for the statrs functions, set with the `statrs.` prefix.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct StatrsOptions {
+    /// Return NaN rather than NULL when an argument is NULL, as versions up to 0.2 did.
+    pub null_as_nan: bool,
+    /// What to return for rows with invalid distribution parameters: 'error', 'null' or 'nan'.
+    pub invalid_params: InvalidParams,
+    /// Whether the functions were registered with these options, after which setting the
+    /// options that shape them would have no effect.
+    pub(crate) registered: bool,
+}
+
+impl Default for StatrsOptions {
+    fn default() -> Self {
+        StatrsOptions {
+            null_as_nan: false,
+            invalid_params: InvalidParams::Error,
+            registered: false,
+        }
+    }
+}
+
+impl StatrsOptions {
+    /// Mark the options as read by a registration, so that setting them fails instead of
+    /// being silently ignored.
+    pub(crate) fn set_registered(&mut self) {
+        self.registered = true;
+    }
+}
+
+impl ConfigField for StatrsOptions {
+    fn visit<V: Visit>(&self, v: &mut V, _key_prefix: &str, _description: &'static str) {
+        self.null_as_nan.visit(
+            v,
+            "null_as_nan",
+            "Return NaN rather than NULL when an argument is NULL, as versions up to 0.2 did.",
+        );
+        self.invalid_params.visit(
+            v,
+            "invalid_params",
+            "What to return for rows with invalid distribution parameters: 'error', 'null' or 'nan'.",
+        );
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> datafusion::error::Result<()> {
+        let (key, rem) = key.split_once('.').unwrap_or((key, ""));
+        match key {
+            "null_as_nan" | "invalid_params" if self.registered => {
+                Err(DataFusionError::Configuration(format!(
+                    "statrs.{key} is read when the functions are registered, so it must be set before"
+                )))
+            }
+            "null_as_nan" => self.null_as_nan.set(rem, value),
+            "invalid_params" => self.invalid_params.set(rem, value),
+            _ => Err(DataFusionError::Configuration(format!(
+                "Config value \"{key}\" not found on StatrsOptions"
+            ))),
+        }
+    }
+}
+
+impl ExtensionOptions for StatrsOptions {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn cloned(&self) -> Box<dyn ExtensionOptions> {
+        Box::new(self.clone())
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> datafusion::error::Result<()> {
+        ConfigField::set(self, key, value)
+    }
+
+    fn entries(&self) -> Vec<ConfigEntry> {
+        struct Visitor(Vec<ConfigEntry>);
+
+        impl Visit for Visitor {
+            fn some<V: Display>(&mut self, key: &str, value: V, description: &'static str) {
+                self.0.push(ConfigEntry {
+                    key: key.to_string(),
+                    value: Some(value.to_string()),
+                    description,
+                })
+            }
+
+            fn none(&mut self, key: &str, description: &'static str) {
+                self.0.push(ConfigEntry {
+                    key: key.to_string(),
+                    value: None,
+                    description,
+                })
+            }
+        }
+
+        let mut v = Visitor(vec![]);
+        self.visit(&mut v, "", "");
+        v.0
+    }
+}
+
+impl ConfigExtension for StatrsOptions {
+    const PREFIX: &'static str = "statrs";
+}
@@ -0,0 +1,433 @@
+//! The `distribution_grid` table function, which tabulates a distribution for plotting.
+//!
+//! Usage:
+//!
+//! `distribution_grid(name, params..., from, to[, steps])`
+//!
+//! with
+//!
+//!   `name`: the distribution, named like its module, e.g. `'gamma'` or `'poisson'`,
+//!   `params`: the parameters of the distribution, in the order its functions take them,
+//!   `from`, `to`: the range to tabulate, from ≤ to `Float64`/`DOUBLE`,
+//!   `steps`: the number of intervals the range is divided into, 0 < steps
+//!   `UInt64`/`BIGINT UNSIGNED`, 100 if omitted
+//!
+//! The table has the columns `x`, `pdf`, `cdf` and `sf` for a continuous distribution, at
+//! `steps + 1` evenly spaced points from `from` to `to`. For a discrete distribution the
+//! columns are `x`, `pmf`, `cdf` and `sf`, at every value of the distribution from `from`
+//! to `to`, and `steps` is not accepted. A grid may have at most 100,000,000 points. `x` has the type of the values of the distribution.
+//! The parameters are checked once, when the query is planned. All arguments must be
+//! constants, and if any is NULL the table is empty.
+//!
+//! DataFusion does not pass named arguments to table functions, so the range and steps
+//! are the arguments after the parameters.
+//!
+//! Examples
+//! ```
+//! #[tokio::main(flavor = "current_thread")]
+//! async fn main() -> std::io::Result<()> {
+//!     let ctx = datafusion::prelude::SessionContext::new();
+//!     datafusion_statrs::table::register(&ctx);
+//!     ctx.sql("SELECT x, pdf, cdf FROM distribution_grid('gamma', 2.0, 1.5, 0, 10, 200)").await?
+//!        .show().await?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::{
+    arrow::{
+        array::ArrayRef,
+        datatypes::{DataType, Field, Schema, SchemaRef},
+    },
+    catalog::{Session, TableFunctionImpl, TableProvider},
+    error::DataFusionError,
+    logical_expr::{Expr, TableType},
+    physical_plan::ExecutionPlan,
+    scalar::ScalarValue,
+};
+
+use super::Rows;
+use crate::utils::coerce::Arg;
+use crate::utils::family::{Family, FamilySpec, Grid, MAX_GRID_LEN, literal, param};
+
+const NAME: &str = "distribution_grid";
+
+const DEFAULT_STEPS: u64 = 100;
+
+/// The `distribution_grid` table function
+pub fn function() -> Arc<dyn TableFunctionImpl> {
+    Arc::new(DistributionGrid)
+}
+
+#[derive(Debug)]
+struct DistributionGrid;
+
+impl TableFunctionImpl for DistributionGrid {
+    fn call(&self, args: &[Expr]) -> Result<Arc<dyn TableProvider>, DataFusionError> {
+        let values = args
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| literal(NAME, i + 1, arg))
+            .collect::<Result<Vec<_>, DataFusionError>>()?;
+        let Some((name, rest)) = values.split_first() else {
+            return Err(DataFusionError::Plan(format!(
+                "{NAME} expects a distribution, its parameters and a range"
+            )));
+        };
+        let spec = FamilySpec::find(NAME, name)?;
+        let n_params = spec.params.len();
+        let (params, from, to, steps) = match rest.len().checked_sub(n_params) {
+            Some(2) => (
+                &rest[..n_params],
+                &rest[n_params],
+                &rest[n_params + 1],
+                None,
+            ),
+            Some(3) if spec.is_continuous() => (
+                &rest[..n_params],
+                &rest[n_params],
+                &rest[n_params + 1],
+                Some(&rest[n_params + 2]),
+            ),
+            Some(3) => {
+                return Err(DataFusionError::Plan(format!(
+                    "{NAME}: {} is discrete, so its grid takes no steps",
+                    spec.name
+                )));
+            }
+            _ => {
+                let steps = if spec.is_continuous() {
+                    " and optionally steps"
+                } else {
+                    ""
+                };
+                return Err(DataFusionError::Plan(format!(
+                    "{NAME}: {} expects {n_params} parameters, from and to{steps}, got {} arguments",
+                    spec.name,
+                    rest.len()
+                )));
+            }
+        };
+
+        let density = if spec.is_continuous() { "pdf" } else { "pmf" };
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("x", spec.value_type(), false),
+            Field::new(density, DataType::Float64, true),
+            Field::new("cdf", DataType::Float64, false),
+            Field::new("sf", DataType::Float64, false),
+        ]));
+
+        let family = spec.make(NAME, 1, params)?;
+        let from = param(NAME, n_params + 2, from, Arg::Float)?;
+        let to = param(NAME, n_params + 3, to, Arg::Float)?;
+        let steps = match steps {
+            Some(steps) => param(NAME, n_params + 4, steps, Arg::Count)?,
+            None => Some(ScalarValue::UInt64(Some(DEFAULT_STEPS))),
+        };
+        let points = match (family, from, to, steps) {
+            (
+                Some(family),
+                Some(ScalarValue::Float64(Some(from))),
+                Some(ScalarValue::Float64(Some(to))),
+                Some(ScalarValue::UInt64(Some(steps))),
+            ) => {
+                let grid = grid(from, to, steps)?;
+                let len = family.grid_len(&grid).ok_or_else(|| {
+                    DataFusionError::Plan(format!(
+                        "{NAME}: the grid must have at most {MAX_GRID_LEN} points"
+                    ))
+                })?;
+                Some(Points { family, grid, len })
+            }
+            _ => None,
+        };
+        Ok(Arc::new(GridTable { schema, points }))
+    }
+}
+
+fn grid(from: f64, to: f64, steps: u64) -> Result<Grid, DataFusionError> {
+    if !from.is_finite() || !to.is_finite() || from > to {
+        return Err(DataFusionError::Plan(format!(
+            "{NAME}: the range must be finite with from ≤ to, got {from} to {to}"
+        )));
+    }
+    if steps == 0 {
+        return Err(DataFusionError::Plan(format!(
+            "{NAME}: steps must be positive"
+        )));
+    }
+    Ok(Grid { from, to, steps })
+}
+
+/// The table of a call to `distribution_grid`, with no points if an argument is NULL.
+#[derive(Debug)]
+struct GridTable {
+    schema: SchemaRef,
+    points: Option<Points>,
+}
+
+#[async_trait]
+impl TableProvider for GridTable {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>, DataFusionError> {
+        let n = self.points.as_ref().map_or(0, |p| p.len);
+        super::scan(
+            NAME,
+            state,
+            &self.schema,
+            self.points.clone(),
+            n,
+            projection,
+            limit,
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Points {
+    family: Arc<dyn Family>,
+    grid: Grid,
+    len: u64,
+}
+
+impl Rows for Points {
+    fn rows(&mut self, start: u64, len: usize) -> Result<Vec<ArrayRef>, DataFusionError> {
+        Ok(self.family.grid(&self.grid, start, len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_eq_float::assert_eq_float;
+    use datafusion::{
+        common::cast::{as_float64_array, as_int64_array, as_uint64_array},
+        prelude::{SessionConfig, SessionContext},
+    };
+    use statrs::distribution::GammaError;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn distribution_grid_continuous() {
+        let ctx = SessionContext::new_with_config(SessionConfig::new().with_batch_size(64));
+        crate::table::register(&ctx);
+        let res = ctx
+            .sql("SELECT x, pdf, cdf, sf FROM distribution_grid('gamma', 2.0, 1.5, 0, 10, 200) ORDER BY x")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let batch = datafusion::arrow::compute::concat_batches(&res[0].schema(), &res).unwrap();
+        assert_eq!(batch.num_rows(), 201);
+        let x = as_float64_array(batch.column(0)).unwrap();
+        let pdf = as_float64_array(batch.column(1)).unwrap();
+        let cdf = as_float64_array(batch.column(2)).unwrap();
+        let sf = as_float64_array(batch.column(3)).unwrap();
+        assert_eq!(x.value(0), 0.0);
+        assert_eq_float!(x.value(20), 1.0);
+        assert_eq!(x.value(200), 10.0);
+        assert_eq_float!(pdf.value(20), 0.5020428603339671);
+        assert_eq_float!(cdf.value(20), 0.44217459962892547);
+        assert_eq_float!(sf.value(20), 0.5578254003710745);
+    }
+
+    #[tokio::test]
+    async fn distribution_grid_discrete() {
+        let ctx = SessionContext::new();
+        crate::table::register(&ctx);
+        let res = ctx
+            .sql("SELECT x, pmf, cdf FROM distribution_grid('poisson', 2.0, -3, 4.5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].schema().field(1).name(), "pmf");
+        let x = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(x.values().to_vec(), vec![0, 1, 2, 3, 4]);
+        assert_eq_float!(
+            as_float64_array(res[0].column(1)).unwrap().value(0),
+            0.1353352832366127
+        );
+        assert_eq_float!(
+            as_float64_array(res[0].column(2)).unwrap().value(4),
+            0.9473469826562889
+        );
+
+        let res = ctx
+            .sql("SELECT x FROM distribution_grid('discrete_uniform', -2, 2, -5, 5)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let x = as_int64_array(res[0].column(0)).unwrap();
+        assert_eq!(x.values().to_vec(), (-5..=5).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn distribution_grid_default_steps() {
+        let ctx = SessionContext::new();
+        crate::table::register(&ctx);
+        let res = ctx
+            .sql("SELECT count(*) FROM distribution_grid('normal', 0.0, 1.0, -4, 4)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(as_int64_array(res[0].column(0)).unwrap().value(0), 101);
+    }
+
+    #[tokio::test]
+    async fn distribution_grid_errors() {
+        let ctx = SessionContext::new();
+        crate::table::register(&ctx);
+        let res = ctx
+            .sql("SELECT * FROM distribution_grid('gamma', 2.0, -1.5, 0, 10)")
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("distribution_grid: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<GammaError>().unwrap();
+                assert_eq!(*be, GammaError::RateInvalid);
+            }
+            _ => panic!("unexpected result: {:?}", res.map(|_| ())),
+        }
+
+        let res = ctx
+            .sql("SELECT * FROM distribution_grid('poisson', 2.0, 0, 10, 5)")
+            .await;
+        match res {
+            Err(DataFusionError::Plan(msg)) => {
+                assert_eq!(
+                    msg,
+                    "distribution_grid: poisson is discrete, so its grid takes no steps"
+                )
+            }
+            _ => panic!("unexpected result: {:?}", res.map(|_| ())),
+        }
+
+        let res = ctx
+            .sql("SELECT * FROM distribution_grid('normal', 0.0, 1.0, 4, -4)")
+            .await;
+        assert!(matches!(res, Err(DataFusionError::Plan(_))));
+
+        let res = ctx
+            .sql("SELECT * FROM distribution_grid('normal', 0.0, 1.0, -4, 4, 0)")
+            .await;
+        assert!(matches!(res, Err(DataFusionError::Plan(_))));
+    }
+
+    #[tokio::test]
+    async fn distribution_grid_null_argument() {
+        let ctx = SessionContext::new();
+        crate::table::register(&ctx);
+        let res = ctx
+            .sql("SELECT * FROM distribution_grid('normal', 0.0, 1.0, NULL, 4)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+
+        let res = ctx
+            .sql("SELECT pdf, cdf FROM distribution_grid('dirac', 1.0, 0, 2, 2)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res[0].column(0).null_count(), 3);
+        assert_eq!(
+            as_float64_array(res[0].column(1))
+                .unwrap()
+                .values()
+                .to_vec(),
+            vec![0.0, 1.0, 1.0]
+        );
+    }
+
+    #[tokio::test]
+    async fn distribution_grid_huge_range() {
+        let ctx = SessionContext::new();
+        crate::table::register(&ctx);
+        let res = ctx
+            .sql("SELECT x FROM distribution_grid('poisson', 2.0, -1e300, 1)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            as_uint64_array(res[0].column(0)).unwrap().values().to_vec(),
+            vec![0, 1]
+        );
+
+        // 2^63 - 1024, which a DOUBLE holds exactly, to the end of BIGINT.
+        let res = ctx
+            .sql("SELECT count(*), min(x), max(x), max(pmf) FROM distribution_grid('discrete_uniform', -3, 2, 9223372036854774784, 1e300)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(as_int64_array(res[0].column(0)).unwrap().value(0), 1024);
+        assert_eq!(
+            as_int64_array(res[0].column(1)).unwrap().value(0),
+            i64::MAX - 1023
+        );
+        assert_eq!(as_int64_array(res[0].column(2)).unwrap().value(0), i64::MAX);
+        assert_eq!(as_float64_array(res[0].column(3)).unwrap().value(0), 0.0);
+
+        let res = ctx
+            .sql("SELECT count(*) FROM distribution_grid('poisson', 2.0, 1e300, 1e300)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(as_int64_array(res[0].column(0)).unwrap().value(0), 0);
+
+        let res = ctx
+            .sql("SELECT * FROM distribution_grid('poisson', 2.0, 0, 1e300)")
+            .await;
+        match res {
+            Err(DataFusionError::Plan(msg)) => {
+                assert_eq!(
+                    msg,
+                    "distribution_grid: the grid must have at most 100000000 points"
+                )
+            }
+            _ => panic!("unexpected result: {:?}", res.map(|_| ())),
+        }
+
+        let res = ctx
+            .sql("SELECT * FROM distribution_grid('normal', 0.0, 1.0, -4, 4, 18446744073709551615)")
+            .await;
+        assert!(matches!(res, Err(DataFusionError::Plan(_))));
+    }
+}
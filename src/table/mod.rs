@@ -0,0 +1,121 @@
+use std::fmt;
+use std::sync::Arc;
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, RecordBatch},
+        datatypes::SchemaRef,
+        record_batch::RecordBatchOptions,
+    },
+    catalog::Session,
+    error::DataFusionError,
+    physical_plan::{
+        ExecutionPlan,
+        memory::{LazyBatchGenerator, LazyMemoryExec},
+    },
+    prelude::SessionContext,
+};
+use parking_lot::RwLock;
+
+pub mod distribution_grid;
+pub mod sample_distribution;
+
+/// Register the table functions. Unlike the scalar and aggregate functions they need a
+/// [`SessionContext`], as a [`datafusion::execution::FunctionRegistry`] cannot hold them.
+pub fn register(ctx: &SessionContext) {
+    ctx.register_udtf("distribution_grid", distribution_grid::function());
+    ctx.register_udtf("sample_distribution", sample_distribution::function());
+}
+
+/// The rows of a table computed by a table function, by position.
+trait Rows: fmt::Debug + Send + Sync + 'static {
+    /// All the columns of rows `start..start + len`.
+    fn rows(&mut self, start: u64, len: usize) -> Result<Vec<ArrayRef>, DataFusionError>;
+}
+
+/// A plan reading the `n` rows of `rows`, none if it is `None`, a batch at a time so that
+/// the table is never held in memory. Only the columns in `projection` and at most `limit`
+/// rows are computed.
+fn scan<R: Rows>(
+    name: &'static str,
+    state: &dyn Session,
+    schema: &SchemaRef,
+    rows: Option<R>,
+    n: u64,
+    projection: Option<&Vec<usize>>,
+    limit: Option<usize>,
+) -> Result<Arc<dyn ExecutionPlan>, DataFusionError> {
+    let projected = match projection {
+        Some(projection) => Arc::new(schema.project(projection)?),
+        None => Arc::clone(schema),
+    };
+    let n = if rows.is_some() { n } else { 0 };
+    let generator = Batches {
+        name,
+        schema: Arc::clone(&projected),
+        projection: projection.cloned(),
+        rows,
+        next: 0,
+        end: limit.map_or(n, |limit| n.min(limit as u64)),
+        batch_size: state.config_options().execution.batch_size,
+    };
+    Ok(Arc::new(LazyMemoryExec::try_new(
+        projected,
+        vec![Arc::new(RwLock::new(generator))],
+    )?))
+}
+
+#[derive(Debug)]
+struct Batches<R: Rows> {
+    name: &'static str,
+    schema: SchemaRef,
+    projection: Option<Vec<usize>>,
+    rows: Option<R>,
+    next: u64,
+    end: u64,
+    batch_size: usize,
+}
+
+impl<R: Rows> fmt::Display for Batches<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: rows={}, batch_size={}",
+            self.name, self.end, self.batch_size
+        )
+    }
+}
+
+impl<R: Rows> LazyBatchGenerator for Batches<R> {
+    fn generate_next_batch(&mut self) -> Result<Option<RecordBatch>, DataFusionError> {
+        let Some(rows) = &mut self.rows else {
+            return Ok(None);
+        };
+        if self.next >= self.end {
+            return Ok(None);
+        }
+        let len = (self.end - self.next).min(self.batch_size as u64) as usize;
+        let start = self.next;
+        self.next += len as u64;
+
+        // Without a column the query only counts rows, so there is nothing to compute.
+        let columns = if self.schema.fields().is_empty() {
+            vec![]
+        } else {
+            let columns = rows.rows(start, len)?;
+            match &self.projection {
+                Some(projection) => projection
+                    .iter()
+                    .map(|&i| Arc::clone(&columns[i]))
+                    .collect(),
+                None => columns,
+            }
+        };
+        let options = RecordBatchOptions::new().with_row_count(Some(len));
+        Ok(Some(RecordBatch::try_new_with_options(
+            Arc::clone(&self.schema),
+            columns,
+            &options,
+        )?))
+    }
+}
@@ -0,0 +1,332 @@
+//! The `sample_distribution` table function, which draws a sample from a distribution.
+//!
+//! Usage:
+//!
+//! `sample_distribution(name, n, params...[, seed])`
+//!
+//! with
+//!
+//!   `name`: the distribution, named like its module, e.g. `'normal'` or `'chi_squared'`,
+//!   `n`: the number of draws, 0 ≤ n `UInt64`/`BIGINT UNSIGNED`,
+//!   `params`: the parameters of the distribution, in the order its functions take them,
+//!   `seed`: (-∞, +∞) `Int64`/`BIGINT`
+//!
+//! The table has one column, `value`, of the type of the values of the distribution:
+//! `UInt64` for count distributions, `Int64` for the discrete uniform and `Float64`
+//! otherwise. The draws are generated a batch at a time as the table is read, so a large
+//! sample is never held in memory. All arguments must be constants, and if any is NULL the
+//! table is empty.
+//!
+//! With a seed, the draws come from a single generator seeded with it, so the same seed
+//! always gives the same table. DataFusion does not pass named arguments to table functions,
+//! so the seed is the argument after the parameters.
+//!
+//! Examples
+//! ```
+//! #[tokio::main(flavor = "current_thread")]
+//! async fn main() -> std::io::Result<()> {
+//!     let ctx = datafusion::prelude::SessionContext::new();
+//!     datafusion_statrs::table::register(&ctx);
+//!     ctx.sql("SELECT avg(value), stddev(value)
+//!              FROM sample_distribution('normal', 100000, 0.0, 1.0, 42)").await?
+//!        .show().await?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::{
+    arrow::{
+        array::ArrayRef,
+        datatypes::{Field, Schema, SchemaRef},
+    },
+    catalog::{Session, TableFunctionImpl, TableProvider},
+    error::DataFusionError,
+    logical_expr::{Expr, TableType},
+    physical_plan::ExecutionPlan,
+    scalar::ScalarValue,
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+use super::Rows;
+use crate::utils::coerce::Arg;
+use crate::utils::family::{Family, FamilySpec, literal, param};
+
+const NAME: &str = "sample_distribution";
+
+/// The `sample_distribution` table function
+pub fn function() -> Arc<dyn TableFunctionImpl> {
+    Arc::new(SampleDistribution)
+}
+
+#[derive(Debug)]
+struct SampleDistribution;
+
+impl TableFunctionImpl for SampleDistribution {
+    fn call(&self, args: &[Expr]) -> Result<Arc<dyn TableProvider>, DataFusionError> {
+        let values = args
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| literal(NAME, i + 1, arg))
+            .collect::<Result<Vec<_>, DataFusionError>>()?;
+        let (name, n, rest) = match values.as_slice() {
+            [name, n, rest @ ..] => (name, n, rest),
+            _ => {
+                return Err(DataFusionError::Plan(format!(
+                    "{NAME} expects a distribution, a count and its parameters"
+                )));
+            }
+        };
+        let spec = FamilySpec::find(NAME, name)?;
+        let (params, seed) = if rest.len() == spec.params.len() + 1 {
+            (&rest[..spec.params.len()], rest.last())
+        } else {
+            (rest, None)
+        };
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "value",
+            spec.value_type(),
+            false,
+        )]));
+        let n = param(NAME, 2, n, Arg::Count)?;
+        let family = spec.make(NAME, 2, params)?;
+        let seed = seed
+            .map(|seed| param(NAME, args.len(), seed, Arg::Integer))
+            .transpose()?;
+        let sample = match (n, family, seed) {
+            (Some(ScalarValue::UInt64(Some(n))), Some(family), None) => Some(Sample {
+                family,
+                n,
+                seed: None,
+            }),
+            (
+                Some(ScalarValue::UInt64(Some(n))),
+                Some(family),
+                Some(Some(ScalarValue::Int64(seed))),
+            ) => Some(Sample { family, n, seed }),
+            _ => None,
+        };
+        Ok(Arc::new(SampleTable { schema, sample }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Sample {
+    family: Arc<dyn Family>,
+    n: u64,
+    seed: Option<i64>,
+}
+
+/// The table of a call to `sample_distribution`, with no sample if an argument is NULL.
+#[derive(Debug)]
+struct SampleTable {
+    schema: SchemaRef,
+    sample: Option<Sample>,
+}
+
+#[async_trait]
+impl TableProvider for SampleTable {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>, DataFusionError> {
+        let n = self.sample.as_ref().map_or(0, |s| s.n);
+        let draws = self.sample.as_ref().map(|sample| Draws {
+            family: Arc::clone(&sample.family),
+            rng: match sample.seed {
+                Some(seed) => StdRng::seed_from_u64(seed as u64),
+                None => StdRng::from_entropy(),
+            },
+        });
+        super::scan(NAME, state, &self.schema, draws, n, projection, limit)
+    }
+}
+
+/// Draws the sample in order, so that a seeded generator gives the same values however
+/// they are split into batches.
+#[derive(Debug)]
+struct Draws {
+    family: Arc<dyn Family>,
+    rng: StdRng,
+}
+
+impl Rows for Draws {
+    fn rows(&mut self, _start: u64, len: usize) -> Result<Vec<ArrayRef>, DataFusionError> {
+        Ok(vec![self.family.sample(&mut self.rng, len)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::{
+        arrow::datatypes::DataType,
+        common::cast::{as_float64_array, as_int64_array, as_uint64_array},
+        prelude::{SessionConfig, SessionContext},
+    };
+    use statrs::distribution::NormalError;
+
+    use super::*;
+
+    async fn values(ctx: &SessionContext, sql: &str) -> Vec<f64> {
+        let res = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+        res.iter()
+            .flat_map(|b| as_float64_array(b.column(0)).unwrap().values().to_vec())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn sample_distribution_seeded() {
+        let sql = "SELECT value FROM sample_distribution('normal', 10000, 1.0, 2.0, 42)";
+        let mut samples = Vec::new();
+        for batch_size in [1000, 8192] {
+            let ctx =
+                SessionContext::new_with_config(SessionConfig::new().with_batch_size(batch_size));
+            crate::table::register(&ctx);
+            samples.push(values(&ctx, sql).await);
+        }
+        assert_eq!(samples[0].len(), 10000);
+        assert_eq!(samples[0], samples[1]);
+        let mean = samples[0].iter().sum::<f64>() / 10000.0;
+        assert!((mean - 1.0).abs() < 0.1);
+    }
+
+    #[tokio::test]
+    async fn sample_distribution_value_types() {
+        let ctx = SessionContext::new();
+        crate::table::register(&ctx);
+        let res = ctx
+            .sql("SELECT value FROM sample_distribution('poisson', 100, 3.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(res[0].schema().field(0).data_type(), &DataType::UInt64);
+        assert_eq!(res.iter().map(|b| b.num_rows()).sum::<usize>(), 100);
+        assert!(as_uint64_array(res[0].column(0)).is_ok());
+
+        let res = ctx
+            .sql("SELECT min(value), max(value) FROM sample_distribution('discrete_uniform', 1000, -1, 1)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(as_int64_array(res[0].column(0)).unwrap().value(0), -1);
+        assert_eq!(as_int64_array(res[0].column(1)).unwrap().value(0), 1);
+    }
+
+    #[tokio::test]
+    async fn sample_distribution_count_and_limit() {
+        let ctx = SessionContext::new();
+        crate::table::register(&ctx);
+        let res = ctx
+            .sql("SELECT count(*) FROM sample_distribution('exp', 12345, 2.0)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(as_int64_array(res[0].column(0)).unwrap().value(0), 12345);
+
+        let limited = values(
+            &ctx,
+            "SELECT * FROM sample_distribution('normal', 1000000000000, 0, 1) LIMIT 5",
+        )
+        .await;
+        assert_eq!(limited.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn sample_distribution_constant_arguments() {
+        let ctx = SessionContext::new();
+        crate::table::register(&ctx);
+        let sample = values(
+            &ctx,
+            "SELECT value FROM sample_distribution('exp', CAST(10 AS INT), 2 * 1.5)",
+        )
+        .await;
+        assert_eq!(sample.len(), 10);
+
+        let res = ctx
+            .sql("SELECT value FROM sample_distribution('categorical', 10, [0.0, 1.0])")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let column = as_uint64_array(res[0].column(0)).unwrap();
+        assert_eq!(column.len(), 10);
+        assert!(column.values().iter().all(|&v| v == 1));
+    }
+
+    #[tokio::test]
+    async fn sample_distribution_null_argument() {
+        let ctx = SessionContext::new();
+        crate::table::register(&ctx);
+        let sample = values(
+            &ctx,
+            "SELECT value FROM sample_distribution('normal', 10, NULL, 1.0)",
+        )
+        .await;
+        assert!(sample.is_empty());
+    }
+
+    #[tokio::test]
+    async fn sample_distribution_errors() {
+        let ctx = SessionContext::new();
+        crate::table::register(&ctx);
+        let res = ctx
+            .sql("SELECT * FROM sample_distribution('nomral', 10, 0.0, 1.0)")
+            .await;
+        match res {
+            Err(DataFusionError::Plan(msg)) => {
+                assert_eq!(msg, "sample_distribution: unknown distribution 'nomral'")
+            }
+            _ => panic!("unexpected result: {:?}", res.map(|_| ())),
+        }
+
+        let res = ctx
+            .sql("SELECT * FROM sample_distribution('normal', 10, 0.0)")
+            .await;
+        match res {
+            Err(DataFusionError::Plan(msg)) => {
+                assert_eq!(
+                    msg,
+                    "sample_distribution: normal expects 2 parameters, got 1"
+                )
+            }
+            _ => panic!("unexpected result: {:?}", res.map(|_| ())),
+        }
+
+        let res = ctx
+            .sql("SELECT * FROM sample_distribution('normal', 10, 0.0, -1.0)")
+            .await;
+        match res {
+            Err(DataFusionError::External(e)) => {
+                assert!(e.to_string().starts_with("sample_distribution: "), "{e}");
+                let be = e.source().unwrap().downcast_ref::<NormalError>().unwrap();
+                assert_eq!(*be, NormalError::StandardDeviationInvalid);
+            }
+            _ => panic!("unexpected result: {:?}", res.map(|_| ())),
+        }
+    }
+}
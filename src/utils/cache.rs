@@ -0,0 +1,45 @@
+use std::borrow::Borrow;
+
+use datafusion::error::DataFusionError;
+
+/// The distribution built for the most recent parameters, reused for as long as
+/// consecutive rows share them. With literal parameters every row does, so the
+/// distribution is built once per batch.
+pub struct LastDist<K, D> {
+    last: Option<(K, D)>,
+}
+
+impl<K, D> LastDist<K, D> {
+    pub fn new() -> Self {
+        LastDist { last: None }
+    }
+
+    /// The distribution for `key`, calling `make` only if it differs from the last key.
+    pub fn get<Q>(
+        &mut self,
+        key: &Q,
+        make: impl FnOnce() -> Result<D, DataFusionError>,
+    ) -> Result<&D, DataFusionError>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ToOwned<Owned = K> + ?Sized,
+    {
+        self.get_by(|k| k.borrow() == key, || key.to_owned(), make)
+    }
+
+    /// As [`LastDist::get`], for keys that cannot be borrowed from the stored key, such as
+    /// several slices: `same` compares the stored key and `key` builds a new one.
+    pub fn get_by(
+        &mut self,
+        same: impl FnOnce(&K) -> bool,
+        key: impl FnOnce() -> K,
+        make: impl FnOnce() -> Result<D, DataFusionError>,
+    ) -> Result<&D, DataFusionError> {
+        let entry = match self.last.take() {
+            Some((k, d)) if same(&k) => (k, d),
+            _ => (key(), make()?),
+        };
+        let (_, d) = self.last.insert(entry);
+        Ok(d)
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    fmt::{self, Display},
+    sync::Arc,
+};
+
+use datafusion::{
+    arrow::{
+        array::{Array, ArrayRef, AsArray, ListArray},
+        buffer::OffsetBuffer,
+        compute::{cast, min},
+        datatypes::{DataType, Field, Float64Type, Int64Type},
+    },
+    error::DataFusionError,
+    logical_expr::ColumnarValue,
+    scalar::ScalarValue,
+};
+
+use super::list::coerce_float64_list;
+
+/// The kind of value a function argument takes, which decides the types it accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arg {
+    /// Any number, passed as `Float64`.
+    Float,
+    /// A non-negative integer, passed as `UInt64`. Floats and decimals are accepted when
+    /// their values are whole.
+    Count,
+    /// Any integer, passed as `Int64`.
+    Integer,
+    /// A `List` or `FixedSizeList` of numbers, passed as the same kind of list of `Float64`.
+    FloatList,
+    /// A `List` of non-negative integers, passed as `List<UInt64>`.
+    CountList,
+}
+
+impl Display for Arg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Arg::Float => write!(f, "a number"),
+            Arg::Count => write!(f, "a non-negative integer"),
+            Arg::Integer => write!(f, "an integer"),
+            Arg::FloatList => write!(f, "a List or FixedSizeList of numbers"),
+            Arg::CountList => write!(f, "a List of non-negative integers"),
+        }
+    }
+}
+
+impl Arg {
+    /// The type to coerce `data_type` to, if it is accepted.
+    ///
+    /// Signed integers given for counts are coerced to `Int64`, and floats and decimals to
+    /// `Float64`, rather than `UInt64`, so that [`count_arg`] can report negative and
+    /// fractional values instead of failing in the cast.
+    pub fn coerce(&self, data_type: &DataType) -> Option<DataType> {
+        match self {
+            Arg::Float => {
+                (data_type.is_numeric() || data_type.is_null()).then_some(DataType::Float64)
+            }
+            Arg::Count => count_type(data_type),
+            Arg::Integer => {
+                (data_type.is_integer() || data_type.is_null()).then_some(DataType::Int64)
+            }
+            Arg::FloatList => {
+                if data_type.is_null() {
+                    Some(DataType::new_list(DataType::Float64, true))
+                } else {
+                    coerce_float64_list(data_type)
+                }
+            }
+            Arg::CountList => match data_type {
+                DataType::Null => Some(DataType::new_list(DataType::UInt64, true)),
+                DataType::List(f) => count_type(f.data_type()).map(|t| DataType::new_list(t, true)),
+                _ => None,
+            },
+        }
+    }
+}
+
+fn count_type(data_type: &DataType) -> Option<DataType> {
+    if data_type.is_signed_integer() {
+        Some(DataType::Int64)
+    } else if data_type.is_unsigned_integer() || data_type.is_null() {
+        Some(DataType::UInt64)
+    } else if data_type.is_numeric() {
+        Some(DataType::Float64)
+    } else {
+        None
+    }
+}
+
+/// `v` as a count, or `None` if it is negative, fractional or too large.
+pub fn float_count(v: f64) -> Option<u64> {
+    // `u64::MAX as f64` rounds up to 2^64, which is out of range.
+    (v >= 0.0 && v.fract() == 0.0 && v < u64::MAX as f64).then_some(v as u64)
+}
+
+/// Coerce the argument types of the function `name` to the types it is evaluated with.
+pub fn coerce_args(
+    name: &str,
+    arg_types: &[DataType],
+    args: &[Arg],
+) -> Result<Vec<DataType>, DataFusionError> {
+    if arg_types.len() != args.len() {
+        return Err(DataFusionError::Plan(format!(
+            "{name} expects {} arguments, got {}",
+            args.len(),
+            arg_types.len()
+        )));
+    }
+    arg_types
+        .iter()
+        .zip(args)
+        .enumerate()
+        .map(|(i, (t, arg))| {
+            arg.coerce(t).ok_or_else(|| {
+                DataFusionError::Plan(format!("{name}: argument {} must be {arg}, got {t}", i + 1))
+            })
+        })
+        .collect()
+}
+
+/// Convert a count argument of the function `name` to `UInt64` (or `List<UInt64>`), with
+/// an error naming the function and the argument if any value is negative or, for a float
+/// or decimal argument, fractional. `position` counts from one.
+pub fn count_arg(
+    name: &str,
+    position: usize,
+    arg: &ColumnarValue,
+) -> Result<ColumnarValue, DataFusionError> {
+    let element = match arg.data_type() {
+        DataType::List(f) => f.data_type().clone(),
+        t => t,
+    };
+    if !matches!(element, DataType::Int64 | DataType::Float64) {
+        return Ok(arg.clone());
+    }
+
+    let array = arg.to_array(1)?;
+    let array = match array.data_type() {
+        DataType::List(_) => Arc::new(count_list(name, position, array.as_list::<i32>())?),
+        _ => count_values(name, position, &array)?,
+    };
+    Ok(match arg {
+        ColumnarValue::Scalar(_) => ColumnarValue::Scalar(ScalarValue::try_from_array(&array, 0)?),
+        ColumnarValue::Array(_) => ColumnarValue::Array(array),
+    })
+}
+
+/// A list of signed or float counts as a `List<UInt64>`. Only the values within the
+/// offsets are checked and converted, as the values outside a sliced list's offsets belong
+/// to no row.
+fn count_list(name: &str, position: usize, list: &ListArray) -> Result<ListArray, DataFusionError> {
+    let offsets = list.offsets();
+    let start = offsets[0];
+    let end = offsets[offsets.len() - 1];
+    let values = list.values().slice(start as usize, (end - start) as usize);
+    let values = count_values(name, position, &values)?;
+    let offsets = OffsetBuffer::new(offsets.iter().map(|o| o - start).collect());
+    let field = Arc::new(Field::new_list_field(DataType::UInt64, true));
+    Ok(ListArray::try_new(
+        field,
+        offsets,
+        values,
+        list.nulls().cloned(),
+    )?)
+}
+
+/// `Int64` or `Float64` counts as `UInt64`.
+fn count_values(
+    name: &str,
+    position: usize,
+    values: &ArrayRef,
+) -> Result<ArrayRef, DataFusionError> {
+    if values.data_type() == &DataType::Int64 {
+        if let Some(v) = min(values.as_primitive::<Int64Type>())
+            && v < 0
+        {
+            return Err(DataFusionError::Execution(format!(
+                "{name}: argument {position} must not be negative, got {v}"
+            )));
+        }
+    } else if let Some(v) = values
+        .as_primitive::<Float64Type>()
+        .iter()
+        .flatten()
+        .find(|&v| float_count(v).is_none())
+    {
+        return Err(DataFusionError::Execution(format!(
+            "{name}: argument {position} must be a non-negative integer, got {v}"
+        )));
+    }
+    Ok(cast(values, &DataType::UInt64)?)
+}
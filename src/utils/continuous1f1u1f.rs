@@ -3,14 +3,16 @@ use std::{marker::PhantomData, sync::Arc};
 use datafusion::{
     arrow::{
         array::{ArrayRef, Float64Array},
-        datatypes::DataType,
+        datatypes::{DataType, Float64Type},
     },
-    common::cast::{as_float64_array, as_uint64_array},
     error::DataFusionError,
     logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
 };
 
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
 use super::evaluator1f1u1f::Evaluator1F1U1F;
+use super::param::{Param, named};
 
 #[derive(Debug)]
 pub struct Continuous1F1U1F<E: Evaluator1F1U1F> {
@@ -23,10 +25,7 @@ impl<E: Evaluator1F1U1F> Continuous1F1U1F<E> {
     pub fn new(name: &str) -> Self {
         Continuous1F1U1F {
             name: String::from(name),
-            signature: Signature::exact(
-                vec![DataType::Float64, DataType::UInt64, DataType::Float64],
-                Volatility::Immutable,
-            ),
+            signature: Signature::user_defined(Volatility::Immutable),
             _phantom: PhantomData,
         }
     }
@@ -45,26 +44,28 @@ impl<E: Evaluator1F1U1F> ScalarUDFImpl for Continuous1F1U1F<E> {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Float, Arg::Count, Arg::Float])
+    }
+
     fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
         Ok(DataType::Float64)
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
-        let args = ColumnarValue::values_to_arrays(&args.args)?;
-        let x_array = as_float64_array(&args[0]).expect("cast failed");
-        let n_array = as_uint64_array(&args[1]).expect("cast failed");
-        let p_array = as_float64_array(&args[2]).expect("cast failed");
-
-        assert_eq!(x_array.len(), n_array.len());
-        assert_eq!(x_array.len(), p_array.len());
+        let x = Param::<Float64Type>::new(&self.name, &args.args, 0)?;
+        let n = Param::count(&self.name, &args.args, 1)?;
+        let p = Param::<Float64Type>::new(&self.name, &args.args, 2)?;
 
-        let array: Float64Array = x_array
-            .iter()
-            .zip(n_array)
-            .zip(p_array)
-            .map(|((x, n), p)| match (x, n, p) {
-                (Some(x), Some(n), Some(p)) => E::eval(x, n, p),
-                _ => Ok(Some(f64::NAN)),
+        let mut cache = LastDist::new();
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), n.get(i), p.get(i)) {
+                (Some(x), Some(n), Some(p)) => {
+                    let d =
+                        cache.get(&(n, p), || E::make(n, p).map_err(|e| named(&self.name, e)))?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
             })
             .collect::<Result<Float64Array, DataFusionError>>()?;
         Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
@@ -3,14 +3,16 @@ use std::{marker::PhantomData, sync::Arc};
 use datafusion::{
     arrow::{
         array::{ArrayRef, Float64Array},
-        datatypes::DataType,
+        datatypes::{DataType, Float64Type},
     },
-    common::cast::as_float64_array,
     error::DataFusionError,
     logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
 };
 
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
 use super::evaluator2f::Evaluator2F;
+use super::param::{Param, named};
 
 #[derive(Debug)]
 pub struct Continuous2F<E: Evaluator2F> {
@@ -23,7 +25,7 @@ impl<E: Evaluator2F> Continuous2F<E> {
     pub fn new(name: &str) -> Self {
         Continuous2F {
             name: String::from(name),
-            signature: Signature::uniform(2, vec![DataType::Float64], Volatility::Immutable),
+            signature: Signature::user_defined(Volatility::Immutable),
             _phantom: PhantomData,
         }
     }
@@ -42,23 +44,26 @@ impl<E: Evaluator2F> ScalarUDFImpl for Continuous2F<E> {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Float, Arg::Float])
+    }
+
     fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
         Ok(DataType::Float64)
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
-        let args = ColumnarValue::values_to_arrays(&args.args)?;
-        let x_array = as_float64_array(&args[0]).expect("cast failed");
-        let p_array = as_float64_array(&args[1]).expect("cast failed");
-
-        assert_eq!(x_array.len(), p_array.len());
+        let x = Param::<Float64Type>::new(&self.name, &args.args, 0)?;
+        let p = Param::<Float64Type>::new(&self.name, &args.args, 1)?;
 
-        let array: Float64Array = x_array
-            .iter()
-            .zip(p_array)
-            .map(|(x, p)| match (x, p) {
-                (Some(x), Some(p)) => E::eval(x, p),
-                _ => Ok(Some(f64::NAN)),
+        let mut cache = LastDist::new();
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), p.get(i)) {
+                (Some(x), Some(p)) => {
+                    let d = cache.get(&p, || E::make(p).map_err(|e| named(&self.name, e)))?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
             })
             .collect::<Result<Float64Array, DataFusionError>>()?;
         Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
@@ -1,24 +1,32 @@
 use std::{marker::PhantomData, sync::Arc};
 
 use datafusion::{
-    arrow::{array::{ArrayRef, Float64Array}, datatypes::DataType}, common::cast::as_float64_array, error::DataFusionError, logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility}
+    arrow::{
+        array::{ArrayRef, Float64Array},
+        datatypes::{DataType, Float64Type},
+    },
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
 };
 
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
 use super::evaluator3f::Evaluator3F;
+use super::param::{Param, named};
 
 #[derive(Debug)]
 pub struct Continuous3F<E: Evaluator3F> {
     name: String,
     signature: Signature,
-    _phantom: PhantomData<E>
+    _phantom: PhantomData<E>,
 }
 
 impl<E: Evaluator3F> Continuous3F<E> {
     pub fn new(name: &str) -> Self {
         Continuous3F {
             name: String::from(name),
-            signature: Signature::uniform(3, vec![DataType::Float64], Volatility::Immutable),
-            _phantom: PhantomData
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
         }
     }
 }
@@ -36,26 +44,29 @@ impl<E: Evaluator3F> ScalarUDFImpl for Continuous3F<E> {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Float, Arg::Float, Arg::Float])
+    }
+
     fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
         Ok(DataType::Float64)
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
-        let args = ColumnarValue::values_to_arrays(&args.args)?;
-        let x_array = as_float64_array(&args[0]).expect("cast failed");
-        let p1_array = as_float64_array(&args[1]).expect("cast failed");
-        let p2_array = as_float64_array(&args[2]).expect("cast failed");
-
-        assert_eq!(x_array.len(), p1_array.len());
-        assert_eq!(x_array.len(), p2_array.len());
-
-        let array: Float64Array = x_array
-            .iter()
-            .zip(p1_array)
-            .zip(p2_array)
-            .map(|((x, p1), p2)| match (x, p1, p2) {
-                (Some(x), Some(p1), Some(p2)) => E::eval(x, p1, p2),
-                _ => Ok(Some(f64::NAN)),
+        let x = Param::<Float64Type>::new(&self.name, &args.args, 0)?;
+        let p1 = Param::<Float64Type>::new(&self.name, &args.args, 1)?;
+        let p2 = Param::<Float64Type>::new(&self.name, &args.args, 2)?;
+
+        let mut cache = LastDist::new();
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), p1.get(i), p2.get(i)) {
+                (Some(x), Some(p1), Some(p2)) => {
+                    let d = cache.get(&(p1, p2), || {
+                        E::make(p1, p2).map_err(|e| named(&self.name, e))
+                    })?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
             })
             .collect::<Result<Float64Array, DataFusionError>>()?;
         Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
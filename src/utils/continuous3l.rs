@@ -0,0 +1,101 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, Float64Array},
+        datatypes::DataType,
+    },
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
+};
+
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::evaluator3l::Evaluator3L;
+use super::list::ListParam;
+use super::param::named;
+
+#[derive(Debug)]
+pub struct Continuous3L<E: Evaluator3L> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Evaluator3L> Continuous3L<E> {
+    pub fn new(name: &str) -> Self {
+        Continuous3L {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Evaluate at `x`, which must have the dimension `dim` of the distribution.
+    fn eval(
+        &self,
+        d: &E::Dist,
+        dim: usize,
+        x: &Float64Array,
+    ) -> Result<Option<f64>, DataFusionError> {
+        if x.len() != dim {
+            return Err(DataFusionError::Execution(format!(
+                "{}: x has {} elements but the distribution has dimension {}",
+                self.name,
+                x.len(),
+                dim
+            )));
+        }
+        E::eval(d, x.values())
+    }
+}
+
+impl<E: Evaluator3L> ScalarUDFImpl for Continuous3L<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(
+            &self.name,
+            arg_types,
+            &[Arg::FloatList, Arg::FloatList, Arg::FloatList],
+        )
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let x = ListParam::new(&self.name, &args.args, 0)?;
+        let p1 = ListParam::new(&self.name, &args.args, 1)?;
+        let p2 = ListParam::new(&self.name, &args.args, 2)?;
+
+        // Building the distribution factorizes its matrix, so reuse it while it is unchanged.
+        let mut cache = LastDist::<(Vec<f64>, Vec<f64>), E::Dist>::new();
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (x.row(i)?, p1.row(i)?, p2.row(i)?) {
+                (Some(x), Some(p1), Some(p2)) => {
+                    let (p1, p2) = (p1.values().as_ref(), p2.values().as_ref());
+                    let d = cache.get_by(
+                        |(k1, k2)| k1 == p1 && k2 == p2,
+                        || (p1.to_vec(), p2.to_vec()),
+                        || E::make(p1, p2).map_err(|e| named(&self.name, e)),
+                    )?;
+                    self.eval(d, p1.len(), &x)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<Float64Array, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
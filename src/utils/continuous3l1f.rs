@@ -0,0 +1,102 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, Float64Array},
+        datatypes::{DataType, Float64Type},
+    },
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
+};
+
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::evaluator3l1f::Evaluator3L1F;
+use super::list::ListParam;
+use super::param::{Param, named};
+
+#[derive(Debug)]
+pub struct Continuous3L1F<E: Evaluator3L1F> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Evaluator3L1F> Continuous3L1F<E> {
+    pub fn new(name: &str) -> Self {
+        Continuous3L1F {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Evaluate at `x`, which must have the dimension `dim` of the distribution.
+    fn eval(
+        &self,
+        d: &E::Dist,
+        dim: usize,
+        x: &Float64Array,
+    ) -> Result<Option<f64>, DataFusionError> {
+        if x.len() != dim {
+            return Err(DataFusionError::Execution(format!(
+                "{}: x has {} elements but the distribution has dimension {}",
+                self.name,
+                x.len(),
+                dim
+            )));
+        }
+        E::eval(d, x.values())
+    }
+}
+
+impl<E: Evaluator3L1F> ScalarUDFImpl for Continuous3L1F<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(
+            &self.name,
+            arg_types,
+            &[Arg::FloatList, Arg::FloatList, Arg::FloatList, Arg::Float],
+        )
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let x = ListParam::new(&self.name, &args.args, 0)?;
+        let p1 = ListParam::new(&self.name, &args.args, 1)?;
+        let p2 = ListParam::new(&self.name, &args.args, 2)?;
+        let p3 = Param::<Float64Type>::new(&self.name, &args.args, 3)?;
+
+        // Building the distribution factorizes its matrix, so reuse it while it is unchanged.
+        let mut cache = LastDist::<(Vec<f64>, Vec<f64>, f64), E::Dist>::new();
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (x.row(i)?, p1.row(i)?, p2.row(i)?, p3.get(i)) {
+                (Some(x), Some(p1), Some(p2), Some(p3)) => {
+                    let (p1, p2) = (p1.values().as_ref(), p2.values().as_ref());
+                    let d = cache.get_by(
+                        |(k1, k2, k3)| k1 == p1 && k2 == p2 && *k3 == p3,
+                        || (p1.to_vec(), p2.to_vec(), p3),
+                        || E::make(p1, p2, p3).map_err(|e| named(&self.name, e)),
+                    )?;
+                    self.eval(d, p1.len(), &x)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<Float64Array, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
@@ -0,0 +1,80 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{Array, ArrayRef, Float64Array},
+        datatypes::DataType,
+    },
+    common::cast::{as_list_array, as_uint64_array},
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
+};
+
+use super::coerce::{Arg, coerce_args, count_arg};
+use super::evaluator1lu1l::Evaluator1LU1L;
+use super::list::ListParam;
+use super::param::{arg, named, type_error};
+
+#[derive(Debug)]
+pub struct Discrete1LU1L<E: Evaluator1LU1L> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Evaluator1LU1L> Discrete1LU1L<E> {
+    pub fn new(name: &str) -> Self {
+        Discrete1LU1L {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: Evaluator1LU1L> ScalarUDFImpl for Discrete1LU1L<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::CountList, Arg::FloatList])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let x = count_arg(&self.name, 1, arg(&self.name, &args.args, 0)?)?;
+        let x = x.to_array(args.number_rows)?;
+        let x_array = match x.data_type() {
+            DataType::List(f) if f.data_type() == &DataType::UInt64 => as_list_array(&x)?,
+            other => return Err(type_error(&self.name, 0, "a list of UInt64", other)),
+        };
+        let p = ListParam::new(&self.name, &args.args, 1)?;
+
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (x_array.is_valid(i), p.row(i)?) {
+                (true, Some(p)) => {
+                    let x = x_array.value(i);
+                    let x = as_uint64_array(&x)?;
+                    if x.null_count() > 0 {
+                        return Ok(None);
+                    }
+                    E::eval(x.values(), p.values()).map_err(|e| named(&self.name, e))
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<Float64Array, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
@@ -2,15 +2,17 @@ use std::{marker::PhantomData, sync::Arc};
 
 use datafusion::{
     arrow::{
-        array::{ArrayRef, Float64Array},
-        datatypes::DataType,
+        array::{ArrayRef, Float64Array, UInt64Array},
+        datatypes::{DataType, Float64Type},
     },
-    common::cast::{as_float64_array, as_uint64_array},
     error::DataFusionError,
     logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
 };
 
-use super::evaluator1u1f::Evaluator1U1F;
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::evaluator1u1f::{Evaluator1U1F, InverseEvaluator1U1F};
+use super::param::{Param, named};
 
 #[derive(Debug)]
 pub struct Discrete1U1F<E: Evaluator1U1F> {
@@ -23,7 +25,7 @@ impl<E: Evaluator1U1F> Discrete1U1F<E> {
     pub fn new(name: &str) -> Self {
         Discrete1U1F {
             name: String::from(name),
-            signature: Signature::exact( vec![DataType::UInt64, DataType::Float64], Volatility::Immutable),
+            signature: Signature::user_defined(Volatility::Immutable),
             _phantom: PhantomData,
         }
     }
@@ -42,25 +44,84 @@ impl<E: Evaluator1U1F> ScalarUDFImpl for Discrete1U1F<E> {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Count, Arg::Float])
+    }
+
     fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
         Ok(DataType::Float64)
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
-        let args = ColumnarValue::values_to_arrays(&args.args)?;
-        let x_array = as_uint64_array(&args[0]).expect("cast failed");
-        let p_array = as_float64_array(&args[1]).expect("cast failed");
-
-        assert_eq!(x_array.len(), p_array.len());
-
-        let array: Float64Array = x_array
-            .iter()
-            .zip(p_array)
-            .map(|(x, p)| match (x, p) {
-                (Some(x), Some(p)) => E::eval(x, p),
-                _ => Ok(Some(f64::NAN)),
+        let x = Param::count(&self.name, &args.args, 0)?;
+        let p = Param::<Float64Type>::new(&self.name, &args.args, 1)?;
+
+        let mut cache = LastDist::new();
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), p.get(i)) {
+                (Some(x), Some(p)) => {
+                    let d = cache.get(&p, || E::make(p).map_err(|e| named(&self.name, e)))?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
             })
             .collect::<Result<Float64Array, DataFusionError>>()?;
         Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
     }
 }
+
+#[derive(Debug)]
+pub struct DiscreteInverse1U1F<E: InverseEvaluator1U1F> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: InverseEvaluator1U1F> DiscreteInverse1U1F<E> {
+    pub fn new(name: &str) -> Self {
+        DiscreteInverse1U1F {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: InverseEvaluator1U1F> ScalarUDFImpl for DiscreteInverse1U1F<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Float, Arg::Float])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let x = Param::<Float64Type>::new(&self.name, &args.args, 0)?;
+        let p = Param::<Float64Type>::new(&self.name, &args.args, 1)?;
+
+        let mut cache = LastDist::new();
+        let array: UInt64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), p.get(i)) {
+                (Some(x), Some(p)) => {
+                    let d = cache.get(&p, || E::make(p).map_err(|e| named(&self.name, e)))?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<UInt64Array, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
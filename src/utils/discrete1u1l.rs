@@ -0,0 +1,74 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, Float64Array},
+        datatypes::DataType,
+    },
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
+};
+
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::evaluator1u1l::Evaluator1U1L;
+use super::list::ListParam;
+use super::param::{Param, named};
+
+#[derive(Debug)]
+pub struct Discrete1U1L<E: Evaluator1U1L> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Evaluator1U1L> Discrete1U1L<E> {
+    pub fn new(name: &str) -> Self {
+        Discrete1U1L {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: Evaluator1U1L> ScalarUDFImpl for Discrete1U1L<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Count, Arg::FloatList])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let x = Param::count(&self.name, &args.args, 0)?;
+        let p = ListParam::new(&self.name, &args.args, 1)?;
+
+        let mut cache = LastDist::<Vec<f64>, E::Dist>::new();
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), p.row(i)?) {
+                (Some(x), Some(p)) => {
+                    let d = cache.get(p.values().as_ref(), || {
+                        E::make(p.values()).map_err(|e| named(&self.name, e))
+                    })?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<Float64Array, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
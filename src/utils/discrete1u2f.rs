@@ -2,15 +2,17 @@ use std::{marker::PhantomData, sync::Arc};
 
 use datafusion::{
     arrow::{
-        array::{ArrayRef, Float64Array},
-        datatypes::DataType,
+        array::{ArrayRef, Float64Array, UInt64Array},
+        datatypes::{DataType, Float64Type},
     },
-    common::cast::{as_float64_array, as_uint64_array},
     error::DataFusionError,
     logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
 };
 
-use super::evaluator1u2f::Evaluator1U2F;
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::evaluator1u2f::{Evaluator1U2F, InverseEvaluator1U2F};
+use super::param::{Param, named};
 
 #[derive(Debug)]
 pub struct Discrete1U2F<E: Evaluator1U2F> {
@@ -23,7 +25,7 @@ impl<E: Evaluator1U2F> Discrete1U2F<E> {
     pub fn new(name: &str) -> Self {
         Discrete1U2F {
             name: String::from(name),
-            signature: Signature::exact( vec![DataType::UInt64, DataType::Float64, DataType::Float64], Volatility::Immutable),
+            signature: Signature::user_defined(Volatility::Immutable),
             _phantom: PhantomData,
         }
     }
@@ -42,28 +44,90 @@ impl<E: Evaluator1U2F> ScalarUDFImpl for Discrete1U2F<E> {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Count, Arg::Float, Arg::Float])
+    }
+
     fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
         Ok(DataType::Float64)
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
-        let args = ColumnarValue::values_to_arrays(&args.args)?;
-        let x_array = as_uint64_array(&args[0]).expect("cast failed");
-        let p1_array = as_float64_array(&args[1]).expect("cast failed");
-        let p2_array = as_float64_array(&args[2]).expect("cast failed");
-
-        assert_eq!(x_array.len(), p1_array.len());
-        assert_eq!(x_array.len(), p2_array.len());
-
-        let array: Float64Array = x_array
-            .iter()
-            .zip(p1_array)
-            .zip(p2_array)
-            .map(|((x, p1), p2)| match (x, p1, p2) {
-                (Some(x), Some(p1), Some(p2)) => E::eval(x, p1, p2),
-                _ => Ok(Some(f64::NAN)),
+        let x = Param::count(&self.name, &args.args, 0)?;
+        let p1 = Param::<Float64Type>::new(&self.name, &args.args, 1)?;
+        let p2 = Param::<Float64Type>::new(&self.name, &args.args, 2)?;
+
+        let mut cache = LastDist::new();
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), p1.get(i), p2.get(i)) {
+                (Some(x), Some(p1), Some(p2)) => {
+                    let d = cache.get(&(p1, p2), || {
+                        E::make(p1, p2).map_err(|e| named(&self.name, e))
+                    })?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
             })
             .collect::<Result<Float64Array, DataFusionError>>()?;
         Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
     }
 }
+
+#[derive(Debug)]
+pub struct DiscreteInverse1U2F<E: InverseEvaluator1U2F> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: InverseEvaluator1U2F> DiscreteInverse1U2F<E> {
+    pub fn new(name: &str) -> Self {
+        DiscreteInverse1U2F {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: InverseEvaluator1U2F> ScalarUDFImpl for DiscreteInverse1U2F<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Float, Arg::Float, Arg::Float])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let x = Param::<Float64Type>::new(&self.name, &args.args, 0)?;
+        let p1 = Param::<Float64Type>::new(&self.name, &args.args, 1)?;
+        let p2 = Param::<Float64Type>::new(&self.name, &args.args, 2)?;
+
+        let mut cache = LastDist::new();
+        let array: UInt64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), p1.get(i), p2.get(i)) {
+                (Some(x), Some(p1), Some(p2)) => {
+                    let d = cache.get(&(p1, p2), || {
+                        E::make(p1, p2).map_err(|e| named(&self.name, e))
+                    })?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<UInt64Array, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
@@ -2,15 +2,17 @@ use std::{marker::PhantomData, sync::Arc};
 
 use datafusion::{
     arrow::{
-        array::{ArrayRef, Float64Array},
-        datatypes::DataType,
+        array::{ArrayRef, Float64Array, UInt64Array},
+        datatypes::{DataType, Float64Type},
     },
-    common::cast::{as_float64_array, as_uint64_array},
     error::DataFusionError,
     logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
 };
 
-use super::evaluator2u1f::Evaluator2U1F;
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::evaluator2u1f::{Evaluator2U1F, InverseEvaluator2U1F};
+use super::param::{Param, named};
 
 #[derive(Debug)]
 pub struct Discrete2U1F<E: Evaluator2U1F> {
@@ -23,10 +25,7 @@ impl<E: Evaluator2U1F> Discrete2U1F<E> {
     pub fn new(name: &str) -> Self {
         Discrete2U1F {
             name: String::from(name),
-            signature: Signature::exact(
-                vec![DataType::UInt64, DataType::UInt64, DataType::Float64],
-                Volatility::Immutable,
-            ),
+            signature: Signature::user_defined(Volatility::Immutable),
             _phantom: PhantomData,
         }
     }
@@ -45,28 +44,88 @@ impl<E: Evaluator2U1F> ScalarUDFImpl for Discrete2U1F<E> {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Count, Arg::Count, Arg::Float])
+    }
+
     fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
         Ok(DataType::Float64)
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
-        let args = ColumnarValue::values_to_arrays(&args.args)?;
-        let x_array = as_uint64_array(&args[0]).expect("cast failed");
-        let n_array = as_uint64_array(&args[1]).expect("cast failed");
-        let p_array = as_float64_array(&args[2]).expect("cast failed");
-
-        assert_eq!(x_array.len(), n_array.len());
-        assert_eq!(x_array.len(), p_array.len());
-
-        let array: Float64Array = x_array
-            .iter()
-            .zip(n_array)
-            .zip(p_array)
-            .map(|((x, n), p)| match (x, n, p) {
-                (Some(x), Some(n), Some(p)) => E::eval(x, n, p),
-                _ => Ok(Some(f64::NAN)),
+        let x = Param::count(&self.name, &args.args, 0)?;
+        let n = Param::count(&self.name, &args.args, 1)?;
+        let p = Param::<Float64Type>::new(&self.name, &args.args, 2)?;
+
+        let mut cache = LastDist::new();
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), n.get(i), p.get(i)) {
+                (Some(x), Some(n), Some(p)) => {
+                    let d =
+                        cache.get(&(n, p), || E::make(n, p).map_err(|e| named(&self.name, e)))?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
             })
             .collect::<Result<Float64Array, DataFusionError>>()?;
         Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
     }
 }
+
+#[derive(Debug)]
+pub struct DiscreteInverse2U1F<E: InverseEvaluator2U1F> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: InverseEvaluator2U1F> DiscreteInverse2U1F<E> {
+    pub fn new(name: &str) -> Self {
+        DiscreteInverse2U1F {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: InverseEvaluator2U1F> ScalarUDFImpl for DiscreteInverse2U1F<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Float, Arg::Count, Arg::Float])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let x = Param::<Float64Type>::new(&self.name, &args.args, 0)?;
+        let n = Param::count(&self.name, &args.args, 1)?;
+        let p = Param::<Float64Type>::new(&self.name, &args.args, 2)?;
+
+        let mut cache = LastDist::new();
+        let array: UInt64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), n.get(i), p.get(i)) {
+                (Some(x), Some(n), Some(p)) => {
+                    let d =
+                        cache.get(&(n, p), || E::make(n, p).map_err(|e| named(&self.name, e)))?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<UInt64Array, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
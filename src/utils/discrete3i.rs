@@ -0,0 +1,141 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, Float64Array, Int64Array},
+        datatypes::{DataType, Float64Type, Int64Type},
+    },
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
+};
+
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::evaluator3i::{Evaluator3I, InverseEvaluator3I};
+use super::param::{Param, named};
+
+#[derive(Debug)]
+pub struct Discrete3I<E: Evaluator3I> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Evaluator3I> Discrete3I<E> {
+    pub fn new(name: &str) -> Self {
+        Discrete3I {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: Evaluator3I> ScalarUDFImpl for Discrete3I<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(
+            &self.name,
+            arg_types,
+            &[Arg::Integer, Arg::Integer, Arg::Integer],
+        )
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let x = Param::<Int64Type>::new(&self.name, &args.args, 0)?;
+        let p1 = Param::<Int64Type>::new(&self.name, &args.args, 1)?;
+        let p2 = Param::<Int64Type>::new(&self.name, &args.args, 2)?;
+
+        let mut cache = LastDist::new();
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), p1.get(i), p2.get(i)) {
+                (Some(x), Some(p1), Some(p2)) => {
+                    let d = cache.get(&(p1, p2), || {
+                        E::make(p1, p2).map_err(|e| named(&self.name, e))
+                    })?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<Float64Array, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
+
+#[derive(Debug)]
+pub struct DiscreteInverse3I<E: InverseEvaluator3I> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: InverseEvaluator3I> DiscreteInverse3I<E> {
+    pub fn new(name: &str) -> Self {
+        DiscreteInverse3I {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: InverseEvaluator3I> ScalarUDFImpl for DiscreteInverse3I<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(
+            &self.name,
+            arg_types,
+            &[Arg::Float, Arg::Integer, Arg::Integer],
+        )
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let x = Param::<Float64Type>::new(&self.name, &args.args, 0)?;
+        let p1 = Param::<Int64Type>::new(&self.name, &args.args, 1)?;
+        let p2 = Param::<Int64Type>::new(&self.name, &args.args, 2)?;
+
+        let mut cache = LastDist::new();
+        let array: Int64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), p1.get(i), p2.get(i)) {
+                (Some(x), Some(p1), Some(p2)) => {
+                    let d = cache.get(&(p1, p2), || {
+                        E::make(p1, p2).map_err(|e| named(&self.name, e))
+                    })?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<Int64Array, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
@@ -1,24 +1,32 @@
 use std::{marker::PhantomData, sync::Arc};
 
 use datafusion::{
-    arrow::{array::{ArrayRef, Float64Array}, datatypes::DataType}, common::cast::as_uint64_array, error::DataFusionError, logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility}
+    arrow::{
+        array::{ArrayRef, Float64Array, UInt64Array},
+        datatypes::{DataType, Float64Type},
+    },
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
 };
 
-use super::evaluator4u::Evaluator4U;
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::evaluator4u::{Evaluator4U, InverseEvaluator4U};
+use super::param::{Param, named};
 
 #[derive(Debug)]
 pub struct Discrete4U<E: Evaluator4U> {
     name: String,
     signature: Signature,
-    _phantom: PhantomData<E>
+    _phantom: PhantomData<E>,
 }
 
 impl<E: Evaluator4U> Discrete4U<E> {
     pub fn new(name: &str) -> Self {
         Discrete4U {
             name: String::from(name),
-            signature: Signature::uniform(4, vec![DataType::UInt64], Volatility::Immutable),
-            _phantom: PhantomData
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
         }
     }
 }
@@ -36,31 +44,100 @@ impl<E: Evaluator4U> ScalarUDFImpl for Discrete4U<E> {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(
+            &self.name,
+            arg_types,
+            &[Arg::Count, Arg::Count, Arg::Count, Arg::Count],
+        )
+    }
+
     fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
         Ok(DataType::Float64)
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
-        let args = ColumnarValue::values_to_arrays(&args.args)?;
-        let x_array = as_uint64_array(&args[0]).expect("cast failed");
-        let p1_array = as_uint64_array(&args[1]).expect("cast failed");
-        let p2_array = as_uint64_array(&args[2]).expect("cast failed");
-        let p3_array = as_uint64_array(&args[3]).expect("cast failed");
-
-        assert_eq!(x_array.len(), p1_array.len());
-        assert_eq!(x_array.len(), p2_array.len());
-        assert_eq!(x_array.len(), p3_array.len());
-
-        let array: Float64Array = x_array
-            .iter()
-            .zip(p1_array)
-            .zip(p2_array)
-            .zip(p3_array)
-            .map(|(((x, p1), p2), p3)| match (x, p1, p2, p3) {
-                (Some(x), Some(p1), Some(p2), Some(p3)) => E::eval(x, p1, p2, p3),
-                _ => Ok(Some(f64::NAN)),
+        let x = Param::count(&self.name, &args.args, 0)?;
+        let p1 = Param::count(&self.name, &args.args, 1)?;
+        let p2 = Param::count(&self.name, &args.args, 2)?;
+        let p3 = Param::count(&self.name, &args.args, 3)?;
+
+        let mut cache = LastDist::new();
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), p1.get(i), p2.get(i), p3.get(i)) {
+                (Some(x), Some(p1), Some(p2), Some(p3)) => {
+                    let d = cache.get(&(p1, p2, p3), || {
+                        E::make(p1, p2, p3).map_err(|e| named(&self.name, e))
+                    })?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
             })
             .collect::<Result<Float64Array, DataFusionError>>()?;
         Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
     }
 }
+
+#[derive(Debug)]
+pub struct DiscreteInverse4U<E: InverseEvaluator4U> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: InverseEvaluator4U> DiscreteInverse4U<E> {
+    pub fn new(name: &str) -> Self {
+        DiscreteInverse4U {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: InverseEvaluator4U> ScalarUDFImpl for DiscreteInverse4U<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(
+            &self.name,
+            arg_types,
+            &[Arg::Float, Arg::Count, Arg::Count, Arg::Count],
+        )
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let x = Param::<Float64Type>::new(&self.name, &args.args, 0)?;
+        let p1 = Param::count(&self.name, &args.args, 1)?;
+        let p2 = Param::count(&self.name, &args.args, 2)?;
+        let p3 = Param::count(&self.name, &args.args, 3)?;
+
+        let mut cache = LastDist::new();
+        let array: UInt64Array = (0..args.number_rows)
+            .map(|i| match (x.get(i), p1.get(i), p2.get(i), p3.get(i)) {
+                (Some(x), Some(p1), Some(p2), Some(p3)) => {
+                    let d = cache.get(&(p1, p2, p3), || {
+                        E::make(p1, p2, p3).map_err(|e| named(&self.name, e))
+                    })?;
+                    E::eval(d, x)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<UInt64Array, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
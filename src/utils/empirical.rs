@@ -0,0 +1,249 @@
+use std::{any::Any, marker::PhantomData, mem::size_of, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, AsArray, Float64Array, ListArray},
+        datatypes::{DataType, Float64Type},
+    },
+    common::cast::as_float64_array,
+    error::DataFusionError,
+    logical_expr::{
+        Accumulator, AggregateUDFImpl, ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+        Volatility, function::AccumulatorArgs,
+    },
+    scalar::ScalarValue,
+};
+
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::list::ListParam;
+use super::param::Param;
+
+/// Aggregate collecting a column into the sorted sample that describes its empirical
+/// distribution, returned as a `List<Float64>`. Nulls and NaNs are skipped.
+#[derive(Debug)]
+pub struct EmpiricalDist {
+    name: String,
+    signature: Signature,
+}
+
+impl EmpiricalDist {
+    pub fn new(name: &str) -> Self {
+        EmpiricalDist {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for EmpiricalDist {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Float])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::new_list(DataType::Float64, true))
+    }
+
+    fn accumulator(
+        &self,
+        _acc_args: AccumulatorArgs,
+    ) -> datafusion::error::Result<Box<dyn Accumulator>> {
+        Ok(Box::new(EmpiricalAccumulator::default()))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct EmpiricalAccumulator {
+    sample: Vec<f64>,
+}
+
+impl EmpiricalAccumulator {
+    fn extend(&mut self, values: &Float64Array) {
+        self.sample
+            .extend(values.iter().flatten().filter(|x| !x.is_nan()));
+    }
+
+    fn to_scalar(&self) -> ScalarValue {
+        let list = ListArray::from_iter_primitive::<Float64Type, _, _>(vec![Some(
+            self.sample.iter().copied().map(Some),
+        )]);
+        ScalarValue::List(Arc::new(list))
+    }
+}
+
+impl Accumulator for EmpiricalAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> datafusion::error::Result<()> {
+        self.extend(as_float64_array(&values[0])?);
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> datafusion::error::Result<()> {
+        for sample in states[0].as_list::<i32>().iter().flatten() {
+            self.extend(as_float64_array(&sample)?);
+        }
+        Ok(())
+    }
+
+    fn state(&mut self) -> datafusion::error::Result<Vec<ScalarValue>> {
+        Ok(vec![self.to_scalar()])
+    }
+
+    fn evaluate(&mut self) -> datafusion::error::Result<ScalarValue> {
+        self.sample.sort_unstable_by(f64::total_cmp);
+        Ok(self.to_scalar())
+    }
+
+    fn size(&self) -> usize {
+        size_of::<Self>() + self.sample.capacity() * size_of::<f64>()
+    }
+}
+
+/// Evaluate a function of the empirical distribution given by a sorted sample.
+///
+/// The results agree with [`statrs::distribution::Empirical`], but use a binary search over
+/// the sample so nothing needs to be rebuilt per row.
+pub trait EvaluatorEmpirical: std::fmt::Debug + Send + Sync + 'static {
+    fn eval(sample: &[f64], x: f64) -> Result<Option<f64>, DataFusionError>;
+}
+
+/// The sample sorted, as a list literal or a hand-built state need not be.
+fn sorted(sample: &[f64]) -> Vec<f64> {
+    let mut sample = sample.to_vec();
+    sample.sort_unstable_by(f64::total_cmp);
+    sample
+}
+
+/// The number of elements of the sorted `sample` that are at most `x`.
+fn count_at_most(sample: &[f64], x: f64) -> usize {
+    sample.partition_point(|&v| v <= x)
+}
+
+#[derive(Debug)]
+pub struct CdfEvaluatorEmpirical;
+
+impl EvaluatorEmpirical for CdfEvaluatorEmpirical {
+    fn eval(sample: &[f64], x: f64) -> Result<Option<f64>, DataFusionError> {
+        if x.is_nan() {
+            return Ok(Some(f64::NAN));
+        }
+        Ok(Some(count_at_most(sample, x) as f64 / sample.len() as f64))
+    }
+}
+
+#[derive(Debug)]
+pub struct SfEvaluatorEmpirical;
+
+impl EvaluatorEmpirical for SfEvaluatorEmpirical {
+    fn eval(sample: &[f64], x: f64) -> Result<Option<f64>, DataFusionError> {
+        if x.is_nan() {
+            return Ok(Some(f64::NAN));
+        }
+        let above = sample.len() - count_at_most(sample, x);
+        Ok(Some(above as f64 / sample.len() as f64))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnCdfEvaluatorEmpirical;
+
+impl EvaluatorEmpirical for LnCdfEvaluatorEmpirical {
+    fn eval(sample: &[f64], x: f64) -> Result<Option<f64>, DataFusionError> {
+        if x.is_nan() {
+            return Ok(Some(f64::NAN));
+        }
+        Ok(Some(
+            (count_at_most(sample, x) as f64).ln() - (sample.len() as f64).ln(),
+        ))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnSfEvaluatorEmpirical;
+
+impl EvaluatorEmpirical for LnSfEvaluatorEmpirical {
+    fn eval(sample: &[f64], x: f64) -> Result<Option<f64>, DataFusionError> {
+        if x.is_nan() {
+            return Ok(Some(f64::NAN));
+        }
+        let above = sample.len() - count_at_most(sample, x);
+        Ok(Some((above as f64).ln() - (sample.len() as f64).ln()))
+    }
+}
+
+#[derive(Debug)]
+pub struct Empirical<E: EvaluatorEmpirical> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: EvaluatorEmpirical> Empirical<E> {
+    pub fn new(name: &str) -> Self {
+        Empirical {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: EvaluatorEmpirical> ScalarUDFImpl for Empirical<E> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::FloatList, Arg::Float])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let sample = ListParam::new(&self.name, &args.args, 0)?;
+        let x = Param::<Float64Type>::new(&self.name, &args.args, 1)?;
+
+        // A sample from `empirical_dist` is already sorted and is used as it is. Otherwise the
+        // rows of a literal sample share its buffer, so the sample is sorted once for as long
+        // as rows point at the same values.
+        let mut cache = LastDist::<(*const f64, usize), Vec<f64>>::new();
+        let array: Float64Array = (0..args.number_rows)
+            .map(|i| match (sample.row(i)?, x.get(i)) {
+                (Some(sample), Some(_)) if sample.is_empty() => Ok(None),
+                (Some(sample), Some(x)) if sample.values().is_sorted() => {
+                    E::eval(sample.values(), x)
+                }
+                (Some(sample), Some(x)) => {
+                    let values = sample.values();
+                    let key = (values.as_ptr(), values.len());
+                    let sample = cache.get(&key, || Ok(sorted(values)))?;
+                    E::eval(sample, x)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<Float64Array, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
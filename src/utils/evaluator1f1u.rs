@@ -4,9 +4,16 @@ use datafusion::error::DataFusionError;
 use statrs::distribution::{Continuous, ContinuousCDF};
 
 use super::factory1u::Factory1U;
+use super::inverse_cdf::InverseCdf;
+use super::inverse_sf::InverseSf;
+use super::ln_cdf::LnCdf;
 
 pub trait Evaluator1F1U: std::fmt::Debug + Send + Sync + 'static {
-    fn eval(x: f64, n: u64) -> Result<Option<f64>, DataFusionError>;
+    type Dist;
+
+    fn make(n: u64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: f64) -> Result<Option<f64>, DataFusionError>;
 }
 
 #[derive(Debug)]
@@ -15,8 +22,13 @@ pub struct PdfEvaluator1F1U<D: Factory1U + Continuous<f64, f64>> {
 }
 
 impl<D: Factory1U + Continuous<f64, f64>> Evaluator1F1U for PdfEvaluator1F1U<D> {
-    fn eval(x: f64, n: u64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(n)?;
+    type Dist = D;
+
+    fn make(n: u64) -> Result<D, DataFusionError> {
+        D::make(n)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.pdf(x)))
     }
 }
@@ -27,8 +39,13 @@ pub struct LnPdfEvaluator1F1U<D: Factory1U + Continuous<f64, f64>> {
 }
 
 impl<D: Factory1U + Continuous<f64, f64>> Evaluator1F1U for LnPdfEvaluator1F1U<D> {
-    fn eval(x: f64, n: u64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(n)?;
+    type Dist = D;
+
+    fn make(n: u64) -> Result<D, DataFusionError> {
+        D::make(n)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.ln_pdf(x)))
     }
 }
@@ -39,8 +56,13 @@ pub struct CdfEvaluator1F1U<D: Factory1U + ContinuousCDF<f64, f64>> {
 }
 
 impl<D: Factory1U + ContinuousCDF<f64, f64>> Evaluator1F1U for CdfEvaluator1F1U<D> {
-    fn eval(x: f64, n: u64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(n)?;
+    type Dist = D;
+
+    fn make(n: u64) -> Result<D, DataFusionError> {
+        D::make(n)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.cdf(x)))
     }
 }
@@ -51,8 +73,87 @@ pub struct SfEvaluator1F1U<D: Factory1U + ContinuousCDF<f64, f64>> {
 }
 
 impl<D: Factory1U + ContinuousCDF<f64, f64>> Evaluator1F1U for SfEvaluator1F1U<D> {
-    fn eval(x: f64, n: u64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(n)?;
+    type Dist = D;
+
+    fn make(n: u64) -> Result<D, DataFusionError> {
+        D::make(n)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.sf(x)))
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug)]
+pub struct LnCdfEvaluator1F1U<D: Factory1U + LnCdf<f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U + LnCdf<f64>> Evaluator1F1U for LnCdfEvaluator1F1U<D> {
+    type Dist = D;
+
+    fn make(n: u64) -> Result<D, DataFusionError> {
+        D::make(n)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_cdf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnSfEvaluator1F1U<D: Factory1U + LnCdf<f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U + LnCdf<f64>> Evaluator1F1U for LnSfEvaluator1F1U<D> {
+    type Dist = D;
+
+    fn make(n: u64) -> Result<D, DataFusionError> {
+        D::make(n)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_sf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseCdfEvaluator1F1U<D: Factory1U + InverseCdf> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U + InverseCdf> Evaluator1F1U for InverseCdfEvaluator1F1U<D> {
+    type Dist = D;
+
+    fn make(n: u64) -> Result<D, DataFusionError> {
+        D::make(n)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(Some(f64::NAN));
+        }
+        Ok(Some(d.quantile(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseSfEvaluator1F1U<D: Factory1U + InverseSf> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U + InverseSf> Evaluator1F1U for InverseSfEvaluator1F1U<D> {
+    type Dist = D;
+
+    fn make(n: u64) -> Result<D, DataFusionError> {
+        D::make(n)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(Some(f64::NAN));
+        }
+        Ok(Some(d.inverse_sf(x)))
+    }
+}
@@ -4,9 +4,16 @@ use datafusion::error::DataFusionError;
 use statrs::distribution::{Continuous, ContinuousCDF};
 
 use super::factory1u1f::Factory1U1F;
+use super::inverse_cdf::InverseCdf;
+use super::inverse_sf::InverseSf;
+use super::ln_cdf::LnCdf;
 
 pub trait Evaluator1F1U1F: std::fmt::Debug + Send + Sync + 'static {
-    fn eval(x: f64, n: u64, p: f64) -> Result<Option<f64>, DataFusionError>;
+    type Dist;
+
+    fn make(n: u64, p: f64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: f64) -> Result<Option<f64>, DataFusionError>;
 }
 
 #[derive(Debug)]
@@ -15,8 +22,13 @@ pub struct PdfEvaluator1F1U1F<D: Factory1U1F + Continuous<f64, f64>> {
 }
 
 impl<D: Factory1U1F + Continuous<f64, f64>> Evaluator1F1U1F for PdfEvaluator1F1U1F<D> {
-    fn eval(x: f64, n: u64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(n, p)?;
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.pdf(x)))
     }
 }
@@ -27,8 +39,13 @@ pub struct LnPdfEvaluator1F1U1F<D: Factory1U1F + Continuous<f64, f64>> {
 }
 
 impl<D: Factory1U1F + Continuous<f64, f64>> Evaluator1F1U1F for LnPdfEvaluator1F1U1F<D> {
-    fn eval(x: f64, n: u64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(n, p)?;
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.ln_pdf(x)))
     }
 }
@@ -39,8 +56,13 @@ pub struct CdfEvaluator1F1U1F<D: Factory1U1F + ContinuousCDF<f64, f64>> {
 }
 
 impl<D: Factory1U1F + ContinuousCDF<f64, f64>> Evaluator1F1U1F for CdfEvaluator1F1U1F<D> {
-    fn eval(x: f64, n: u64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(n, p)?;
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.cdf(x)))
     }
 }
@@ -51,8 +73,87 @@ pub struct SfEvaluator1F1U1F<D: Factory1U1F + ContinuousCDF<f64, f64>> {
 }
 
 impl<D: Factory1U1F + ContinuousCDF<f64, f64>> Evaluator1F1U1F for SfEvaluator1F1U1F<D> {
-    fn eval(x: f64, n: u64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(n, p)?;
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.sf(x)))
     }
 }
+
+#[derive(Debug)]
+pub struct LnCdfEvaluator1F1U1F<D: Factory1U1F + LnCdf<f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U1F + LnCdf<f64>> Evaluator1F1U1F for LnCdfEvaluator1F1U1F<D> {
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_cdf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnSfEvaluator1F1U1F<D: Factory1U1F + LnCdf<f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U1F + LnCdf<f64>> Evaluator1F1U1F for LnSfEvaluator1F1U1F<D> {
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_sf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseCdfEvaluator1F1U1F<D: Factory1U1F + InverseCdf> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U1F + InverseCdf> Evaluator1F1U1F for InverseCdfEvaluator1F1U1F<D> {
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(Some(f64::NAN));
+        }
+        Ok(Some(d.quantile(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseSfEvaluator1F1U1F<D: Factory1U1F + InverseSf> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U1F + InverseSf> Evaluator1F1U1F for InverseSfEvaluator1F1U1F<D> {
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(Some(f64::NAN));
+        }
+        Ok(Some(d.inverse_sf(x)))
+    }
+}
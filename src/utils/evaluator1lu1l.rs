@@ -0,0 +1,77 @@
+use std::marker::PhantomData;
+
+use datafusion::error::DataFusionError;
+use nalgebra::Dyn;
+use statrs::distribution::Multinomial;
+use statrs::function::factorial::ln_factorial;
+
+use super::factory1l1u::Factory1L1U;
+
+pub trait Evaluator1LU1L: std::fmt::Debug + Send + Sync + 'static {
+    fn eval(x: &[u64], p: &[f64]) -> Result<Option<f64>, DataFusionError>;
+}
+
+/// The log-PMF of a vector of counts, computed entirely in log space: statrs builds the
+/// multinomial coefficient as a float before taking its logarithm, which overflows once
+/// the counts reach the thousands.
+pub trait LnPmfCounts {
+    fn ln_pmf_counts(&self, x: &[u64]) -> f64;
+}
+
+impl LnPmfCounts for Multinomial<Dyn> {
+    /// `ln n! - Σ ln x_i! + Σ x_i ln p_i`, leaving out the categories without counts so
+    /// that a zero probability with a zero count adds nothing rather than `0 · -∞ = NaN`.
+    fn ln_pmf_counts(&self, x: &[u64]) -> f64 {
+        if x.iter().sum::<u64>() != self.n() {
+            return f64::NEG_INFINITY;
+        }
+        x.iter()
+            .zip(self.p().iter())
+            .filter(|(k, _)| **k > 0)
+            .fold(ln_factorial(self.n()), |acc, (&k, p)| {
+                acc - ln_factorial(k) + k as f64 * p.ln()
+            })
+    }
+}
+
+/// Build the distribution for the counts `x`, taking the number of trials to be their sum.
+fn make_for_counts<D: Factory1L1U>(x: &[u64], p: &[f64]) -> Result<D, DataFusionError> {
+    if x.len() != p.len() {
+        return Err(DataFusionError::Execution(format!(
+            "counts and probabilities must have the same length, got {} and {}",
+            x.len(),
+            p.len()
+        )));
+    }
+    let n = x
+        .iter()
+        .try_fold(0u64, |n, &k| n.checked_add(k))
+        .ok_or_else(|| {
+            DataFusionError::Execution(String::from("sum of counts overflows UInt64"))
+        })?;
+    D::make(p, n)
+}
+
+#[derive(Debug)]
+pub struct PmfEvaluator1LU1L<D: Factory1L1U + LnPmfCounts> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1L1U + LnPmfCounts> Evaluator1LU1L for PmfEvaluator1LU1L<D> {
+    fn eval(x: &[u64], p: &[f64]) -> Result<Option<f64>, DataFusionError> {
+        let d = make_for_counts::<D>(x, p)?;
+        Ok(Some(d.ln_pmf_counts(x).exp()))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnPmfEvaluator1LU1L<D: Factory1L1U + LnPmfCounts> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1L1U + LnPmfCounts> Evaluator1LU1L for LnPmfEvaluator1LU1L<D> {
+    fn eval(x: &[u64], p: &[f64]) -> Result<Option<f64>, DataFusionError> {
+        let d = make_for_counts::<D>(x, p)?;
+        Ok(Some(d.ln_pmf_counts(x)))
+    }
+}
@@ -4,9 +4,16 @@ use datafusion::error::DataFusionError;
 use statrs::distribution::{Discrete, DiscreteCDF};
 
 use super::factory1f::Factory1F;
+use super::inverse_cdf::discrete_quantile;
+use super::inverse_sf::discrete_inverse_sf;
+use super::ln_cdf::LnCdf;
 
 pub trait Evaluator1U1F: std::fmt::Debug + Send + Sync + 'static {
-    fn eval(x: u64, p: f64) -> Result<Option<f64>, DataFusionError>;
+    type Dist;
+
+    fn make(p: f64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: u64) -> Result<Option<f64>, DataFusionError>;
 }
 
 #[derive(Debug)]
@@ -15,8 +22,13 @@ pub struct PmfEvaluator1U1F<D: Factory1F + Discrete<u64, f64>> {
 }
 
 impl<D: Factory1F + Discrete<u64, f64>> Evaluator1U1F for PmfEvaluator1U1F<D> {
-    fn eval(x: u64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p)?;
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.pmf(x)))
     }
 }
@@ -27,8 +39,13 @@ pub struct LnPmfEvaluator1U1F<D: Factory1F + Discrete<u64, f64>> {
 }
 
 impl<D: Factory1F + Discrete<u64, f64>> Evaluator1U1F for LnPmfEvaluator1U1F<D> {
-    fn eval(x: u64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p)?;
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.ln_pmf(x)))
     }
 }
@@ -39,8 +56,13 @@ pub struct CdfEvaluator1U1F<D: Factory1F + DiscreteCDF<u64, f64>> {
 }
 
 impl<D: Factory1F + DiscreteCDF<u64, f64>> Evaluator1U1F for CdfEvaluator1U1F<D> {
-    fn eval(x: u64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p)?;
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.cdf(x)))
     }
 }
@@ -51,8 +73,95 @@ pub struct SfEvaluator1U1F<D: Factory1F + DiscreteCDF<u64, f64>> {
 }
 
 impl<D: Factory1F + DiscreteCDF<u64, f64>> Evaluator1U1F for SfEvaluator1U1F<D> {
-    fn eval(x: u64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p)?;
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.sf(x)))
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug)]
+pub struct LnCdfEvaluator1U1F<D: Factory1F + LnCdf<u64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1F + LnCdf<u64>> Evaluator1U1F for LnCdfEvaluator1U1F<D> {
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_cdf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnSfEvaluator1U1F<D: Factory1F + LnCdf<u64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1F + LnCdf<u64>> Evaluator1U1F for LnSfEvaluator1U1F<D> {
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_sf(x)))
+    }
+}
+
+pub trait InverseEvaluator1U1F: std::fmt::Debug + Send + Sync + 'static {
+    type Dist;
+
+    fn make(p: f64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: f64) -> Result<Option<u64>, DataFusionError>;
+}
+
+#[derive(Debug)]
+pub struct InverseCdfEvaluator1U1F<D: Factory1F + DiscreteCDF<u64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1F + DiscreteCDF<u64, f64>> InverseEvaluator1U1F for InverseCdfEvaluator1U1F<D> {
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<u64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(None);
+        }
+        Ok(Some(discrete_quantile(d, x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseSfEvaluator1U1F<D: Factory1F + DiscreteCDF<u64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1F + DiscreteCDF<u64, f64>> InverseEvaluator1U1F for InverseSfEvaluator1U1F<D> {
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<u64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(None);
+        }
+        Ok(Some(discrete_inverse_sf(d, x)))
+    }
+}
@@ -0,0 +1,117 @@
+use std::marker::PhantomData;
+
+use datafusion::error::DataFusionError;
+use statrs::distribution::{Discrete, DiscreteCDF};
+
+use super::factory1l::Factory1L;
+use super::ln_cdf::LnCdf;
+
+pub trait Evaluator1U1L: std::fmt::Debug + Send + Sync + 'static {
+    type Dist;
+
+    fn make(p: &[f64]) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: u64) -> Result<Option<f64>, DataFusionError>;
+}
+
+#[derive(Debug)]
+pub struct PmfEvaluator1U1L<D: Factory1L + Discrete<u64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1L + Discrete<u64, f64>> Evaluator1U1L for PmfEvaluator1U1L<D> {
+    type Dist = D;
+
+    fn make(p: &[f64]) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.pmf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnPmfEvaluator1U1L<D: Factory1L + Discrete<u64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1L + Discrete<u64, f64>> Evaluator1U1L for LnPmfEvaluator1U1L<D> {
+    type Dist = D;
+
+    fn make(p: &[f64]) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_pmf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct CdfEvaluator1U1L<D: Factory1L + DiscreteCDF<u64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1L + DiscreteCDF<u64, f64>> Evaluator1U1L for CdfEvaluator1U1L<D> {
+    type Dist = D;
+
+    fn make(p: &[f64]) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.cdf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct SfEvaluator1U1L<D: Factory1L + DiscreteCDF<u64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1L + DiscreteCDF<u64, f64>> Evaluator1U1L for SfEvaluator1U1L<D> {
+    type Dist = D;
+
+    fn make(p: &[f64]) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.sf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnCdfEvaluator1U1L<D: Factory1L + LnCdf<u64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1L + LnCdf<u64>> Evaluator1U1L for LnCdfEvaluator1U1L<D> {
+    type Dist = D;
+
+    fn make(p: &[f64]) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_cdf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnSfEvaluator1U1L<D: Factory1L + LnCdf<u64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1L + LnCdf<u64>> Evaluator1U1L for LnSfEvaluator1U1L<D> {
+    type Dist = D;
+
+    fn make(p: &[f64]) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_sf(x)))
+    }
+}
@@ -4,9 +4,17 @@ use datafusion::error::DataFusionError;
 use statrs::distribution::{Continuous, ContinuousCDF};
 
 use super::factory1f::Factory1F;
+use super::hazard::Hazard;
+use super::inverse_cdf::InverseCdf;
+use super::inverse_sf::InverseSf;
+use super::ln_cdf::LnCdf;
 
 pub trait Evaluator2F: std::fmt::Debug + Send + Sync + 'static {
-    fn eval(x: f64, p: f64) -> Result<Option<f64>, DataFusionError>;
+    type Dist;
+
+    fn make(p: f64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: f64) -> Result<Option<f64>, DataFusionError>;
 }
 
 #[derive(Debug)]
@@ -15,8 +23,13 @@ pub struct PdfEvaluator2F<D: Factory1F + Continuous<f64, f64>> {
 }
 
 impl<D: Factory1F + Continuous<f64, f64>> Evaluator2F for PdfEvaluator2F<D> {
-    fn eval(x: f64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p)?;
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.pdf(x)))
     }
 }
@@ -27,8 +40,13 @@ pub struct LnPdfEvaluator2F<D: Factory1F + Continuous<f64, f64>> {
 }
 
 impl<D: Factory1F + Continuous<f64, f64>> Evaluator2F for LnPdfEvaluator2F<D> {
-    fn eval(x: f64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p)?;
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.ln_pdf(x)))
     }
 }
@@ -39,8 +57,13 @@ pub struct CdfEvaluator2F<D: Factory1F + ContinuousCDF<f64, f64>> {
 }
 
 impl<D: Factory1F + ContinuousCDF<f64, f64>> Evaluator2F for CdfEvaluator2F<D> {
-    fn eval(x: f64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p)?;
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.cdf(x)))
     }
 }
@@ -51,8 +74,121 @@ pub struct SfEvaluator2F<D: Factory1F + ContinuousCDF<f64, f64>> {
 }
 
 impl<D: Factory1F + ContinuousCDF<f64, f64>> Evaluator2F for SfEvaluator2F<D> {
-    fn eval(x: f64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p)?;
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.sf(x)))
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug)]
+pub struct LnCdfEvaluator2F<D: Factory1F + LnCdf<f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1F + LnCdf<f64>> Evaluator2F for LnCdfEvaluator2F<D> {
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_cdf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnSfEvaluator2F<D: Factory1F + LnCdf<f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1F + LnCdf<f64>> Evaluator2F for LnSfEvaluator2F<D> {
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_sf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct HazardEvaluator2F<D: Factory1F + Hazard> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1F + Hazard> Evaluator2F for HazardEvaluator2F<D> {
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.hazard(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct CumHazardEvaluator2F<D: Factory1F + Hazard> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1F + Hazard> Evaluator2F for CumHazardEvaluator2F<D> {
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.cum_hazard(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseCdfEvaluator2F<D: Factory1F + InverseCdf> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1F + InverseCdf> Evaluator2F for InverseCdfEvaluator2F<D> {
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(Some(f64::NAN));
+        }
+        Ok(Some(d.quantile(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseSfEvaluator2F<D: Factory1F + InverseSf> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1F + InverseSf> Evaluator2F for InverseSfEvaluator2F<D> {
+    type Dist = D;
+
+    fn make(p: f64) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(Some(f64::NAN));
+        }
+        Ok(Some(d.inverse_sf(x)))
+    }
+}
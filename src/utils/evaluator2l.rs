@@ -0,0 +1,65 @@
+use std::marker::PhantomData;
+
+use datafusion::error::DataFusionError;
+use nalgebra::DVector;
+use statrs::distribution::Continuous;
+
+use super::factory1l::Factory1L;
+
+/// Evaluator for a density over the simplex at `x` with a vector parameter. The caller
+/// checks that `x` has the same length as the parameter.
+pub trait Evaluator2L: std::fmt::Debug + Send + Sync + 'static {
+    type Dist;
+
+    fn make(p: &[f64]) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: &[f64]) -> Result<Option<f64>, DataFusionError>;
+}
+
+/// Whether `x` lies on the open simplex, which is the support of the densities evaluated
+/// here. statrs panics for points off the simplex.
+fn on_simplex(x: &[f64]) -> bool {
+    let inside = x.iter().all(|&xi| 0.0 < xi && xi < 1.0);
+    let sum: f64 = x.iter().sum();
+    inside && (sum - 1.0).abs() <= 1e-4
+}
+
+#[derive(Debug)]
+pub struct PdfEvaluator2L<D: Factory1L + for<'a> Continuous<&'a DVector<f64>, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1L + for<'a> Continuous<&'a DVector<f64>, f64>> Evaluator2L for PdfEvaluator2L<D> {
+    type Dist = D;
+
+    fn make(p: &[f64]) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: &[f64]) -> Result<Option<f64>, DataFusionError> {
+        if !on_simplex(x) {
+            return Ok(Some(0.0));
+        }
+        Ok(Some(d.pdf(&DVector::from_column_slice(x))))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnPdfEvaluator2L<D: Factory1L + for<'a> Continuous<&'a DVector<f64>, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1L + for<'a> Continuous<&'a DVector<f64>, f64>> Evaluator2L for LnPdfEvaluator2L<D> {
+    type Dist = D;
+
+    fn make(p: &[f64]) -> Result<D, DataFusionError> {
+        D::make(p)
+    }
+
+    fn eval(d: &D, x: &[f64]) -> Result<Option<f64>, DataFusionError> {
+        if !on_simplex(x) {
+            return Ok(Some(f64::NEG_INFINITY));
+        }
+        Ok(Some(d.ln_pdf(&DVector::from_column_slice(x))))
+    }
+}
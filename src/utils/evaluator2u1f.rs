@@ -4,9 +4,16 @@ use datafusion::error::DataFusionError;
 use statrs::distribution::{Discrete, DiscreteCDF};
 
 use super::factory1u1f::Factory1U1F;
+use super::inverse_cdf::discrete_quantile;
+use super::inverse_sf::discrete_inverse_sf;
+use super::ln_cdf::LnCdf;
 
 pub trait Evaluator2U1F: std::fmt::Debug + Send + Sync + 'static {
-    fn eval(x: u64, n: u64, p: f64) -> Result<Option<f64>, DataFusionError>;
+    type Dist;
+
+    fn make(n: u64, p: f64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: u64) -> Result<Option<f64>, DataFusionError>;
 }
 
 #[derive(Debug)]
@@ -15,8 +22,13 @@ pub struct PmfEvaluator2U1F<D: Factory1U1F + Discrete<u64, f64>> {
 }
 
 impl<D: Factory1U1F + Discrete<u64, f64>> Evaluator2U1F for PmfEvaluator2U1F<D> {
-    fn eval(x: u64, n: u64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(n, p)?;
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.pmf(x)))
     }
 }
@@ -27,8 +39,13 @@ pub struct LnPmfEvaluator2U1F<D: Factory1U1F + Discrete<u64, f64>> {
 }
 
 impl<D: Factory1U1F + Discrete<u64, f64>> Evaluator2U1F for LnPmfEvaluator2U1F<D> {
-    fn eval(x: u64, n: u64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(n, p)?;
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.ln_pmf(x)))
     }
 }
@@ -39,9 +56,14 @@ pub struct CdfEvaluator2U1F<D: Factory1U1F + DiscreteCDF<u64, f64>> {
 }
 
 impl<D: Factory1U1F + DiscreteCDF<u64, f64>> Evaluator2U1F for CdfEvaluator2U1F<D> {
-    fn eval(x: u64, n: u64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(n, p)?;
-        Ok(Some(DiscreteCDF::cdf(&d, x)))
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(DiscreteCDF::cdf(d, x)))
     }
 }
 
@@ -51,8 +73,95 @@ pub struct SfEvaluator2U1F<D: Factory1U1F + DiscreteCDF<u64, f64>> {
 }
 
 impl<D: Factory1U1F + DiscreteCDF<u64, f64>> Evaluator2U1F for SfEvaluator2U1F<D> {
-    fn eval(x: u64, n: u64, p: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(n, p)?;
-        Ok(Some(DiscreteCDF::sf(&d, x)))
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(DiscreteCDF::sf(d, x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnCdfEvaluator2U1F<D: Factory1U1F + LnCdf<u64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U1F + LnCdf<u64>> Evaluator2U1F for LnCdfEvaluator2U1F<D> {
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
     }
-}
\ No newline at end of file
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_cdf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnSfEvaluator2U1F<D: Factory1U1F + LnCdf<u64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U1F + LnCdf<u64>> Evaluator2U1F for LnSfEvaluator2U1F<D> {
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_sf(x)))
+    }
+}
+
+pub trait InverseEvaluator2U1F: std::fmt::Debug + Send + Sync + 'static {
+    type Dist;
+
+    fn make(n: u64, p: f64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: f64) -> Result<Option<u64>, DataFusionError>;
+}
+
+#[derive(Debug)]
+pub struct InverseCdfEvaluator2U1F<D: Factory1U1F + DiscreteCDF<u64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U1F + DiscreteCDF<u64, f64>> InverseEvaluator2U1F for InverseCdfEvaluator2U1F<D> {
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<u64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(None);
+        }
+        Ok(Some(discrete_quantile(d, x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseSfEvaluator2U1F<D: Factory1U1F + DiscreteCDF<u64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U1F + DiscreteCDF<u64, f64>> InverseEvaluator2U1F for InverseSfEvaluator2U1F<D> {
+    type Dist = D;
+
+    fn make(n: u64, p: f64) -> Result<D, DataFusionError> {
+        D::make(n, p)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<u64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(None);
+        }
+        Ok(Some(discrete_inverse_sf(d, x)))
+    }
+}
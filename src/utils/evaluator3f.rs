@@ -4,9 +4,17 @@ use datafusion::error::DataFusionError;
 use statrs::distribution::{Continuous, ContinuousCDF};
 
 use super::factory2f::Factory2F;
+use super::hazard::Hazard;
+use super::inverse_cdf::InverseCdf;
+use super::inverse_sf::InverseSf;
+use super::ln_cdf::LnCdf;
 
 pub trait Evaluator3F: std::fmt::Debug + Send + Sync + 'static {
-    fn eval(x: f64, p1: f64, p2: f64) -> Result<Option<f64>, DataFusionError>;
+    type Dist;
+
+    fn make(p1: f64, p2: f64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: f64) -> Result<Option<f64>, DataFusionError>;
 }
 
 #[derive(Debug)]
@@ -15,8 +23,13 @@ pub struct PdfEvaluator3F<D: Factory2F + Continuous<f64, f64>> {
 }
 
 impl<D: Factory2F + Continuous<f64, f64>> Evaluator3F for PdfEvaluator3F<D> {
-    fn eval(x: f64, p1: f64, p2: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p1, p2)?;
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.pdf(x)))
     }
 }
@@ -27,8 +40,13 @@ pub struct LnPdfEvaluator3F<D: Factory2F + Continuous<f64, f64>> {
 }
 
 impl<D: Factory2F + Continuous<f64, f64>> Evaluator3F for LnPdfEvaluator3F<D> {
-    fn eval(x: f64, p1: f64, p2: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p1, p2)?;
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.ln_pdf(x)))
     }
 }
@@ -39,8 +57,13 @@ pub struct CdfEvaluator3F<D: Factory2F + ContinuousCDF<f64, f64>> {
 }
 
 impl<D: Factory2F + ContinuousCDF<f64, f64>> Evaluator3F for CdfEvaluator3F<D> {
-    fn eval(x: f64, p1: f64, p2: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p1, p2)?;
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.cdf(x)))
     }
 }
@@ -51,8 +74,121 @@ pub struct SfEvaluator3F<D: Factory2F + ContinuousCDF<f64, f64>> {
 }
 
 impl<D: Factory2F + ContinuousCDF<f64, f64>> Evaluator3F for SfEvaluator3F<D> {
-    fn eval(x: f64, p1: f64, p2: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p1, p2)?;
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.sf(x)))
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug)]
+pub struct LnCdfEvaluator3F<D: Factory2F + LnCdf<f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2F + LnCdf<f64>> Evaluator3F for LnCdfEvaluator3F<D> {
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_cdf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnSfEvaluator3F<D: Factory2F + LnCdf<f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2F + LnCdf<f64>> Evaluator3F for LnSfEvaluator3F<D> {
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_sf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct HazardEvaluator3F<D: Factory2F + Hazard> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2F + Hazard> Evaluator3F for HazardEvaluator3F<D> {
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.hazard(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct CumHazardEvaluator3F<D: Factory2F + Hazard> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2F + Hazard> Evaluator3F for CumHazardEvaluator3F<D> {
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.cum_hazard(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseCdfEvaluator3F<D: Factory2F + InverseCdf> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2F + InverseCdf> Evaluator3F for InverseCdfEvaluator3F<D> {
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(Some(f64::NAN));
+        }
+        Ok(Some(d.quantile(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseSfEvaluator3F<D: Factory2F + InverseSf> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2F + InverseSf> Evaluator3F for InverseSfEvaluator3F<D> {
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(Some(f64::NAN));
+        }
+        Ok(Some(d.inverse_sf(x)))
+    }
+}
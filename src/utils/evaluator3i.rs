@@ -0,0 +1,167 @@
+use std::marker::PhantomData;
+
+use datafusion::error::DataFusionError;
+use statrs::distribution::{Discrete, DiscreteCDF};
+
+use super::factory2i::Factory2I;
+use super::inverse_cdf::discrete_quantile_i64;
+use super::inverse_sf::discrete_inverse_sf_i64;
+use super::ln_cdf::LnCdf;
+
+pub trait Evaluator3I: std::fmt::Debug + Send + Sync + 'static {
+    type Dist;
+
+    fn make(p1: i64, p2: i64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: i64) -> Result<Option<f64>, DataFusionError>;
+}
+
+#[derive(Debug)]
+pub struct PmfEvaluator3I<D: Factory2I + Discrete<i64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2I + Discrete<i64, f64>> Evaluator3I for PmfEvaluator3I<D> {
+    type Dist = D;
+
+    fn make(p1: i64, p2: i64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: i64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.pmf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnPmfEvaluator3I<D: Factory2I + Discrete<i64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2I + Discrete<i64, f64>> Evaluator3I for LnPmfEvaluator3I<D> {
+    type Dist = D;
+
+    fn make(p1: i64, p2: i64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: i64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_pmf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct CdfEvaluator3I<D: Factory2I + DiscreteCDF<i64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2I + DiscreteCDF<i64, f64>> Evaluator3I for CdfEvaluator3I<D> {
+    type Dist = D;
+
+    fn make(p1: i64, p2: i64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: i64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.cdf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct SfEvaluator3I<D: Factory2I + DiscreteCDF<i64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2I + DiscreteCDF<i64, f64>> Evaluator3I for SfEvaluator3I<D> {
+    type Dist = D;
+
+    fn make(p1: i64, p2: i64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: i64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.sf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnCdfEvaluator3I<D: Factory2I + LnCdf<i64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2I + LnCdf<i64>> Evaluator3I for LnCdfEvaluator3I<D> {
+    type Dist = D;
+
+    fn make(p1: i64, p2: i64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: i64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_cdf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnSfEvaluator3I<D: Factory2I + LnCdf<i64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2I + LnCdf<i64>> Evaluator3I for LnSfEvaluator3I<D> {
+    type Dist = D;
+
+    fn make(p1: i64, p2: i64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: i64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_sf(x)))
+    }
+}
+
+pub trait InverseEvaluator3I: std::fmt::Debug + Send + Sync + 'static {
+    type Dist;
+
+    fn make(p1: i64, p2: i64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: f64) -> Result<Option<i64>, DataFusionError>;
+}
+
+#[derive(Debug)]
+pub struct InverseCdfEvaluator3I<D: Factory2I + DiscreteCDF<i64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2I + DiscreteCDF<i64, f64>> InverseEvaluator3I for InverseCdfEvaluator3I<D> {
+    type Dist = D;
+
+    fn make(p1: i64, p2: i64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<i64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(None);
+        }
+        Ok(Some(discrete_quantile_i64(d, x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseSfEvaluator3I<D: Factory2I + DiscreteCDF<i64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2I + DiscreteCDF<i64, f64>> InverseEvaluator3I for InverseSfEvaluator3I<D> {
+    type Dist = D;
+
+    fn make(p1: i64, p2: i64) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<i64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(None);
+        }
+        Ok(Some(discrete_inverse_sf_i64(d, x)))
+    }
+}
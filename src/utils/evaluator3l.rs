@@ -0,0 +1,53 @@
+use std::marker::PhantomData;
+
+use datafusion::error::DataFusionError;
+use nalgebra::DVector;
+use statrs::distribution::Continuous;
+
+use super::factory2l::Factory2L;
+
+/// Evaluator for a multivariate density at `x` with two vector/matrix parameters.
+///
+/// Construction is split from evaluation so that the distribution (and the factorization
+/// of its matrix parameter) can be reused across rows when the parameters are literals.
+pub trait Evaluator3L: std::fmt::Debug + Send + Sync + 'static {
+    type Dist;
+
+    fn make(p1: &[f64], p2: &[f64]) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: &[f64]) -> Result<Option<f64>, DataFusionError>;
+}
+
+#[derive(Debug)]
+pub struct PdfEvaluator3L<D: Factory2L + for<'a> Continuous<&'a DVector<f64>, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2L + for<'a> Continuous<&'a DVector<f64>, f64>> Evaluator3L for PdfEvaluator3L<D> {
+    type Dist = D;
+
+    fn make(p1: &[f64], p2: &[f64]) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: &[f64]) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.pdf(&DVector::from_column_slice(x))))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnPdfEvaluator3L<D: Factory2L + for<'a> Continuous<&'a DVector<f64>, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2L + for<'a> Continuous<&'a DVector<f64>, f64>> Evaluator3L for LnPdfEvaluator3L<D> {
+    type Dist = D;
+
+    fn make(p1: &[f64], p2: &[f64]) -> Result<D, DataFusionError> {
+        D::make(p1, p2)
+    }
+
+    fn eval(d: &D, x: &[f64]) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_pdf(&DVector::from_column_slice(x))))
+    }
+}
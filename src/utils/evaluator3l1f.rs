@@ -0,0 +1,56 @@
+use std::marker::PhantomData;
+
+use datafusion::error::DataFusionError;
+use nalgebra::DVector;
+use statrs::distribution::Continuous;
+
+use super::factory2l1f::Factory2L1F;
+
+/// Evaluator for a multivariate density at `x` with two vector/matrix parameters and one
+/// scalar parameter. As with [`super::evaluator3l::Evaluator3L`], construction is split
+/// from evaluation so literal parameters are only processed once.
+pub trait Evaluator3L1F: std::fmt::Debug + Send + Sync + 'static {
+    type Dist;
+
+    fn make(p1: &[f64], p2: &[f64], p3: f64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: &[f64]) -> Result<Option<f64>, DataFusionError>;
+}
+
+#[derive(Debug)]
+pub struct PdfEvaluator3L1F<D: Factory2L1F + for<'a> Continuous<&'a DVector<f64>, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2L1F + for<'a> Continuous<&'a DVector<f64>, f64>> Evaluator3L1F
+    for PdfEvaluator3L1F<D>
+{
+    type Dist = D;
+
+    fn make(p1: &[f64], p2: &[f64], p3: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: &[f64]) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.pdf(&DVector::from_column_slice(x))))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnPdfEvaluator3L1F<D: Factory2L1F + for<'a> Continuous<&'a DVector<f64>, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2L1F + for<'a> Continuous<&'a DVector<f64>, f64>> Evaluator3L1F
+    for LnPdfEvaluator3L1F<D>
+{
+    type Dist = D;
+
+    fn make(p1: &[f64], p2: &[f64], p3: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: &[f64]) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_pdf(&DVector::from_column_slice(x))))
+    }
+}
@@ -4,9 +4,16 @@ use datafusion::error::DataFusionError;
 use statrs::distribution::{Continuous, ContinuousCDF};
 
 use super::factory3f::Factory3F;
+use super::inverse_cdf::InverseCdf;
+use super::inverse_sf::InverseSf;
+use super::ln_cdf::LnCdf;
 
 pub trait Evaluator4F: std::fmt::Debug + Send + Sync + 'static {
-    fn eval(x: f64, p1: f64, p2: f64, p3: f64) -> Result<Option<f64>, DataFusionError>;
+    type Dist;
+
+    fn make(p1: f64, p2: f64, p3: f64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: f64) -> Result<Option<f64>, DataFusionError>;
 }
 
 #[derive(Debug)]
@@ -15,8 +22,13 @@ pub struct PdfEvaluator4F<D: Factory3F + Continuous<f64, f64>> {
 }
 
 impl<D: Factory3F + Continuous<f64, f64>> Evaluator4F for PdfEvaluator4F<D> {
-    fn eval(x: f64, p1: f64, p2: f64, p3: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p1, p2, p3)?;
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64, p3: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.pdf(x)))
     }
 }
@@ -27,8 +39,13 @@ pub struct LnPdfEvaluator4F<D: Factory3F + Continuous<f64, f64>> {
 }
 
 impl<D: Factory3F + Continuous<f64, f64>> Evaluator4F for LnPdfEvaluator4F<D> {
-    fn eval(x: f64, p1: f64, p2: f64, p3: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p1, p2, p3)?;
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64, p3: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.ln_pdf(x)))
     }
 }
@@ -39,8 +56,13 @@ pub struct CdfEvaluator4F<D: Factory3F + ContinuousCDF<f64, f64>> {
 }
 
 impl<D: Factory3F + ContinuousCDF<f64, f64>> Evaluator4F for CdfEvaluator4F<D> {
-    fn eval(x: f64, p1: f64, p2: f64, p3: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p1, p2, p3)?;
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64, p3: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.cdf(x)))
     }
 }
@@ -51,8 +73,87 @@ pub struct SfEvaluator4F<D: Factory3F + ContinuousCDF<f64, f64>> {
 }
 
 impl<D: Factory3F + ContinuousCDF<f64, f64>> Evaluator4F for SfEvaluator4F<D> {
-    fn eval(x: f64, p1: f64, p2: f64, p3: f64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p1, p2, p3)?;
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64, p3: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.sf(x)))
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug)]
+pub struct LnCdfEvaluator4F<D: Factory3F + LnCdf<f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory3F + LnCdf<f64>> Evaluator4F for LnCdfEvaluator4F<D> {
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64, p3: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_cdf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnSfEvaluator4F<D: Factory3F + LnCdf<f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory3F + LnCdf<f64>> Evaluator4F for LnSfEvaluator4F<D> {
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64, p3: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_sf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseCdfEvaluator4F<D: Factory3F + InverseCdf> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory3F + InverseCdf> Evaluator4F for InverseCdfEvaluator4F<D> {
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64, p3: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(Some(f64::NAN));
+        }
+        Ok(Some(d.quantile(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseSfEvaluator4F<D: Factory3F + InverseSf> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory3F + InverseSf> Evaluator4F for InverseSfEvaluator4F<D> {
+    type Dist = D;
+
+    fn make(p1: f64, p2: f64, p3: f64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<f64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(Some(f64::NAN));
+        }
+        Ok(Some(d.inverse_sf(x)))
+    }
+}
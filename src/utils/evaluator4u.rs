@@ -4,9 +4,16 @@ use datafusion::error::DataFusionError;
 use statrs::distribution::{Discrete, DiscreteCDF};
 
 use super::factory3u::Factory3U;
+use super::inverse_cdf::discrete_quantile;
+use super::inverse_sf::discrete_inverse_sf;
+use super::ln_cdf::LnCdf;
 
 pub trait Evaluator4U: std::fmt::Debug + Send + Sync + 'static {
-    fn eval(x: u64, p1: u64, p2: u64, p3: u64) -> Result<Option<f64>, DataFusionError>;
+    type Dist;
+
+    fn make(p1: u64, p2: u64, p3: u64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: u64) -> Result<Option<f64>, DataFusionError>;
 }
 
 #[derive(Debug)]
@@ -15,8 +22,13 @@ pub struct PmfEvaluator4U<D: Factory3U + Discrete<u64, f64>> {
 }
 
 impl<D: Factory3U + Discrete<u64, f64>> Evaluator4U for PmfEvaluator4U<D> {
-    fn eval(x: u64, p1: u64, p2: u64, p3: u64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p1, p2, p3)?;
+    type Dist = D;
+
+    fn make(p1: u64, p2: u64, p3: u64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.pmf(x)))
     }
 }
@@ -27,8 +39,13 @@ pub struct LnPmfEvaluator4U<D: Factory3U + Discrete<u64, f64>> {
 }
 
 impl<D: Factory3U + Discrete<u64, f64>> Evaluator4U for LnPmfEvaluator4U<D> {
-    fn eval(x: u64, p1: u64, p2: u64, p3: u64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p1, p2, p3)?;
+    type Dist = D;
+
+    fn make(p1: u64, p2: u64, p3: u64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.ln_pmf(x)))
     }
 }
@@ -39,8 +56,13 @@ pub struct CdfEvaluator4U<D: Factory3U + DiscreteCDF<u64, f64>> {
 }
 
 impl<D: Factory3U + DiscreteCDF<u64, f64>> Evaluator4U for CdfEvaluator4U<D> {
-    fn eval(x: u64, p1: u64, p2: u64, p3: u64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p1, p2, p3)?;
+    type Dist = D;
+
+    fn make(p1: u64, p2: u64, p3: u64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.cdf(x)))
     }
 }
@@ -51,8 +73,95 @@ pub struct SfEvaluator4U<D: Factory3U + DiscreteCDF<u64, f64>> {
 }
 
 impl<D: Factory3U + DiscreteCDF<u64, f64>> Evaluator4U for SfEvaluator4U<D> {
-    fn eval(x: u64, p1: u64, p2: u64, p3: u64) -> Result<Option<f64>, DataFusionError> {
-        let d = D::make(p1, p2, p3)?;
+    type Dist = D;
+
+    fn make(p1: u64, p2: u64, p3: u64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
         Ok(Some(d.sf(x)))
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug)]
+pub struct LnCdfEvaluator4U<D: Factory3U + LnCdf<u64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory3U + LnCdf<u64>> Evaluator4U for LnCdfEvaluator4U<D> {
+    type Dist = D;
+
+    fn make(p1: u64, p2: u64, p3: u64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_cdf(x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct LnSfEvaluator4U<D: Factory3U + LnCdf<u64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory3U + LnCdf<u64>> Evaluator4U for LnSfEvaluator4U<D> {
+    type Dist = D;
+
+    fn make(p1: u64, p2: u64, p3: u64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: u64) -> Result<Option<f64>, DataFusionError> {
+        Ok(Some(d.ln_sf(x)))
+    }
+}
+
+pub trait InverseEvaluator4U: std::fmt::Debug + Send + Sync + 'static {
+    type Dist;
+
+    fn make(p1: u64, p2: u64, p3: u64) -> Result<Self::Dist, DataFusionError>;
+
+    fn eval(d: &Self::Dist, x: f64) -> Result<Option<u64>, DataFusionError>;
+}
+
+#[derive(Debug)]
+pub struct InverseCdfEvaluator4U<D: Factory3U + DiscreteCDF<u64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory3U + DiscreteCDF<u64, f64>> InverseEvaluator4U for InverseCdfEvaluator4U<D> {
+    type Dist = D;
+
+    fn make(p1: u64, p2: u64, p3: u64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<u64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(None);
+        }
+        Ok(Some(discrete_quantile(d, x)))
+    }
+}
+
+#[derive(Debug)]
+pub struct InverseSfEvaluator4U<D: Factory3U + DiscreteCDF<u64, f64>> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory3U + DiscreteCDF<u64, f64>> InverseEvaluator4U for InverseSfEvaluator4U<D> {
+    type Dist = D;
+
+    fn make(p1: u64, p2: u64, p3: u64) -> Result<D, DataFusionError> {
+        D::make(p1, p2, p3)
+    }
+
+    fn eval(d: &D, x: f64) -> Result<Option<u64>, DataFusionError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Ok(None);
+        }
+        Ok(Some(discrete_inverse_sf(d, x)))
+    }
+}
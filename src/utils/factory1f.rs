@@ -6,9 +6,7 @@ use statrs::distribution::Exp;
 use statrs::distribution::Geometric;
 use statrs::distribution::Poisson;
 
-pub trait Factory1F:
-    std::fmt::Debug + Send + Sync + Sized + 'static
-{
+pub trait Factory1F: std::fmt::Debug + Send + Sync + Sized + 'static {
     fn make(p: f64) -> Result<Self, DataFusionError>;
 }
 
@@ -46,4 +44,4 @@ impl Factory1F for Poisson {
     fn make(p: f64) -> Result<Self, DataFusionError> {
         Poisson::new(p).map_err(|e| DataFusionError::External(Box::new(e)))
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,19 @@
+use datafusion::error::DataFusionError;
+use nalgebra::Dyn;
+use statrs::distribution::{Categorical, Dirichlet};
+
+pub trait Factory1L: std::fmt::Debug + Send + Sync + Sized + 'static {
+    fn make(p: &[f64]) -> Result<Self, DataFusionError>;
+}
+
+impl Factory1L for Categorical {
+    fn make(p: &[f64]) -> Result<Self, DataFusionError> {
+        Categorical::new(p).map_err(|e| DataFusionError::External(Box::new(e)))
+    }
+}
+
+impl Factory1L for Dirichlet<Dyn> {
+    fn make(p: &[f64]) -> Result<Self, DataFusionError> {
+        Dirichlet::new(p.to_vec()).map_err(|e| DataFusionError::External(Box::new(e)))
+    }
+}
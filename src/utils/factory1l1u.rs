@@ -0,0 +1,13 @@
+use datafusion::error::DataFusionError;
+use nalgebra::Dyn;
+use statrs::distribution::Multinomial;
+
+pub trait Factory1L1U: std::fmt::Debug + Send + Sync + Sized + 'static {
+    fn make(p: &[f64], n: u64) -> Result<Self, DataFusionError>;
+}
+
+impl Factory1L1U for Multinomial<Dyn> {
+    fn make(p: &[f64], n: u64) -> Result<Self, DataFusionError> {
+        Multinomial::new(p.to_vec(), n).map_err(|e| DataFusionError::External(Box::new(e)))
+    }
+}
@@ -1,9 +1,7 @@
 use datafusion::error::DataFusionError;
 use statrs::distribution::{Binomial, Erlang};
 
-pub trait Factory1U1F:
-    std::fmt::Debug + Send + Sync + Sized + 'static
-{
+pub trait Factory1U1F: std::fmt::Debug + Send + Sync + Sized + 'static {
     fn make(p1: u64, p2: f64) -> Result<Self, DataFusionError>;
 }
 
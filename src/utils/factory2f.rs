@@ -13,9 +13,7 @@ use statrs::distribution::Pareto;
 use statrs::distribution::Uniform;
 use statrs::distribution::Weibull;
 
-pub trait Factory2F:
-    std::fmt::Debug + Send + Sync + Sized + 'static
-{
+pub trait Factory2F: std::fmt::Debug + Send + Sync + Sized + 'static {
     fn make(p1: f64, p2: f64) -> Result<Self, DataFusionError>;
 }
 
@@ -1,15 +1,21 @@
 use datafusion::error::DataFusionError;
 use statrs::distribution::DiscreteUniform;
 
-pub trait Factory2I:
-    std::fmt::Debug + Send + Sync + Sized + 'static
-{
+use super::param::invalid_params;
+
+pub trait Factory2I: std::fmt::Debug + Send + Sync + Sized + 'static {
     fn make(p1: i64, p2: i64) -> Result<Self, DataFusionError>;
 }
 
 impl Factory2I for DiscreteUniform {
     fn make(p1: i64, p2: i64) -> Result<Self, DataFusionError> {
-        DiscreteUniform::new(p1, p2).map_err(|e| DataFusionError::External(Box::new(e)))
+        let d = DiscreteUniform::new(p1, p2).map_err(|e| DataFusionError::External(Box::new(e)))?;
+        // statrs computes the number of values, b - a + 1, in i64.
+        if p2.checked_sub(p1).and_then(|n| n.checked_add(1)).is_none() {
+            return Err(invalid_params(format!(
+                "discrete uniform range [{p1}, {p2}] has more values than fit in Int64"
+            )));
+        }
+        Ok(d)
     }
 }
-
@@ -0,0 +1,27 @@
+use datafusion::error::DataFusionError;
+use nalgebra::Dyn;
+use statrs::distribution::MultivariateNormal;
+
+pub trait Factory2L: std::fmt::Debug + Send + Sync + Sized + 'static {
+    fn make(p1: &[f64], p2: &[f64]) -> Result<Self, DataFusionError>;
+}
+
+/// Check that `m` holds a flattened square matrix matching a vector of length `d`.
+pub fn check_square(d: usize, m: &[f64]) -> Result<(), DataFusionError> {
+    if m.len() != d * d {
+        return Err(DataFusionError::Execution(format!(
+            "a {d}-dimensional distribution needs a {d}x{d} matrix with {} elements, got {}",
+            d * d,
+            m.len()
+        )));
+    }
+    Ok(())
+}
+
+impl Factory2L for MultivariateNormal<Dyn> {
+    fn make(p1: &[f64], p2: &[f64]) -> Result<Self, DataFusionError> {
+        check_square(p1.len(), p2)?;
+        MultivariateNormal::new(p1.to_vec(), p2.to_vec())
+            .map_err(|e| DataFusionError::External(Box::new(e)))
+    }
+}
@@ -0,0 +1,17 @@
+use datafusion::error::DataFusionError;
+use nalgebra::Dyn;
+use statrs::distribution::MultivariateStudent;
+
+use super::factory2l::check_square;
+
+pub trait Factory2L1F: std::fmt::Debug + Send + Sync + Sized + 'static {
+    fn make(p1: &[f64], p2: &[f64], p3: f64) -> Result<Self, DataFusionError>;
+}
+
+impl Factory2L1F for MultivariateStudent<Dyn> {
+    fn make(p1: &[f64], p2: &[f64], p3: f64) -> Result<Self, DataFusionError> {
+        check_square(p1.len(), p2)?;
+        MultivariateStudent::new(p1.to_vec(), p2.to_vec(), p3)
+            .map_err(|e| DataFusionError::External(Box::new(e)))
+    }
+}
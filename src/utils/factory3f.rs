@@ -1,9 +1,7 @@
 use datafusion::error::DataFusionError;
 use statrs::distribution::{StudentsT, Triangular};
 
-pub trait Factory3F:
-    std::fmt::Debug + Send + Sync + Sized + 'static
-{
+pub trait Factory3F: std::fmt::Debug + Send + Sync + Sized + 'static {
     fn make(p1: f64, p2: f64, p3: f64) -> Result<Self, DataFusionError>;
 }
 
@@ -0,0 +1,467 @@
+use std::sync::Arc;
+
+use datafusion::{
+    arrow::{
+        array::{Array, ArrayRef, Float64Array, Int64Array, PrimitiveArray, UInt64Array},
+        datatypes::{ArrowPrimitiveType, DataType},
+    },
+    common::DFSchema,
+    error::DataFusionError,
+    logical_expr::{Expr, execution_props::ExecutionProps, simplify::SimplifyContext},
+    optimizer::simplify_expressions::ExprSimplifier,
+    scalar::ScalarValue,
+};
+use rand::rngs::StdRng;
+use statrs::distribution::{
+    Bernoulli, Beta, Binomial, Categorical, Cauchy, Chi, ChiSquared, Dirac, DiscreteUniform,
+    Erlang, Exp, FisherSnedecor, Gamma, Geometric, Gumbel, Hypergeometric, InverseGamma, Laplace,
+    LogNormal, NegativeBinomial, Normal, Pareto, Poisson, StudentsT, Triangular, Uniform, Weibull,
+};
+use statrs::distribution::{Continuous, ContinuousCDF, Discrete, DiscreteCDF};
+
+use super::coerce::{Arg, float_count};
+use super::factory1f::Factory1F;
+use super::factory1l::Factory1L;
+use super::factory1u::Factory1U;
+use super::factory1u1f::Factory1U1F;
+use super::factory2f::Factory2F;
+use super::factory2i::Factory2I;
+use super::factory3f::Factory3F;
+use super::factory3u::Factory3U;
+use super::list::float64_row;
+use super::param::named;
+use super::random::Random;
+
+/// A univariate distribution chosen by name when a query is planned, as by the table
+/// functions, rather than by the function called.
+pub trait Family: std::fmt::Debug + Send + Sync {
+    /// `n` variates drawn from `rng`.
+    fn sample(&self, rng: &mut StdRng, n: usize) -> ArrayRef;
+
+    /// The number of points of `grid`, or `None` if there are more than [`MAX_GRID_LEN`].
+    fn grid_len(&self, grid: &Grid) -> Option<u64>;
+
+    /// Points `start..start + len` of `grid`, as the columns x, density (PDF or PMF), CDF
+    /// and SF.
+    fn grid(&self, grid: &Grid, start: u64, len: usize) -> Vec<ArrayRef>;
+}
+
+impl<D: Random + Tabulate> Family for D {
+    fn sample(&self, rng: &mut StdRng, n: usize) -> ArrayRef {
+        Arc::new(PrimitiveArray::<D::Value>::from_iter_values(
+            (0..n).map(|_| self.random(rng)),
+        ))
+    }
+
+    fn grid_len(&self, grid: &Grid) -> Option<u64> {
+        Tabulate::grid_len(self, grid)
+    }
+
+    fn grid(&self, grid: &Grid, start: u64, len: usize) -> Vec<ArrayRef> {
+        Tabulate::grid(self, grid, start, len)
+    }
+}
+
+/// The most points a grid may have.
+pub const MAX_GRID_LEN: u64 = 100_000_000;
+
+/// The points from `from` to `to` at which a distribution is tabulated: `steps + 1`
+/// evenly spaced points for a continuous distribution, and every value in between for a
+/// discrete one.
+#[derive(Debug, Clone, Copy)]
+pub struct Grid {
+    pub from: f64,
+    pub to: f64,
+    pub steps: u64,
+}
+
+impl Grid {
+    fn point(&self, i: u64) -> f64 {
+        if i == self.steps {
+            self.to
+        } else {
+            self.from + (self.to - self.from) * (i as f64 / self.steps as f64)
+        }
+    }
+
+    /// The number of evenly spaced points, if not more than [`MAX_GRID_LEN`].
+    fn steps_len(&self) -> Option<u64> {
+        self.steps.checked_add(1).filter(|&len| len <= MAX_GRID_LEN)
+    }
+
+    /// The first whole number from `from` that is at least `min`, the smallest value of
+    /// the type of the distribution. The bounds are converted to `i128` with saturation, so
+    /// that a range far beyond the type cannot overflow, and every value of the type fits.
+    fn first_whole(&self, min: i128) -> i128 {
+        (self.from.ceil() as i128).max(min)
+    }
+
+    /// The number of whole numbers from `from` to `to` within `[min, max]`, the range of
+    /// the type of the distribution, if not more than [`MAX_GRID_LEN`].
+    fn whole_len(&self, min: i128, max: i128) -> Option<u64> {
+        let last = (self.to.floor() as i128).min(max);
+        let len = (last - self.first_whole(min) + 1).max(0);
+        (len <= MAX_GRID_LEN as i128).then_some(len as u64)
+    }
+}
+
+pub trait Tabulate {
+    fn grid_len(&self, grid: &Grid) -> Option<u64>;
+    fn grid(&self, grid: &Grid, start: u64, len: usize) -> Vec<ArrayRef>;
+}
+
+macro_rules! impl_tabulate {
+    (continuous: $($dist:ty),+) => {
+        $(
+            impl Tabulate for $dist {
+                fn grid_len(&self, grid: &Grid) -> Option<u64> {
+                    grid.steps_len()
+                }
+
+                fn grid(&self, grid: &Grid, start: u64, len: usize) -> Vec<ArrayRef> {
+                    let xs = (start..start + len as u64).map(|i| grid.point(i)).collect::<Vec<_>>();
+                    tabulate(
+                        Float64Array::from_iter_values(xs.iter().copied()),
+                        Float64Array::from_iter_values(xs.iter().map(|&x| self.pdf(x))),
+                        xs.iter().map(|&x| ContinuousCDF::cdf(self, x)),
+                        xs.iter().map(|&x| ContinuousCDF::sf(self, x)),
+                    )
+                }
+            }
+        )+
+    };
+    (count: $($dist:ty),+) => {
+        $(
+            impl Tabulate for $dist {
+                fn grid_len(&self, grid: &Grid) -> Option<u64> {
+                    grid.whole_len(0, u64::MAX.into())
+                }
+
+                fn grid(&self, grid: &Grid, start: u64, len: usize) -> Vec<ArrayRef> {
+                    let first = grid.first_whole(0) as u64;
+                    let xs = (start..start + len as u64).map(|i| first + i);
+                    tabulate(
+                        UInt64Array::from_iter_values(xs.clone()),
+                        Float64Array::from_iter_values(xs.clone().map(|x| self.pmf(x))),
+                        xs.clone().map(|x| DiscreteCDF::cdf(self, x)),
+                        xs.map(|x| DiscreteCDF::sf(self, x)),
+                    )
+                }
+            }
+        )+
+    };
+}
+
+impl_tabulate!(
+    continuous: Beta, Cauchy, Chi, ChiSquared, Erlang, Exp, FisherSnedecor, Gamma, Gumbel,
+    InverseGamma, Laplace, LogNormal, Normal, Pareto, StudentsT, Triangular, Uniform, Weibull
+);
+impl_tabulate!(
+    count: Bernoulli, Binomial, Categorical, Geometric, Hypergeometric, NegativeBinomial, Poisson
+);
+
+impl Tabulate for Dirac {
+    fn grid_len(&self, grid: &Grid) -> Option<u64> {
+        grid.steps_len()
+    }
+
+    /// statrs has no PDF for the Dirac distribution, so the density is NULL.
+    fn grid(&self, grid: &Grid, start: u64, len: usize) -> Vec<ArrayRef> {
+        let xs = (start..start + len as u64)
+            .map(|i| grid.point(i))
+            .collect::<Vec<_>>();
+        tabulate(
+            Float64Array::from_iter_values(xs.iter().copied()),
+            Float64Array::new_null(len),
+            xs.iter().map(|&x| ContinuousCDF::cdf(self, x)),
+            xs.iter().map(|&x| ContinuousCDF::sf(self, x)),
+        )
+    }
+}
+
+impl Tabulate for DiscreteUniform {
+    fn grid_len(&self, grid: &Grid) -> Option<u64> {
+        grid.whole_len(i64::MIN.into(), i64::MAX.into())
+    }
+
+    fn grid(&self, grid: &Grid, start: u64, len: usize) -> Vec<ArrayRef> {
+        let first = grid.first_whole(i64::MIN.into()) as i64;
+        let xs = (start..start + len as u64).map(|i| first + i as i64);
+        tabulate(
+            Int64Array::from_iter_values(xs.clone()),
+            Float64Array::from_iter_values(xs.clone().map(|x| self.pmf(x))),
+            xs.clone().map(|x| DiscreteCDF::cdf(self, x)),
+            xs.map(|x| DiscreteCDF::sf(self, x)),
+        )
+    }
+}
+
+fn tabulate(
+    xs: impl Array + 'static,
+    density: Float64Array,
+    cdf: impl Iterator<Item = f64>,
+    sf: impl Iterator<Item = f64>,
+) -> Vec<ArrayRef> {
+    vec![
+        Arc::new(xs),
+        Arc::new(density),
+        Arc::new(Float64Array::from_iter_values(cdf)),
+        Arc::new(Float64Array::from_iter_values(sf)),
+    ]
+}
+
+type Make = fn(&[ScalarValue]) -> Result<Arc<dyn Family>, DataFusionError>;
+
+/// A distribution that can be chosen by name, with the kinds of its parameters in the
+/// order its functions take them.
+pub struct FamilySpec {
+    pub name: &'static str,
+    pub params: &'static [Arg],
+    value_type: fn() -> DataType,
+    make: Make,
+}
+
+macro_rules! family {
+    ($name:literal, $dist:ty, $make:ident, [$($param:ident),+]) => {
+        FamilySpec {
+            name: $name,
+            params: &[$(Arg::$param),+],
+            value_type: value_type::<$dist>,
+            make: $make::<$dist>,
+        }
+    };
+}
+
+const FAMILIES: &[FamilySpec] = &[
+    family!("bernoulli", Bernoulli, make_1f, [Float]),
+    family!("beta", Beta, make_2f, [Float, Float]),
+    family!("binomial", Binomial, make_1u1f, [Count, Float]),
+    family!("categorical", Categorical, make_1l, [FloatList]),
+    family!("cauchy", Cauchy, make_2f, [Float, Float]),
+    family!("chi", Chi, make_1u, [Count]),
+    family!("chi_squared", ChiSquared, make_1f, [Float]),
+    family!("dirac", Dirac, make_1f, [Float]),
+    family!(
+        "discrete_uniform",
+        DiscreteUniform,
+        make_2i,
+        [Integer, Integer]
+    ),
+    family!("erlang", Erlang, make_1u1f, [Count, Float]),
+    family!("exp", Exp, make_1f, [Float]),
+    family!("fisher_snedecor", FisherSnedecor, make_2f, [Float, Float]),
+    family!("gamma", Gamma, make_2f, [Float, Float]),
+    family!("geometric", Geometric, make_1f, [Float]),
+    family!("gumbel", Gumbel, make_2f, [Float, Float]),
+    family!(
+        "hypergeometric",
+        Hypergeometric,
+        make_3u,
+        [Count, Count, Count]
+    ),
+    family!("inverse_gamma", InverseGamma, make_2f, [Float, Float]),
+    family!("laplace", Laplace, make_2f, [Float, Float]),
+    family!("log_normal", LogNormal, make_2f, [Float, Float]),
+    family!(
+        "negative_binomial",
+        NegativeBinomial,
+        make_2f,
+        [Float, Float]
+    ),
+    family!("normal", Normal, make_2f, [Float, Float]),
+    family!("pareto", Pareto, make_2f, [Float, Float]),
+    family!("poisson", Poisson, make_1f, [Float]),
+    family!("students_t", StudentsT, make_3f, [Float, Float, Float]),
+    family!("triangular", Triangular, make_3f, [Float, Float, Float]),
+    family!("uniform", Uniform, make_2f, [Float, Float]),
+    family!("weibull", Weibull, make_2f, [Float, Float]),
+];
+
+fn value_type<D: Random>() -> DataType {
+    D::Value::DATA_TYPE
+}
+
+impl FamilySpec {
+    /// The distribution named by the first argument of the function `fname`, a string such
+    /// as `'normal'` naming it like its module.
+    pub fn find(fname: &str, name: &ScalarValue) -> Result<&'static FamilySpec, DataFusionError> {
+        let name = match name {
+            ScalarValue::Utf8(Some(s))
+            | ScalarValue::LargeUtf8(Some(s))
+            | ScalarValue::Utf8View(Some(s)) => s.to_ascii_lowercase(),
+            _ => {
+                return Err(DataFusionError::Plan(format!(
+                    "{fname}: argument 1 must be the name of a distribution, got {name}"
+                )));
+            }
+        };
+        FAMILIES
+            .iter()
+            .find(|f| f.name == name)
+            .ok_or_else(|| DataFusionError::Plan(format!("{fname}: unknown distribution '{name}'")))
+    }
+
+    /// Whether the distribution is continuous, with a PDF rather than a PMF.
+    pub fn is_continuous(&self) -> bool {
+        self.value_type() == DataType::Float64
+    }
+
+    /// The type of the values of the distribution.
+    pub fn value_type(&self) -> DataType {
+        (self.value_type)()
+    }
+
+    /// The distribution with the parameters `args` of the function `fname`, which start at
+    /// argument `first`, or `None` if any is null.
+    pub fn make(
+        &self,
+        fname: &str,
+        first: usize,
+        args: &[ScalarValue],
+    ) -> Result<Option<Arc<dyn Family>>, DataFusionError> {
+        if args.len() != self.params.len() {
+            return Err(DataFusionError::Plan(format!(
+                "{fname}: {} expects {} parameters, got {}",
+                self.name,
+                self.params.len(),
+                args.len()
+            )));
+        }
+        let mut params = Vec::with_capacity(args.len());
+        for (i, (value, kind)) in args.iter().zip(self.params).enumerate() {
+            match param(fname, first + i + 1, value, *kind)? {
+                Some(value) => params.push(value),
+                None => return Ok(None),
+            }
+        }
+        (self.make)(&params).map(Some).map_err(|e| named(fname, e))
+    }
+}
+
+/// `value` as the type that parameters of `kind` are passed as, or `None` if it is null.
+/// `position` counts from one.
+pub fn param(
+    fname: &str,
+    position: usize,
+    value: &ScalarValue,
+    kind: Arg,
+) -> Result<Option<ScalarValue>, DataFusionError> {
+    let data_type = value.data_type();
+    let target = kind.coerce(&data_type).ok_or_else(|| {
+        DataFusionError::Plan(format!(
+            "{fname}: argument {position} must be {kind}, got {data_type}"
+        ))
+    })?;
+    let value = value.cast_to(&target)?;
+    if value.is_null() {
+        return Ok(None);
+    }
+    match value {
+        ScalarValue::Int64(Some(v)) if kind == Arg::Count => {
+            if v < 0 {
+                return Err(DataFusionError::Plan(format!(
+                    "{fname}: argument {position} must not be negative, got {v}"
+                )));
+            }
+            Ok(Some(ScalarValue::UInt64(Some(v as u64))))
+        }
+        ScalarValue::Float64(Some(v)) if kind == Arg::Count => match float_count(v) {
+            Some(v) => Ok(Some(ScalarValue::UInt64(Some(v)))),
+            None => Err(DataFusionError::Plan(format!(
+                "{fname}: argument {position} must be a non-negative integer, got {v}"
+            ))),
+        },
+        value => Ok(Some(value)),
+    }
+}
+
+/// The constant value of argument `position` of the function `fname`, after folding
+/// expressions such as casts and list constructors.
+pub fn literal(fname: &str, position: usize, expr: &Expr) -> Result<ScalarValue, DataFusionError> {
+    let props = ExecutionProps::new();
+    let schema = Arc::new(DFSchema::empty());
+    let simplifier = ExprSimplifier::new(SimplifyContext::new(&props).with_schema(schema.clone()));
+    match simplifier.simplify(simplifier.coerce(expr.clone(), &schema)?)? {
+        Expr::Literal(value, _) => Ok(value),
+        _ => Err(DataFusionError::Plan(format!(
+            "{fname}: argument {position} must be a constant, got {expr}"
+        ))),
+    }
+}
+
+fn float(value: &ScalarValue) -> Result<f64, DataFusionError> {
+    match value {
+        ScalarValue::Float64(Some(v)) => Ok(*v),
+        _ => Err(DataFusionError::Internal(format!(
+            "expected a Float64 parameter, got {value}"
+        ))),
+    }
+}
+
+fn count(value: &ScalarValue) -> Result<u64, DataFusionError> {
+    match value {
+        ScalarValue::UInt64(Some(v)) => Ok(*v),
+        _ => Err(DataFusionError::Internal(format!(
+            "expected a UInt64 parameter, got {value}"
+        ))),
+    }
+}
+
+fn integer(value: &ScalarValue) -> Result<i64, DataFusionError> {
+    match value {
+        ScalarValue::Int64(Some(v)) => Ok(*v),
+        _ => Err(DataFusionError::Internal(format!(
+            "expected an Int64 parameter, got {value}"
+        ))),
+    }
+}
+
+fn float_list(value: &ScalarValue) -> Result<Option<Vec<f64>>, DataFusionError> {
+    let array = value.to_array()?;
+    Ok(float64_row(array.as_ref(), 0)?.map(|values| values.values().to_vec()))
+}
+
+fn make_1f<D: Factory1F + Family>(p: &[ScalarValue]) -> Result<Arc<dyn Family>, DataFusionError> {
+    Ok(Arc::new(D::make(float(&p[0])?)?))
+}
+
+fn make_1l<D: Factory1L + Family>(p: &[ScalarValue]) -> Result<Arc<dyn Family>, DataFusionError> {
+    let values = float_list(&p[0])?.ok_or_else(|| {
+        DataFusionError::Plan("the probabilities must not contain NULL".to_string())
+    })?;
+    Ok(Arc::new(D::make(&values)?))
+}
+
+fn make_1u<D: Factory1U + Family>(p: &[ScalarValue]) -> Result<Arc<dyn Family>, DataFusionError> {
+    Ok(Arc::new(D::make(count(&p[0])?)?))
+}
+
+fn make_1u1f<D: Factory1U1F + Family>(
+    p: &[ScalarValue],
+) -> Result<Arc<dyn Family>, DataFusionError> {
+    Ok(Arc::new(D::make(count(&p[0])?, float(&p[1])?)?))
+}
+
+fn make_2f<D: Factory2F + Family>(p: &[ScalarValue]) -> Result<Arc<dyn Family>, DataFusionError> {
+    Ok(Arc::new(D::make(float(&p[0])?, float(&p[1])?)?))
+}
+
+fn make_2i<D: Factory2I + Family>(p: &[ScalarValue]) -> Result<Arc<dyn Family>, DataFusionError> {
+    Ok(Arc::new(D::make(integer(&p[0])?, integer(&p[1])?)?))
+}
+
+fn make_3f<D: Factory3F + Family>(p: &[ScalarValue]) -> Result<Arc<dyn Family>, DataFusionError> {
+    Ok(Arc::new(D::make(
+        float(&p[0])?,
+        float(&p[1])?,
+        float(&p[2])?,
+    )?))
+}
+
+fn make_3u<D: Factory3U + Family>(p: &[ScalarValue]) -> Result<Arc<dyn Family>, DataFusionError> {
+    Ok(Arc::new(D::make(
+        count(&p[0])?,
+        count(&p[1])?,
+        count(&p[2])?,
+    )?))
+}
@@ -0,0 +1,1080 @@
+use std::{any::Any, collections::BTreeMap, marker::PhantomData, mem::size_of, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{Array, ArrayRef, AsArray, ListArray},
+        compute::sum,
+        datatypes::{DataType, Field, FieldRef, Fields, Float64Type, UInt64Type},
+    },
+    common::{
+        cast::{as_float64_array, as_uint64_array},
+        scalar::ScalarStructBuilder,
+    },
+    logical_expr::{
+        Accumulator, AggregateUDFImpl, ColumnarValue, Signature, Volatility,
+        function::{AccumulatorArgs, StateFieldsArgs},
+        utils::format_state_name,
+    },
+    scalar::ScalarValue,
+};
+use statrs::consts::LN_SQRT_2PI;
+use statrs::distribution::{
+    Beta, Binomial, Continuous, Discrete, Exp, Gamma, Geometric, LogNormal, NegativeBinomial,
+    Normal, Poisson, Weibull,
+};
+use statrs::function::beta::ln_beta;
+use statrs::function::factorial::ln_factorial;
+use statrs::function::gamma::{digamma, ln_gamma};
+
+use super::coerce::{Arg, coerce_args, count_arg};
+
+/// Maximum likelihood fitting of a continuous distribution to a sample.
+pub trait Fit: std::fmt::Debug + Send + Sync + Sized + 'static {
+    /// The names of the parameters, in the order the other functions of the distribution
+    /// take them.
+    const PARAMS: &'static [&'static str];
+
+    /// What the aggregate keeps of the sample to fit it.
+    type State: FitState + Push<f64>;
+
+    /// The estimates of the parameters, in order, and the log-likelihood of the sample, or
+    /// `None` if the sample has no maximum likelihood estimate, such as when a value is
+    /// outside the support or there are too few distinct values.
+    fn fit(state: &Self::State) -> Option<(Vec<f64>, f64)>;
+}
+
+/// What a fit aggregate keeps of the rows it has seen, which it exchanges between
+/// partitions as its state.
+pub trait FitState: std::fmt::Debug + Send + Sync + 'static {
+    fn new() -> Self;
+
+    /// The fields of the state of the aggregate `name`.
+    fn state_fields(name: &str) -> Vec<Field>;
+
+    /// Add the state of another accumulator.
+    fn merge(&mut self, states: &[ArrayRef]) -> datafusion::error::Result<()>;
+
+    fn state(&self) -> Vec<ScalarValue>;
+
+    /// The number of rows seen.
+    fn len(&self) -> u64;
+
+    /// The size of the heap allocations, for [`Accumulator::size`].
+    fn size(&self) -> usize;
+}
+
+/// Adding a row of type `T` to a [`FitState`].
+pub trait Push<T> {
+    fn push(&mut self, row: T);
+}
+
+/// A family fitted from sums of functions of the values, which are all the fit needs of
+/// the sample.
+pub trait SumFit: std::fmt::Debug + Send + Sync + 'static {
+    /// The names of the sums.
+    const SUMS: &'static [&'static str];
+
+    /// Add the terms of `x` to `sums`. A value outside the support adds NaN, so that the
+    /// sums reject the sample however they are merged.
+    fn add(sums: &mut [f64], x: f64);
+}
+
+/// The number of values and the sums of [`SumFit::SUMS`] over them.
+#[derive(Debug)]
+pub struct Sums<D: SumFit> {
+    n: u64,
+    sums: Vec<f64>,
+    _phantom: PhantomData<D>,
+}
+
+impl<D: SumFit> Sums<D> {
+    /// The sums divided by the number of values, or `None` if a value was outside the
+    /// support.
+    fn means(&self) -> Option<Vec<f64>> {
+        let n = self.n as f64;
+        self.sums
+            .iter()
+            .map(|&s| (!s.is_nan()).then_some(s / n))
+            .collect()
+    }
+}
+
+impl<D: SumFit> FitState for Sums<D> {
+    fn new() -> Self {
+        Sums {
+            n: 0,
+            sums: vec![0.0; D::SUMS.len()],
+            _phantom: PhantomData,
+        }
+    }
+
+    fn state_fields(name: &str) -> Vec<Field> {
+        std::iter::once(Field::new(
+            format_state_name(name, "n"),
+            DataType::UInt64,
+            true,
+        ))
+        .chain(D::SUMS.iter().map(|sum| {
+            Field::new(
+                format_state_name(name, &format!("sum_{sum}")),
+                DataType::Float64,
+                true,
+            )
+        }))
+        .collect()
+    }
+
+    fn merge(&mut self, states: &[ArrayRef]) -> datafusion::error::Result<()> {
+        self.n += sum(as_uint64_array(&states[0])?).unwrap_or(0);
+        for (total, state) in self.sums.iter_mut().zip(&states[1..]) {
+            *total += as_float64_array(state)?.iter().flatten().sum::<f64>();
+        }
+        Ok(())
+    }
+
+    fn state(&self) -> Vec<ScalarValue> {
+        std::iter::once(ScalarValue::UInt64(Some(self.n)))
+            .chain(self.sums.iter().map(|&s| ScalarValue::Float64(Some(s))))
+            .collect()
+    }
+
+    fn len(&self) -> u64 {
+        self.n
+    }
+
+    fn size(&self) -> usize {
+        self.sums.capacity() * size_of::<f64>()
+    }
+}
+
+impl<D: SumFit> Push<f64> for Sums<D> {
+    fn push(&mut self, x: f64) {
+        self.n += 1;
+        D::add(&mut self.sums, x);
+    }
+}
+
+/// The first column of a row of counts, for the count families fitted from sums.
+impl<D: SumFit> Push<&[u64]> for Sums<D> {
+    fn push(&mut self, row: &[u64]) {
+        self.push(row[0] as f64);
+    }
+}
+
+/// The whole sample, for a family whose fit needs more than sums of the values.
+#[derive(Debug)]
+pub struct Values(Vec<f64>);
+
+impl FitState for Values {
+    fn new() -> Self {
+        Values(Vec::new())
+    }
+
+    fn state_fields(name: &str) -> Vec<Field> {
+        vec![Field::new(
+            format_state_name(name, "sample"),
+            DataType::new_list(DataType::Float64, true),
+            true,
+        )]
+    }
+
+    fn merge(&mut self, states: &[ArrayRef]) -> datafusion::error::Result<()> {
+        for sample in states[0].as_list::<i32>().iter().flatten() {
+            self.0.extend(as_float64_array(&sample)?.iter().flatten());
+        }
+        Ok(())
+    }
+
+    fn state(&self) -> Vec<ScalarValue> {
+        let list = ListArray::from_iter_primitive::<Float64Type, _, _>(vec![Some(
+            self.0.iter().copied().map(Some),
+        )]);
+        vec![ScalarValue::List(Arc::new(list))]
+    }
+
+    fn len(&self) -> u64 {
+        self.0.len() as u64
+    }
+
+    fn size(&self) -> usize {
+        self.0.capacity() * size_of::<f64>()
+    }
+}
+
+impl Push<f64> for Values {
+    fn push(&mut self, x: f64) {
+        self.0.push(x);
+    }
+}
+
+/// The number of times each row of counts occurs, for the count families whose fit needs
+/// more than sums of the counts.
+#[derive(Debug)]
+pub struct Histogram<D: CountFit> {
+    n: u64,
+    rows: BTreeMap<Vec<u64>, u64>,
+    _phantom: PhantomData<D>,
+}
+
+impl<D: CountFit> Histogram<D> {
+    fn add(&mut self, row: &[u64], count: u64) {
+        self.n += count;
+        match self.rows.get_mut(row) {
+            Some(c) => *c += count,
+            None => {
+                self.rows.insert(row.to_vec(), count);
+            }
+        }
+    }
+}
+
+impl<D: CountFit> FitState for Histogram<D> {
+    fn new() -> Self {
+        Histogram {
+            n: 0,
+            rows: BTreeMap::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// A list of the distinct values of each argument, row by row, and a list of the number
+    /// of times each row occurs.
+    fn state_fields(name: &str) -> Vec<Field> {
+        D::ARGS
+            .iter()
+            .chain(&["count"])
+            .map(|arg| {
+                Field::new(
+                    format_state_name(name, arg),
+                    DataType::new_list(DataType::UInt64, true),
+                    true,
+                )
+            })
+            .collect()
+    }
+
+    fn merge(&mut self, states: &[ArrayRef]) -> datafusion::error::Result<()> {
+        let lists = states
+            .iter()
+            .map(|s| s.as_list::<i32>())
+            .collect::<Vec<_>>();
+        let mut row = vec![0; D::ARGS.len()];
+        for i in 0..states[0].len() {
+            let columns = lists
+                .iter()
+                .map(|l| as_uint64_array(&l.value(i)).cloned())
+                .collect::<datafusion::error::Result<Vec<_>>>()?;
+            let (counts, columns) = columns.split_last().unwrap();
+            for (j, count) in counts.values().iter().enumerate() {
+                for (v, c) in row.iter_mut().zip(columns) {
+                    *v = c.value(j);
+                }
+                self.add(&row, *count);
+            }
+        }
+        Ok(())
+    }
+
+    fn state(&self) -> Vec<ScalarValue> {
+        let column = |values: Vec<u64>| {
+            let list = ListArray::from_iter_primitive::<UInt64Type, _, _>(vec![Some(
+                values.into_iter().map(Some),
+            )]);
+            ScalarValue::List(Arc::new(list))
+        };
+        (0..D::ARGS.len())
+            .map(|j| column(self.rows.keys().map(|row| row[j]).collect()))
+            .chain([column(self.rows.values().copied().collect())])
+            .collect()
+    }
+
+    fn len(&self) -> u64 {
+        self.n
+    }
+
+    fn size(&self) -> usize {
+        self.rows.len() * (size_of::<Vec<u64>>() + (D::ARGS.len() + 1) * size_of::<u64>())
+    }
+}
+
+impl<D: CountFit> Push<&[u64]> for Histogram<D> {
+    fn push(&mut self, row: &[u64]) {
+        self.add(row, 1);
+    }
+}
+
+const TOLERANCE: f64 = 1e-12;
+
+const MAX_ITER: usize = 100;
+
+fn mean(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, n) = values.fold((0.0, 0usize), |(sum, n), v| (sum + v, n + 1));
+    sum / n as f64
+}
+
+/// The mean and the biased (maximum likelihood) variance.
+fn mean_variance(values: &[f64]) -> (f64, f64) {
+    let m = mean(values.iter().copied());
+    (m, mean(values.iter().map(|v| (v - m) * (v - m))))
+}
+
+/// The natural logarithms of a sample of positive values, `None` if any is not positive.
+fn logs(sample: &[f64]) -> Option<Vec<f64>> {
+    sample.iter().map(|&x| (x > 0.0).then(|| x.ln())).collect()
+}
+
+/// The natural logarithm of a positive `x`, or NaN to mark a value outside the support.
+fn ln_positive(x: f64) -> f64 {
+    if x > 0.0 { x.ln() } else { f64::NAN }
+}
+
+/// The mean and the biased (maximum likelihood) variance from the means of the values and
+/// of their squares, `None` unless the variance is positive.
+fn moments_variance(m: f64, m2: f64) -> Option<(f64, f64)> {
+    let v = m2 - m * m;
+    (v > 0.0).then_some((m, v))
+}
+
+/// The derivative of [`digamma`], by recurrence up to 6 and then its asymptotic series.
+fn trigamma(mut x: f64) -> f64 {
+    let mut result = 0.0;
+    while x < 6.0 {
+        result += 1.0 / (x * x);
+        x += 1.0;
+    }
+    let f = 1.0 / (x * x);
+    result
+        + 1.0 / x
+        + f / 2.0
+        + f / x * (1.0 / 6.0 - f * (1.0 / 30.0 - f * (1.0 / 42.0 - f / 30.0)))
+}
+
+/// Newton's method for the positive root of `f`, whose value and derivative `step` gives,
+/// halving the estimate instead of stepping to a value that is not positive.
+fn newton_positive(mut x: f64, step: impl Fn(f64) -> (f64, f64)) -> Option<f64> {
+    for _ in 0..MAX_ITER {
+        let (f, df) = step(x);
+        let next = x - f / df;
+        let next = if next > 0.0 { next } else { x / 2.0 };
+        if !next.is_finite() {
+            return None;
+        }
+        if (next - x).abs() <= TOLERANCE * x {
+            return Some(next);
+        }
+        x = next;
+    }
+    Some(x)
+}
+
+/// Newton's method for the positive root of a decreasing `f`, whose value and derivative
+/// `step` gives. The root is first bracketed by doubling or halving `x` until `f` changes
+/// sign, and a step leaving the bracket bisects it instead. `None` if no sign change is
+/// found or `f` is not finite.
+fn newton_bracketed(mut x: f64, step: impl Fn(f64) -> (f64, f64)) -> Option<f64> {
+    let (mut f, mut df) = step(x);
+    let (mut lo, mut hi) = (x, x);
+    for _ in 0..MAX_ITER {
+        if !f.is_finite() {
+            return None;
+        }
+        if f > 0.0 {
+            lo = x;
+            if hi > x {
+                break;
+            }
+            x *= 2.0;
+        } else if f < 0.0 {
+            hi = x;
+            if lo < x {
+                break;
+            }
+            x /= 2.0;
+        } else {
+            return Some(x);
+        }
+        (f, df) = step(x);
+    }
+    if lo >= hi {
+        return None;
+    }
+    for _ in 0..MAX_ITER {
+        let next = x - f / df;
+        let next = if next > lo && next < hi {
+            next
+        } else {
+            (lo + hi) / 2.0
+        };
+        if (next - x).abs() <= TOLERANCE * next || hi - lo <= TOLERANCE * next {
+            return Some(next);
+        }
+        x = next;
+        (f, df) = step(x);
+        if !f.is_finite() {
+            return None;
+        }
+        if f > 0.0 {
+            lo = x;
+        } else if f < 0.0 {
+            hi = x;
+        } else {
+            return Some(x);
+        }
+    }
+    Some(x)
+}
+
+impl SumFit for Normal {
+    const SUMS: &'static [&'static str] = &["x", "x2"];
+
+    fn add(sums: &mut [f64], x: f64) {
+        sums[0] += x;
+        sums[1] += x * x;
+    }
+}
+
+impl Fit for Normal {
+    const PARAMS: &'static [&'static str] = &["mean", "std_dev"];
+
+    type State = Sums<Self>;
+
+    fn fit(state: &Sums<Self>) -> Option<(Vec<f64>, f64)> {
+        let means = state.means()?;
+        let (m, v) = moments_variance(means[0], means[1])?;
+        let s = v.sqrt();
+        Normal::new(m, s).ok()?;
+        // At the estimates, the squared deviations sum to n times the variance.
+        let ln_likelihood = -(state.n as f64) * (s.ln() + LN_SQRT_2PI + 0.5);
+        Some((vec![m, s], ln_likelihood))
+    }
+}
+
+impl SumFit for LogNormal {
+    const SUMS: &'static [&'static str] = &["ln_x", "ln_x2"];
+
+    fn add(sums: &mut [f64], x: f64) {
+        let ln_x = ln_positive(x);
+        sums[0] += ln_x;
+        sums[1] += ln_x * ln_x;
+    }
+}
+
+impl Fit for LogNormal {
+    const PARAMS: &'static [&'static str] = &["location", "scale"];
+
+    type State = Sums<Self>;
+
+    /// The normal fit of `ln x`, whose density is the log-normal one times `x`.
+    fn fit(state: &Sums<Self>) -> Option<(Vec<f64>, f64)> {
+        let means = state.means()?;
+        let (m, v) = moments_variance(means[0], means[1])?;
+        let s = v.sqrt();
+        LogNormal::new(m, s).ok()?;
+        let n = state.n as f64;
+        let ln_likelihood = -n * (s.ln() + LN_SQRT_2PI + 0.5) - state.sums[0];
+        Some((vec![m, s], ln_likelihood))
+    }
+}
+
+impl SumFit for Exp {
+    const SUMS: &'static [&'static str] = &["x"];
+
+    fn add(sums: &mut [f64], x: f64) {
+        sums[0] += if x >= 0.0 { x } else { f64::NAN };
+    }
+}
+
+impl Fit for Exp {
+    const PARAMS: &'static [&'static str] = &["rate"];
+
+    type State = Sums<Self>;
+
+    fn fit(state: &Sums<Self>) -> Option<(Vec<f64>, f64)> {
+        let rate = 1.0 / state.means()?[0];
+        if !rate.is_finite() {
+            return None;
+        }
+        Exp::new(rate).ok()?;
+        let ln_likelihood = state.n as f64 * (rate.ln() - 1.0);
+        Some((vec![rate], ln_likelihood))
+    }
+}
+
+impl SumFit for Gamma {
+    const SUMS: &'static [&'static str] = &["x", "ln_x"];
+
+    fn add(sums: &mut [f64], x: f64) {
+        sums[0] += x;
+        sums[1] += ln_positive(x);
+    }
+}
+
+impl Fit for Gamma {
+    const PARAMS: &'static [&'static str] = &["shape", "rate"];
+
+    type State = Sums<Self>;
+
+    /// The shape solves `ln α - ψ(α) = ln(mean) - mean(ln x)`, from the approximation of
+    /// Minka (2002), and the rate is then `α / mean`.
+    fn fit(state: &Sums<Self>) -> Option<(Vec<f64>, f64)> {
+        let means = state.means()?;
+        let (m, ln_mean) = (means[0], means[1]);
+        let s = m.ln() - ln_mean;
+        if s.is_nan() || s <= 0.0 {
+            return None;
+        }
+        let start = (3.0 - s + ((s - 3.0) * (s - 3.0) + 24.0 * s).sqrt()) / (12.0 * s);
+        let shape = newton_positive(start, |a| (a.ln() - digamma(a) - s, 1.0 / a - trigamma(a)))?;
+        let rate = shape / m;
+        Gamma::new(shape, rate).ok()?;
+        // At the estimates, the rate times the sum of the values is n times the shape.
+        let n = state.n as f64;
+        let ln_likelihood =
+            n * (shape * rate.ln() - ln_gamma(shape) - shape) + (shape - 1.0) * state.sums[1];
+        Some((vec![shape, rate], ln_likelihood))
+    }
+}
+
+impl Fit for Weibull {
+    const PARAMS: &'static [&'static str] = &["shape", "scale"];
+
+    type State = Values;
+
+    /// The shape solves `Σ x^k ln x / Σ x^k - 1/k = mean(ln x)`, with the powers taken
+    /// relative to the largest value so they cannot overflow.
+    fn fit(state: &Values) -> Option<(Vec<f64>, f64)> {
+        let sample = &state.0;
+        let ln_x = logs(sample)?;
+        let (ln_mean, ln_var) = mean_variance(&ln_x);
+        if ln_var.is_nan() || ln_var <= 0.0 {
+            return None;
+        }
+        let ln_max = ln_x.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        // The weighted moments of ln x with weights x^k.
+        let moments = |k: f64| {
+            let (mut w, mut wl, mut wl2) = (0.0, 0.0, 0.0);
+            for &l in &ln_x {
+                let wi = (k * (l - ln_max)).exp();
+                w += wi;
+                wl += wi * l;
+                wl2 += wi * l * l;
+            }
+            (w, wl / w, wl2 / w)
+        };
+        let start = std::f64::consts::PI / (6.0 * ln_var).sqrt();
+        let shape = newton_positive(start, |k| {
+            let (_, a, b) = moments(k);
+            (a - 1.0 / k - ln_mean, b - a * a + 1.0 / (k * k))
+        })?;
+        let (w, _, _) = moments(shape);
+        let scale = (ln_max + (w / ln_x.len() as f64).ln() / shape).exp();
+        let d = Weibull::new(shape, scale).ok()?;
+        let ln_likelihood = sample.iter().map(|&x| d.ln_pdf(x)).sum();
+        Some((vec![shape, scale], ln_likelihood))
+    }
+}
+
+impl SumFit for Beta {
+    const SUMS: &'static [&'static str] = &["ln_x", "ln_1mx"];
+
+    fn add(sums: &mut [f64], x: f64) {
+        if x > 0.0 && x < 1.0 {
+            sums[0] += x.ln();
+            sums[1] += (-x).ln_1p();
+        } else {
+            sums[0] = f64::NAN;
+        }
+    }
+}
+
+impl Fit for Beta {
+    const PARAMS: &'static [&'static str] = &["shape_a", "shape_b"];
+
+    type State = Sums<Self>;
+
+    /// Newton's method on `ψ(α) - ψ(α + β) = mean(ln x)` and
+    /// `ψ(β) - ψ(α + β) = mean(ln(1 - x))`, starting from the approximation by the geometric
+    /// means `G` of `x` and `1 - x`, `α ≈ 1/2 + Gx / (2 (1 - Gx - G1mx))` and likewise for `β`.
+    /// The geometric means sum to less than one unless all the values are equal.
+    fn fit(state: &Sums<Self>) -> Option<(Vec<f64>, f64)> {
+        let means = state.means()?;
+        let (ln_x, ln_1mx) = (means[0], means[1]);
+        let (g_x, g_1mx) = (ln_x.exp(), ln_1mx.exp());
+        let rest = 1.0 - g_x - g_1mx;
+        if rest <= 0.0 {
+            return None;
+        }
+        let (mut a, mut b) = (0.5 + g_x / (2.0 * rest), 0.5 + g_1mx / (2.0 * rest));
+        for _ in 0..MAX_ITER {
+            let psi_ab = digamma(a + b);
+            let g1 = digamma(a) - psi_ab - ln_x;
+            let g2 = digamma(b) - psi_ab - ln_1mx;
+            let t_ab = trigamma(a + b);
+            let (j11, j12, j22) = (trigamma(a) - t_ab, -t_ab, trigamma(b) - t_ab);
+            let det = j11 * j22 - j12 * j12;
+            let da = (j22 * g1 - j12 * g2) / det;
+            let db = (j11 * g2 - j12 * g1) / det;
+            // Shorten the step until both shapes stay positive.
+            let mut t = 1.0;
+            while a - t * da <= 0.0 || b - t * db <= 0.0 {
+                t /= 2.0;
+            }
+            let (next_a, next_b) = (a - t * da, b - t * db);
+            if !next_a.is_finite() || !next_b.is_finite() {
+                return None;
+            }
+            let done = (next_a - a).abs() <= TOLERANCE * a && (next_b - b).abs() <= TOLERANCE * b;
+            (a, b) = (next_a, next_b);
+            if done {
+                break;
+            }
+        }
+        Beta::new(a, b).ok()?;
+        let n = state.n as f64;
+        let ln_likelihood =
+            (a - 1.0) * state.sums[0] + (b - 1.0) * state.sums[1] - n * ln_beta(a, b);
+        Some((vec![a, b], ln_likelihood))
+    }
+}
+
+/// The fields of a fit: the parameters, the log-likelihood of the sample and its size.
+fn fit_fields(params: impl Iterator<Item = Field>) -> Fields {
+    params
+        .chain([
+            Field::new("ln_likelihood", DataType::Float64, false),
+            Field::new("n", DataType::UInt64, false),
+        ])
+        .collect()
+}
+
+/// `Float64` fields named `names`.
+fn float_fields(names: &[&str]) -> impl Iterator<Item = Field> {
+    names
+        .iter()
+        .map(|name| Field::new(*name, DataType::Float64, false))
+}
+
+/// The struct of a fit with `fields`, of the estimates `params` and the log-likelihood of a
+/// sample of size `n`.
+fn fit_struct(
+    fields: Fields,
+    params: Vec<ScalarValue>,
+    ln_likelihood: f64,
+    n: u64,
+) -> datafusion::error::Result<ScalarValue> {
+    let values = params.into_iter().chain([
+        ScalarValue::Float64(Some(ln_likelihood)),
+        ScalarValue::UInt64(Some(n)),
+    ]);
+    fields
+        .iter()
+        .zip(values)
+        .fold(ScalarStructBuilder::new(), |builder, (field, value)| {
+            builder.with_scalar(Arc::clone(field), value)
+        })
+        .build()
+}
+
+/// The fields of the fit of `D`.
+fn fields<D: Fit>() -> Fields {
+    fit_fields(float_fields(D::PARAMS))
+}
+
+/// Aggregate fitting `D` to a column by maximum likelihood, returned as a struct of the
+/// parameters, the log-likelihood and the sample size. Nulls and NaNs are skipped, and the
+/// fit is NULL if there is no estimate.
+#[derive(Debug)]
+pub struct FitAggregate<D: Fit> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Fit> FitAggregate<D> {
+    pub fn new(name: &str) -> Self {
+        FitAggregate {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<D: Fit> AggregateUDFImpl for FitAggregate<D> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Float])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Struct(fields::<D>()))
+    }
+
+    /// What [`Fit::State`] keeps of the sample, rather than the default of a value of the
+    /// return type.
+    fn state_fields(&self, args: StateFieldsArgs) -> datafusion::error::Result<Vec<FieldRef>> {
+        Ok(D::State::state_fields(args.name)
+            .into_iter()
+            .map(Arc::new)
+            .collect())
+    }
+
+    fn accumulator(
+        &self,
+        _acc_args: AccumulatorArgs,
+    ) -> datafusion::error::Result<Box<dyn Accumulator>> {
+        Ok(Box::new(FitAccumulator::<D> {
+            state: D::State::new(),
+        }))
+    }
+}
+
+/// Keeps the [`Fit::State`] of the values, skipping nulls and NaNs, and fits it at the end.
+#[derive(Debug)]
+struct FitAccumulator<D: Fit> {
+    state: D::State,
+}
+
+impl<D: Fit> Accumulator for FitAccumulator<D> {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> datafusion::error::Result<()> {
+        for x in as_float64_array(&values[0])?.iter().flatten() {
+            if !x.is_nan() {
+                self.state.push(x);
+            }
+        }
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> datafusion::error::Result<()> {
+        self.state.merge(states)
+    }
+
+    fn state(&mut self) -> datafusion::error::Result<Vec<ScalarValue>> {
+        Ok(self.state.state())
+    }
+
+    fn evaluate(&mut self) -> datafusion::error::Result<ScalarValue> {
+        let fit = if self.state.len() == 0 {
+            None
+        } else {
+            D::fit(&self.state)
+        };
+        let Some((params, ln_likelihood)) = fit else {
+            return Ok(ScalarStructBuilder::new_null(fields::<D>()));
+        };
+        let params = params
+            .into_iter()
+            .map(|v| ScalarValue::Float64(Some(v)))
+            .collect();
+        fit_struct(fields::<D>(), params, ln_likelihood, self.state.len())
+    }
+
+    fn size(&self) -> usize {
+        size_of::<Self>() + self.state.size()
+    }
+}
+
+/// Maximum likelihood fitting of a discrete distribution to a sample of counts, which the
+/// distributions of a number of trials take together with the trials of each count.
+pub trait CountFit: std::fmt::Debug + Send + Sync + Sized + 'static {
+    /// The names of the arguments of the aggregate, each a non-negative integer: the counts,
+    /// then the trials if any.
+    const ARGS: &'static [&'static str];
+
+    /// What the aggregate keeps of the rows of the arguments to fit them.
+    type State: FitState + for<'a> Push<&'a [u64]>;
+
+    /// The fields of the parameters, in the order the other functions of the distribution
+    /// take them.
+    fn params() -> Vec<Field>;
+
+    /// The estimates of the parameters, in order, and the log-likelihood of the sample, or
+    /// `None` if the sample has no maximum likelihood estimate.
+    fn fit(state: &Self::State) -> Option<(Vec<ScalarValue>, f64)>;
+}
+
+fn float_params(params: &[f64]) -> Vec<ScalarValue> {
+    params
+        .iter()
+        .map(|&v| ScalarValue::Float64(Some(v)))
+        .collect()
+}
+
+impl SumFit for Poisson {
+    const SUMS: &'static [&'static str] = &["x", "ln_x_factorial"];
+
+    fn add(sums: &mut [f64], x: f64) {
+        sums[0] += x;
+        sums[1] += ln_factorial(x as u64);
+    }
+}
+
+impl CountFit for Poisson {
+    const ARGS: &'static [&'static str] = &["x"];
+
+    type State = Sums<Self>;
+
+    fn params() -> Vec<Field> {
+        float_fields(&["lambda"]).collect()
+    }
+
+    fn fit(state: &Sums<Self>) -> Option<(Vec<ScalarValue>, f64)> {
+        let lambda = state.means()?[0];
+        Poisson::new(lambda).ok()?;
+        let ln_likelihood = state.sums[0] * lambda.ln() - state.n as f64 * lambda - state.sums[1];
+        Some((float_params(&[lambda]), ln_likelihood))
+    }
+}
+
+impl SumFit for Geometric {
+    const SUMS: &'static [&'static str] = &["x"];
+
+    /// The counts are numbers of trials, so a count of 0 is outside the support.
+    fn add(sums: &mut [f64], x: f64) {
+        sums[0] += if x > 0.0 { x } else { f64::NAN };
+    }
+}
+
+impl CountFit for Geometric {
+    const ARGS: &'static [&'static str] = &["x"];
+
+    type State = Sums<Self>;
+
+    fn params() -> Vec<Field> {
+        float_fields(&["p"]).collect()
+    }
+
+    fn fit(state: &Sums<Self>) -> Option<(Vec<ScalarValue>, f64)> {
+        let p = 1.0 / state.means()?[0];
+        Geometric::new(p).ok()?;
+        // Each count is a success after `x - 1` failures, of which there are none when p is 1.
+        let n = state.n as f64;
+        let failures = state.sums[0] - n;
+        let ln_likelihood = n * p.ln()
+            + if failures > 0.0 {
+                failures * (-p).ln_1p()
+            } else {
+                0.0
+            };
+        Some((float_params(&[p]), ln_likelihood))
+    }
+}
+
+impl CountFit for NegativeBinomial {
+    const ARGS: &'static [&'static str] = &["x"];
+
+    type State = Histogram<Self>;
+
+    fn params() -> Vec<Field> {
+        float_fields(&["r", "p"]).collect()
+    }
+
+    /// `r` solves `mean(ψ(k + r)) - ψ(r) + ln(r / (r + mean)) = 0`, bracketed from the
+    /// method of moments, and `p` is then `r / (r + mean)`. The sample must be overdispersed,
+    /// with a variance above its mean, as otherwise the likelihood grows towards a Poisson
+    /// distribution as `r` grows.
+    fn fit(state: &Histogram<Self>) -> Option<(Vec<ScalarValue>, f64)> {
+        let n = state.n as f64;
+        let counts = || state.rows.iter().map(|(k, &c)| (k[0] as f64, c as f64));
+        let m = counts().map(|(k, c)| c * k).sum::<f64>() / n;
+        let v = counts().map(|(k, c)| c * (k - m) * (k - m)).sum::<f64>() / n;
+        if v.is_nan() || v <= m {
+            return None;
+        }
+        let r = newton_bracketed(m * m / (v - m), |r| {
+            let (psi, tri) = counts().fold((0.0, 0.0), |(psi, tri), (k, c)| {
+                (psi + c * digamma(k + r), tri + c * trigamma(k + r))
+            });
+            (
+                psi / n - digamma(r) + (r / (r + m)).ln(),
+                tri / n - trigamma(r) + 1.0 / r - 1.0 / (r + m),
+            )
+        })?;
+        let p = r / (r + m);
+        let d = NegativeBinomial::new(r, p).ok()?;
+        let ln_likelihood = state
+            .rows
+            .iter()
+            .map(|(k, &c)| c as f64 * d.ln_pmf(k[0]))
+            .sum();
+        Some((float_params(&[r, p]), ln_likelihood))
+    }
+}
+
+impl CountFit for Binomial {
+    const ARGS: &'static [&'static str] = &["x", "n"];
+
+    type State = Histogram<Self>;
+
+    /// The trials are NULL unless every count has the same number of trials, as the fit
+    /// is then of several binomial distributions sharing `p`.
+    fn params() -> Vec<Field> {
+        let mut params = vec![Field::new("trials", DataType::UInt64, true)];
+        params.extend(float_fields(&["p"]));
+        params
+    }
+
+    /// `p` is the total of the counts over the total of the trials.
+    fn fit(state: &Histogram<Self>) -> Option<(Vec<ScalarValue>, f64)> {
+        if state.rows.keys().any(|row| row[0] > row[1]) {
+            return None;
+        }
+        let total = |j: usize| {
+            state
+                .rows
+                .iter()
+                .map(|(row, &c)| c as f64 * row[j] as f64)
+                .sum::<f64>()
+        };
+        let p = total(0) / total(1);
+        let ln_likelihood = state
+            .rows
+            .iter()
+            .map(|(row, &c)| Some(c as f64 * Binomial::new(p, row[1]).ok()?.ln_pmf(row[0])))
+            .sum::<Option<f64>>()?;
+        let mut trials = state.rows.keys().map(|row| row[1]);
+        let first = trials.next();
+        let same = trials.all(|n| Some(n) == first);
+        let params = vec![
+            ScalarValue::UInt64(first.filter(|_| same)),
+            ScalarValue::Float64(Some(p)),
+        ];
+        Some((params, ln_likelihood))
+    }
+}
+
+/// The fields of the fit of `D`.
+fn count_fields<D: CountFit>() -> Fields {
+    fit_fields(D::params().into_iter())
+}
+
+/// Aggregate fitting `D` to columns of counts by maximum likelihood, returned as a struct of
+/// the parameters, the log-likelihood and the sample size. Rows with a null argument are
+/// skipped, negative arguments are an error, and the fit is NULL if there is no estimate.
+#[derive(Debug)]
+pub struct CountFitAggregate<D: CountFit> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<D>,
+}
+
+impl<D: CountFit> CountFitAggregate<D> {
+    pub fn new(name: &str) -> Self {
+        CountFitAggregate {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<D: CountFit> AggregateUDFImpl for CountFitAggregate<D> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &vec![Arg::Count; D::ARGS.len()])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Struct(count_fields::<D>()))
+    }
+
+    /// What [`CountFit::State`] keeps of the rows, rather than the default of a value of the
+    /// return type.
+    fn state_fields(&self, args: StateFieldsArgs) -> datafusion::error::Result<Vec<FieldRef>> {
+        Ok(D::State::state_fields(args.name)
+            .into_iter()
+            .map(Arc::new)
+            .collect())
+    }
+
+    fn accumulator(
+        &self,
+        _acc_args: AccumulatorArgs,
+    ) -> datafusion::error::Result<Box<dyn Accumulator>> {
+        Ok(Box::new(CountFitAccumulator::<D> {
+            name: self.name.clone(),
+            state: D::State::new(),
+        }))
+    }
+}
+
+/// Keeps the [`CountFit::State`] of the rows of the arguments without a null, and fits it
+/// at the end.
+#[derive(Debug)]
+struct CountFitAccumulator<D: CountFit> {
+    name: String,
+    state: D::State,
+}
+
+impl<D: CountFit> Accumulator for CountFitAccumulator<D> {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> datafusion::error::Result<()> {
+        let columns = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                count_arg(&self.name, i + 1, &ColumnarValue::Array(Arc::clone(v)))?
+                    .to_array(v.len())
+            })
+            .collect::<datafusion::error::Result<Vec<_>>>()?;
+        let columns = columns
+            .iter()
+            .map(|c| as_uint64_array(c))
+            .collect::<datafusion::error::Result<Vec<_>>>()?;
+        let mut row = vec![0; columns.len()];
+        for i in 0..columns[0].len() {
+            if columns.iter().all(|c| c.is_valid(i)) {
+                for (v, c) in row.iter_mut().zip(&columns) {
+                    *v = c.value(i);
+                }
+                self.state.push(&row);
+            }
+        }
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> datafusion::error::Result<()> {
+        self.state.merge(states)
+    }
+
+    fn state(&mut self) -> datafusion::error::Result<Vec<ScalarValue>> {
+        Ok(self.state.state())
+    }
+
+    fn evaluate(&mut self) -> datafusion::error::Result<ScalarValue> {
+        let fit = if self.state.len() == 0 {
+            None
+        } else {
+            D::fit(&self.state)
+        };
+        let Some((params, ln_likelihood)) = fit else {
+            return Ok(ScalarStructBuilder::new_null(count_fields::<D>()));
+        };
+        fit_struct(count_fields::<D>(), params, ln_likelihood, self.state.len())
+    }
+
+    fn size(&self) -> usize {
+        size_of::<Self>() + self.name.capacity() + self.state.size()
+    }
+}
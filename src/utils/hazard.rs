@@ -0,0 +1,75 @@
+use statrs::distribution::{Continuous, Exp, Gamma, Gumbel, Weibull};
+
+use super::ln_cdf::{LnCdf, LogNormalParams};
+
+/// The hazard `pdf / sf` and cumulative hazard `-ln sf` of a distribution of survival
+/// times, computed without dividing the PDF by an SF that underflows in the far tail.
+pub trait Hazard: LnCdf<f64> {
+    fn hazard(&self, x: f64) -> f64;
+
+    /// `0 - ln sf`, so that it is 0 rather than -0 where the SF is 1.
+    fn cum_hazard(&self, x: f64) -> f64 {
+        0.0 - self.ln_sf(x)
+    }
+}
+
+macro_rules! impl_hazard_by_log {
+    ($($dist:ty),+) => {
+        $(
+            impl Hazard for $dist {
+                fn hazard(&self, x: f64) -> f64 {
+                    (self.ln_pdf(x) - self.ln_sf(x)).exp()
+                }
+            }
+        )+
+    };
+}
+
+impl_hazard_by_log!(Gamma);
+
+impl Hazard for LogNormalParams {
+    fn hazard(&self, x: f64) -> f64 {
+        (self.dist.ln_pdf(x) - self.ln_sf(x)).exp()
+    }
+}
+
+impl Hazard for Exp {
+    fn hazard(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else if x.is_nan() {
+            f64::NAN
+        } else {
+            self.rate()
+        }
+    }
+}
+
+impl Hazard for Weibull {
+    fn hazard(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        self.shape() / self.scale() * (x / self.scale()).powf(self.shape() - 1.0)
+    }
+}
+
+impl Hazard for Gumbel {
+    /// `t / (exp(t) - 1) / β` with `t = exp(-z)`, rearranged so that it neither overflows
+    /// for large `t` nor divides zero by zero where `t` underflows.
+    fn hazard(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        let z = (x - self.location()) / self.scale();
+        let t = (-z).exp();
+        let h = if t > 1.0 {
+            (-z - t).exp() / -(-t).exp_m1()
+        } else if t > 0.0 {
+            t / t.exp_m1()
+        } else {
+            1.0
+        };
+        h / self.scale()
+    }
+}
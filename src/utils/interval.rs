@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, NullBufferBuilder, PrimitiveBuilder, StructArray},
+        datatypes::{ArrowPrimitiveType, DataType, Field, Fields},
+    },
+    error::DataFusionError,
+};
+use statrs::distribution::{
+    Bernoulli, Beta, Binomial, Categorical, Cauchy, Chi, ChiSquared, Dirac, DiscreteUniform,
+    Erlang, Exp, FisherSnedecor, Gamma, Geometric, Gumbel, Hypergeometric, InverseGamma, Laplace,
+    LogNormal, NegativeBinomial, Normal, Pareto, Poisson, StudentsT, Triangular, Uniform, Weibull,
+};
+
+use super::inverse_cdf::{InverseCdf, discrete_quantile, discrete_quantile_i64};
+use super::inverse_sf::{InverseSf, discrete_inverse_sf, discrete_inverse_sf_i64};
+use super::statistic::{Location, Native};
+
+/// The lower and upper bounds of an interval of values of type `T`.
+pub type Bounds<T> = (Native<T>, Native<T>);
+
+/// The equal-tailed central interval of a univariate distribution, with bounds of the same
+/// type as its other values, see [`Location`].
+pub trait Interval: Location {
+    /// The bounds leaving probability `tail` below the lower and above the upper bound.
+    fn bounds(&self, tail: f64) -> Bounds<Self::Value>;
+
+    /// The interval holding probability `level`, or `None` if `level` is not in [0, 1].
+    fn interval(&self, level: f64) -> Option<Bounds<Self::Value>> {
+        if !(0.0..=1.0).contains(&level) {
+            return None;
+        }
+        Some(self.bounds((1.0 - level) / 2.0))
+    }
+}
+
+macro_rules! impl_continuous_interval {
+    ($($dist:ty),+) => {
+        $(
+            impl Interval for $dist {
+                fn bounds(&self, tail: f64) -> (f64, f64) {
+                    (self.quantile(tail), self.inverse_sf(tail))
+                }
+            }
+        )+
+    };
+}
+
+impl_continuous_interval!(
+    Beta,
+    Cauchy,
+    Chi,
+    ChiSquared,
+    Dirac,
+    Erlang,
+    Exp,
+    FisherSnedecor,
+    Gamma,
+    Gumbel,
+    InverseGamma,
+    Laplace,
+    LogNormal,
+    Normal,
+    Pareto,
+    StudentsT,
+    Triangular,
+    Uniform,
+    Weibull
+);
+
+macro_rules! impl_discrete_interval {
+    ($($dist:ty),+) => {
+        $(
+            impl Interval for $dist {
+                /// The smallest values with at most `tail` below the lower bound and at
+                /// most `tail` above the upper bound, so the interval holds at least the level.
+                fn bounds(&self, tail: f64) -> (u64, u64) {
+                    (discrete_quantile(self, tail), discrete_inverse_sf(self, tail))
+                }
+            }
+        )+
+    };
+}
+
+impl_discrete_interval!(
+    Bernoulli,
+    Binomial,
+    Categorical,
+    Geometric,
+    Hypergeometric,
+    NegativeBinomial,
+    Poisson
+);
+
+impl Interval for DiscreteUniform {
+    /// As for the count distributions, over `Int64`.
+    fn bounds(&self, tail: f64) -> (i64, i64) {
+        (
+            discrete_quantile_i64(self, tail),
+            discrete_inverse_sf_i64(self, tail),
+        )
+    }
+}
+
+/// The fields of an interval with bounds of type `T`.
+fn fields<T: ArrowPrimitiveType>() -> Fields {
+    Fields::from(vec![
+        Field::new("lower", T::DATA_TYPE, false),
+        Field::new("upper", T::DATA_TYPE, false),
+    ])
+}
+
+/// The `Struct<lower, upper>` type of an interval with bounds of type `T`.
+pub fn interval_type<T: ArrowPrimitiveType>() -> DataType {
+    DataType::Struct(fields::<T>())
+}
+
+/// Collect the intervals of the rows into a struct array, `None` being a NULL interval.
+pub fn interval_array<T: ArrowPrimitiveType>(
+    rows: impl Iterator<Item = Result<Option<Bounds<T>>, DataFusionError>>,
+) -> Result<ArrayRef, DataFusionError> {
+    let mut lower = PrimitiveBuilder::<T>::new();
+    let mut upper = PrimitiveBuilder::<T>::new();
+    let mut nulls = NullBufferBuilder::new(0);
+    for row in rows {
+        match row? {
+            Some((l, u)) => {
+                lower.append_value(l);
+                upper.append_value(u);
+                nulls.append_non_null();
+            }
+            None => {
+                lower.append_value(T::Native::default());
+                upper.append_value(T::Native::default());
+                nulls.append_null();
+            }
+        }
+    }
+    let columns: Vec<ArrayRef> = vec![Arc::new(lower.finish()), Arc::new(upper.finish())];
+    Ok(Arc::new(StructArray::try_new(
+        fields::<T>(),
+        columns,
+        nulls.finish(),
+    )?))
+}
@@ -0,0 +1,70 @@
+use std::marker::PhantomData;
+
+use datafusion::{
+    arrow::datatypes::{DataType, Float64Type},
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
+};
+
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::factory1l::Factory1L;
+use super::interval::{Interval, interval_array, interval_type};
+use super::list::ListParam;
+use super::param::{Param, named};
+
+#[derive(Debug)]
+pub struct Interval1L<D: Factory1L + Interval> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1L + Interval> Interval1L<D> {
+    pub fn new(name: &str) -> Self {
+        Interval1L {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<D: Factory1L + Interval> ScalarUDFImpl for Interval1L<D> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Float, Arg::FloatList])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(interval_type::<D::Value>())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let level = Param::<Float64Type>::new(&self.name, &args.args, 0)?;
+        let p = ListParam::new(&self.name, &args.args, 1)?;
+
+        let mut cache = LastDist::<Vec<f64>, D>::new();
+        let rows = (0..args.number_rows).map(|i| match (level.get(i), p.row(i)?) {
+            (Some(level), Some(p)) => {
+                let d = cache.get(p.values().as_ref(), || {
+                    D::make(p.values()).map_err(|e| named(&self.name, e))
+                })?;
+                Ok(d.interval(level))
+            }
+            _ => Ok(None),
+        });
+        Ok(ColumnarValue::from(interval_array::<D::Value>(rows)?))
+    }
+}
@@ -0,0 +1,70 @@
+use std::marker::PhantomData;
+
+use datafusion::{
+    arrow::datatypes::{DataType, Float64Type},
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
+};
+
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::factory2f::Factory2F;
+use super::interval::{Interval, interval_array, interval_type};
+use super::param::{Param, named};
+
+#[derive(Debug)]
+pub struct Interval2F<D: Factory2F + Interval> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory2F + Interval> Interval2F<D> {
+    pub fn new(name: &str) -> Self {
+        Interval2F {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<D: Factory2F + Interval> ScalarUDFImpl for Interval2F<D> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Float, Arg::Float, Arg::Float])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(interval_type::<D::Value>())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let level = Param::<Float64Type>::new(&self.name, &args.args, 0)?;
+        let p1 = Param::<Float64Type>::new(&self.name, &args.args, 1)?;
+        let p2 = Param::<Float64Type>::new(&self.name, &args.args, 2)?;
+
+        let mut cache = LastDist::new();
+        let rows = (0..args.number_rows).map(|i| match (level.get(i), p1.get(i), p2.get(i)) {
+            (Some(level), Some(p1), Some(p2)) => {
+                let d = cache.get(&(p1, p2), || {
+                    D::make(p1, p2).map_err(|e| named(&self.name, e))
+                })?;
+                Ok(d.interval(level))
+            }
+            _ => Ok(None),
+        });
+        Ok(ColumnarValue::from(interval_array::<D::Value>(rows)?))
+    }
+}
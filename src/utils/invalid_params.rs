@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use datafusion::{
+    arrow::datatypes::{DataType, FieldRef},
+    error::DataFusionError,
+    logical_expr::{
+        ColumnarValue, Documentation, Expr, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDF,
+        ScalarUDFImpl, Signature,
+        interval_arithmetic::Interval,
+        simplify::{ExprSimplifyResult, SimplifyInfo},
+        sort_properties::{ExprProperties, SortProperties},
+    },
+    scalar::ScalarValue,
+};
+
+use crate::options::InvalidParams;
+
+use super::param::is_invalid_params;
+use super::wrap::rewrap;
+
+/// Wraps a function so that rows with invalid distribution parameters come back as NULL
+/// or NaN instead of failing the query.
+///
+/// The wrapped function is evaluated on the whole batch first. Only if that fails is the
+/// batch evaluated again one row at a time, replacing the rows that fail, so clean batches
+/// cost nothing extra. Only errors from building a distribution count, see
+/// [`is_invalid_params`]; errors in the types, number or shape of the arguments still
+/// fail the query.
+///
+/// Everything but the evaluation is left to the wrapped function, including
+/// [`ScalarUDFImpl::simplify`], whose result is wrapped in turn.
+#[derive(Debug)]
+pub struct InvalidParamsAs {
+    inner: Arc<ScalarUDF>,
+    policy: InvalidParams,
+}
+
+impl InvalidParamsAs {
+    pub fn new(inner: Arc<ScalarUDF>, policy: InvalidParams) -> Self {
+        InvalidParamsAs { inner, policy }
+    }
+
+    fn replacement(&self, return_type: &DataType) -> Result<ScalarValue, DataFusionError> {
+        match (self.policy, return_type) {
+            (InvalidParams::Nan, DataType::Float64) => Ok(ScalarValue::Float64(Some(f64::NAN))),
+            _ => ScalarValue::try_from(return_type),
+        }
+    }
+
+    fn invoke_row(
+        &self,
+        args: &ScalarFunctionArgs,
+        row: usize,
+    ) -> Result<ScalarValue, DataFusionError> {
+        let row_args = args
+            .args
+            .iter()
+            .map(|arg| match arg {
+                ColumnarValue::Array(array) => Ok(ColumnarValue::Scalar(
+                    ScalarValue::try_from_array(array, row)?,
+                )),
+                ColumnarValue::Scalar(scalar) => Ok(ColumnarValue::Scalar(scalar.clone())),
+            })
+            .collect::<Result<Vec<_>, DataFusionError>>()?;
+        let row_args = ScalarFunctionArgs {
+            args: row_args,
+            arg_fields: args.arg_fields.clone(),
+            number_rows: 1,
+            return_field: args.return_field.clone(),
+        };
+        match self.inner.invoke_with_args(row_args) {
+            Ok(ColumnarValue::Array(array)) => ScalarValue::try_from_array(&array, 0),
+            Ok(ColumnarValue::Scalar(scalar)) => Ok(scalar),
+            Err(e) if is_invalid_params(&e) => self.replacement(args.return_type()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl ScalarUDFImpl for InvalidParamsAs {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn display_name(&self, args: &[Expr]) -> datafusion::error::Result<String> {
+        self.inner.display_name(args)
+    }
+
+    fn schema_name(&self, args: &[Expr]) -> datafusion::error::Result<String> {
+        self.inner.schema_name(args)
+    }
+
+    fn aliases(&self) -> &[String] {
+        self.inner.aliases()
+    }
+
+    fn signature(&self) -> &Signature {
+        self.inner.signature()
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        self.inner.coerce_types(arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        self.inner.return_type(arg_types)
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> datafusion::error::Result<FieldRef> {
+        self.inner.return_field_from_args(args)
+    }
+
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> datafusion::error::Result<ExprSimplifyResult> {
+        let result = self.inner.simplify(args, info)?;
+        Ok(rewrap(self.name(), result, |inner| {
+            ScalarUDF::from(InvalidParamsAs::new(inner, self.policy))
+        }))
+    }
+
+    fn short_circuits(&self) -> bool {
+        self.inner.short_circuits()
+    }
+
+    fn evaluate_bounds(&self, input: &[&Interval]) -> datafusion::error::Result<Interval> {
+        self.inner.evaluate_bounds(input)
+    }
+
+    fn propagate_constraints(
+        &self,
+        interval: &Interval,
+        inputs: &[&Interval],
+    ) -> datafusion::error::Result<Option<Vec<Interval>>> {
+        self.inner.propagate_constraints(interval, inputs)
+    }
+
+    fn output_ordering(
+        &self,
+        inputs: &[ExprProperties],
+    ) -> datafusion::error::Result<SortProperties> {
+        self.inner.output_ordering(inputs)
+    }
+
+    fn preserves_lex_ordering(&self, inputs: &[ExprProperties]) -> datafusion::error::Result<bool> {
+        self.inner.preserves_lex_ordering(inputs)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.inner.documentation()
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let batch_args = ScalarFunctionArgs {
+            args: args.args.clone(),
+            arg_fields: args.arg_fields.clone(),
+            number_rows: args.number_rows,
+            return_field: args.return_field.clone(),
+        };
+        match self.inner.invoke_with_args(batch_args) {
+            Err(e) if is_invalid_params(&e) && self.policy != InvalidParams::Error => {}
+            result => return result,
+        }
+
+        if args
+            .args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)))
+        {
+            return Ok(ColumnarValue::Scalar(self.invoke_row(&args, 0)?));
+        }
+        let values = (0..args.number_rows)
+            .map(|row| self.invoke_row(&args, row))
+            .collect::<Result<Vec<_>, DataFusionError>>()?;
+        let array = ScalarValue::iter_to_array(values)?;
+        Ok(ColumnarValue::Array(array))
+    }
+}
@@ -0,0 +1,185 @@
+use statrs::distribution::{
+    Beta, Cauchy, Chi, ChiSquared, ContinuousCDF, Dirac, DiscreteCDF, Erlang, Exp, FisherSnedecor,
+    Gamma, Gumbel, InverseGamma, Laplace, LogNormal, Normal, Pareto, StudentsT, Triangular,
+    Uniform, Weibull,
+};
+use statrs::statistics::Min;
+
+use super::inverse_sf::InverseSf;
+use super::ln_cdf::LnCdf;
+
+/// Quantile function for the continuous distributions.
+///
+/// Defaults to [`ContinuousCDF::inverse_cdf`]. Distributions for which statrs only
+/// provides a search with a fixed iteration count (which loses accuracy, most of all in
+/// the tails) override it.
+pub trait InverseCdf: ContinuousCDF<f64, f64> {
+    fn quantile(&self, p: f64) -> f64 {
+        self.inverse_cdf(p)
+    }
+}
+
+impl InverseCdf for Beta {}
+
+impl InverseCdf for Cauchy {}
+
+impl InverseCdf for Chi {
+    fn quantile(&self, p: f64) -> f64 {
+        let chi_squared = ChiSquared::new(self.freedom() as f64).unwrap();
+        chi_squared.quantile(p).sqrt()
+    }
+}
+
+impl InverseCdf for ChiSquared {
+    fn quantile(&self, p: f64) -> f64 {
+        positive_quantile(self, p)
+    }
+}
+
+impl InverseCdf for Dirac {
+    fn quantile(&self, _p: f64) -> f64 {
+        self.min()
+    }
+}
+
+impl InverseCdf for Erlang {
+    fn quantile(&self, p: f64) -> f64 {
+        positive_quantile(self, p)
+    }
+}
+
+impl InverseCdf for Exp {}
+
+impl InverseCdf for FisherSnedecor {}
+
+impl InverseCdf for Gamma {
+    fn quantile(&self, p: f64) -> f64 {
+        positive_quantile(self, p)
+    }
+}
+
+impl InverseCdf for Gumbel {}
+
+impl InverseCdf for InverseGamma {
+    fn quantile(&self, p: f64) -> f64 {
+        // If X ~ InverseGamma(α, β) then 1/X ~ Gamma(α, β).
+        let gamma = Gamma::new(self.shape(), self.rate()).unwrap();
+        1.0 / gamma.inverse_sf(p)
+    }
+}
+
+impl InverseCdf for Laplace {}
+
+impl InverseCdf for LogNormal {}
+
+impl InverseCdf for Normal {}
+
+impl InverseCdf for Pareto {}
+
+impl InverseCdf for StudentsT {}
+
+impl InverseCdf for Triangular {}
+
+impl InverseCdf for Uniform {}
+
+impl InverseCdf for Weibull {}
+
+/// Quantile for a distribution supported on `[0, ∞)`, bracketing by doubling or halving
+/// from one and bisecting until the bracket is down to adjacent floats.
+///
+/// The search compares `ln_cdf` with `ln(p)`, as statrs' `cdf` rounds to zero well before
+/// the true CDF underflows, which would stop the search far above a small quantile. The
+/// statrs estimate is not used as a starting point, since its search panics for some
+/// extreme parameters.
+fn positive_quantile<D: ContinuousCDF<f64, f64> + LnCdf<f64>>(d: &D, p: f64) -> f64 {
+    if p == 0.0 {
+        return d.min();
+    }
+    if p == 1.0 {
+        return d.max();
+    }
+
+    let ln_p = p.ln();
+    let mut hi = 1.0;
+    let mut lo = hi;
+    while d.ln_cdf(hi) < ln_p {
+        lo = hi;
+        hi *= 2.0;
+    }
+    while lo > 0.0 && d.ln_cdf(lo) >= ln_p {
+        hi = lo;
+        lo /= 2.0;
+    }
+    bisect(lo, hi, |x| d.ln_cdf(x) >= ln_p)
+}
+
+/// Find the smallest float in `(lo, hi]` for which the monotone predicate holds, given
+/// that it does not hold at `lo` and does at `hi`.
+pub fn bisect(mut lo: f64, mut hi: f64, pred: impl Fn(f64) -> bool) -> f64 {
+    loop {
+        let mid = lo + (hi - lo) / 2.0;
+        if mid <= lo || mid >= hi {
+            return hi;
+        }
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+}
+
+/// Quantile function for the discrete distributions: the smallest `k` with `cdf(k) >= p`.
+///
+/// [`DiscreteCDF::inverse_cdf`] panics when `p <= cdf(min)`, so the search is done here.
+/// As in statrs, `p >= 1` gives the support maximum, which the search on `cdf` would
+/// stop short of once it rounds to one.
+pub fn discrete_quantile<D: DiscreteCDF<u64, f64>>(d: &D, p: f64) -> u64 {
+    if p >= 1.0 {
+        return d.max();
+    }
+    first_integer(d.min(), d.max(), |k| d.cdf(k) >= p)
+}
+
+/// [`discrete_quantile`] for a distribution over `Int64`, searched as offsets from its
+/// minimum so that the whole range of `Int64` can be covered.
+pub fn discrete_quantile_i64<D: DiscreteCDF<i64, f64>>(d: &D, p: f64) -> i64 {
+    if p >= 1.0 {
+        return d.max();
+    }
+    let min = d.min();
+    let k = first_integer(0, d.max().abs_diff(min), |k| {
+        d.cdf(min.wrapping_add_unsigned(k)) >= p
+    });
+    min.wrapping_add_unsigned(k)
+}
+
+/// Find the smallest `k` in `[min, max]` for which the monotone predicate holds,
+/// returning `max` if it never does.
+pub fn first_integer(min: u64, max: u64, pred: impl Fn(u64) -> bool) -> u64 {
+    if pred(min) {
+        return min;
+    }
+
+    // Invariant: !pred(lo) && pred(hi).
+    let mut lo = min;
+    let mut step = 1u64;
+    let mut hi = lo.saturating_add(step).min(max);
+    while !pred(hi) {
+        if hi == max {
+            return max;
+        }
+        lo = hi;
+        step = step.saturating_mul(2);
+        hi = lo.saturating_add(step).min(max);
+    }
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
@@ -0,0 +1,193 @@
+use std::f64::consts::PI;
+
+use statrs::distribution::{
+    Beta, Cauchy, Chi, ChiSquared, Dirac, DiscreteCDF, Erlang, Exp, FisherSnedecor, Gamma, Gumbel,
+    InverseGamma, Laplace, LogNormal, Normal, Pareto, StudentsT, Triangular, Uniform, Weibull,
+};
+use statrs::function::beta::inv_beta_reg;
+use statrs::statistics::{Max, Median, Min, Mode};
+
+use super::inverse_cdf::{InverseCdf, bisect, first_integer};
+
+/// Inverse survival function for the continuous distributions: the `x` with `sf(x) = p`.
+///
+/// Computing `quantile(1 - p)` throws away everything below `f64::EPSILON`, so for upper
+/// tail probabilities the default bisects on `sf` directly. Distributions with a closed
+/// form in terms of `p` override it.
+pub trait InverseSf: InverseCdf {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        if p >= 0.5 {
+            return self.quantile(1.0 - p);
+        }
+        if p == 0.0 {
+            return self.max();
+        }
+
+        // Invariant: sf(lo) > p >= sf(hi).
+        let max = self.max();
+        let mut lo = self.quantile(0.5);
+        let mut step = lo.abs().max(1.0);
+        let mut hi = (lo + step).min(max);
+        while self.sf(hi) > p {
+            if hi == max {
+                return max;
+            }
+            lo = hi;
+            step *= 2.0;
+            hi = (lo + step).min(max);
+        }
+        bisect(lo, hi, |x| self.sf(x) <= p)
+    }
+}
+
+impl InverseSf for Beta {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        // sf(x; α, β) = cdf(1 - x; β, α)
+        1.0 - inv_beta_reg(self.shape_b(), self.shape_a(), p)
+    }
+}
+
+impl InverseSf for Cauchy {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        if p >= 0.5 {
+            return self.quantile(1.0 - p);
+        }
+        self.location() + self.scale() / (PI * p).tan()
+    }
+}
+
+impl InverseSf for Chi {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        let chi_squared = ChiSquared::new(self.freedom() as f64).unwrap();
+        chi_squared.inverse_sf(p).sqrt()
+    }
+}
+
+impl InverseSf for ChiSquared {}
+
+impl InverseSf for Dirac {
+    fn inverse_sf(&self, _p: f64) -> f64 {
+        self.min()
+    }
+}
+
+impl InverseSf for Erlang {}
+
+impl InverseSf for Exp {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        -p.ln() / self.rate()
+    }
+}
+
+impl InverseSf for FisherSnedecor {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        // sf(x) = I_z(d2 / 2, d1 / 2) with z = d2 / (d2 + d1 x)
+        let d1 = self.freedom_1();
+        let d2 = self.freedom_2();
+        let z = inv_beta_reg(d2 / 2.0, d1 / 2.0, p);
+        d2 * (1.0 - z) / (d1 * z)
+    }
+}
+
+impl InverseSf for Gamma {}
+
+impl InverseSf for Gumbel {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        self.location() - self.scale() * (-(-p).ln_1p()).ln()
+    }
+}
+
+impl InverseSf for InverseGamma {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        // If X ~ InverseGamma(α, β) then 1/X ~ Gamma(α, β).
+        let gamma = Gamma::new(self.shape(), self.rate()).unwrap();
+        1.0 / gamma.quantile(p)
+    }
+}
+
+impl InverseSf for Laplace {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        if p >= 0.5 {
+            return self.quantile(1.0 - p);
+        }
+        self.location() - self.scale() * (2.0 * p).ln()
+    }
+}
+
+impl InverseSf for LogNormal {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        // Symmetric in log space about the median.
+        let median = self.median();
+        median * (median / self.quantile(p))
+    }
+}
+
+impl InverseSf for Normal {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        let median = self.median();
+        median + (median - self.quantile(p))
+    }
+}
+
+impl InverseSf for Pareto {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        self.scale() * p.powf(-1.0 / self.shape())
+    }
+}
+
+impl InverseSf for StudentsT {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        let median = self.median();
+        median + (median - self.quantile(p))
+    }
+}
+
+impl InverseSf for Triangular {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        let a = self.min();
+        let b = self.max();
+        let c = self.mode().unwrap();
+        if p < (b - c) / (b - a) {
+            b - ((b - a) * (b - c) * p).sqrt()
+        } else {
+            self.quantile(1.0 - p)
+        }
+    }
+}
+
+impl InverseSf for Uniform {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        let a = self.min();
+        let b = self.max();
+        b - p * (b - a)
+    }
+}
+
+impl InverseSf for Weibull {
+    fn inverse_sf(&self, p: f64) -> f64 {
+        self.scale() * (-p.ln()).powf(1.0 / self.shape())
+    }
+}
+
+/// Inverse survival function for the discrete distributions: the smallest `k` with
+/// `sf(k) <= p`, searched on `sf` so that small upper-tail probabilities are not lost.
+/// As for the continuous distributions, `p <= 0` gives the support maximum.
+pub fn discrete_inverse_sf<D: DiscreteCDF<u64, f64>>(d: &D, p: f64) -> u64 {
+    if p <= 0.0 {
+        return d.max();
+    }
+    first_integer(d.min(), d.max(), |k| d.sf(k) <= p)
+}
+
+/// [`discrete_inverse_sf`] for a distribution over `Int64`, searched as offsets from its
+/// minimum as for [`discrete_quantile_i64`](super::inverse_cdf::discrete_quantile_i64).
+pub fn discrete_inverse_sf_i64<D: DiscreteCDF<i64, f64>>(d: &D, p: f64) -> i64 {
+    if p <= 0.0 {
+        return d.max();
+    }
+    let min = d.min();
+    let k = first_integer(0, d.max().abs_diff(min), |k| {
+        d.sf(min.wrapping_add_unsigned(k)) <= p
+    });
+    min.wrapping_add_unsigned(k)
+}
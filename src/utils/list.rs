@@ -0,0 +1,85 @@
+use datafusion::{
+    arrow::{
+        array::{Array, ArrayRef, AsArray, Float64Array},
+        datatypes::DataType,
+    },
+    common::cast::as_float64_array,
+    error::DataFusionError,
+    logical_expr::ColumnarValue,
+};
+
+use super::param::{arg, type_error};
+
+/// Coerce a `List` or `FixedSizeList` of numbers to the same kind of list of `Float64`.
+pub fn coerce_float64_list(data_type: &DataType) -> Option<DataType> {
+    match data_type {
+        DataType::List(f) if is_number(f.data_type()) => {
+            Some(DataType::new_list(DataType::Float64, true))
+        }
+        DataType::FixedSizeList(f, n) if is_number(f.data_type()) => {
+            Some(DataType::new_fixed_size_list(DataType::Float64, *n, true))
+        }
+        _ => None,
+    }
+}
+
+/// Whether a list of `data_type` can be coerced to a list of `Float64`; the elements of an
+/// empty list literal have type `Null`.
+fn is_number(data_type: &DataType) -> bool {
+    data_type.is_numeric() || data_type.is_null()
+}
+
+/// The values in row `i` of a `List<Float64>` or `FixedSizeList<Float64>` array, or `None`
+/// if the row or any of its elements is null.
+pub fn float64_row(array: &dyn Array, i: usize) -> Result<Option<Float64Array>, DataFusionError> {
+    if array.is_null(i) {
+        return Ok(None);
+    }
+    let values = match array.data_type() {
+        DataType::FixedSizeList(_, _) => array.as_fixed_size_list().value(i),
+        DataType::List(_) => array.as_list::<i32>().value(i),
+        other => {
+            return Err(DataFusionError::Internal(format!(
+                "expected a list of Float64, got {other}"
+            )));
+        }
+    };
+    let values = as_float64_array(&values)?.clone();
+    Ok((values.null_count() == 0).then_some(values))
+}
+
+/// A list argument that is either a literal, held as a single row, or a column, so that
+/// literal arguments are never expanded into arrays.
+pub struct ListParam {
+    array: ArrayRef,
+    scalar: bool,
+}
+
+impl ListParam {
+    /// Argument `index` of the function `name`, which must be a `List<Float64>` or
+    /// `FixedSizeList<Float64>`.
+    pub fn new(name: &str, args: &[ColumnarValue], index: usize) -> Result<Self, DataFusionError> {
+        let arg = arg(name, args, index)?;
+        let data_type = arg.data_type();
+        match &data_type {
+            DataType::List(f) | DataType::FixedSizeList(f, _)
+                if f.data_type() == &DataType::Float64 => {}
+            _ => return Err(type_error(name, index, "a list of Float64", data_type)),
+        }
+        match arg {
+            ColumnarValue::Scalar(scalar) => Ok(ListParam {
+                array: scalar.to_array()?,
+                scalar: true,
+            }),
+            ColumnarValue::Array(array) => Ok(ListParam {
+                array: array.clone(),
+                scalar: false,
+            }),
+        }
+    }
+
+    /// The values in row `i`, as for [`float64_row`].
+    pub fn row(&self, i: usize) -> Result<Option<Float64Array>, DataFusionError> {
+        float64_row(self.array.as_ref(), if self.scalar { 0 } else { i })
+    }
+}
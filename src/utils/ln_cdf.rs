@@ -0,0 +1,595 @@
+use std::f64::consts::{LN_2, PI};
+
+use datafusion::error::DataFusionError;
+use statrs::consts::LN_SQRT_2PI;
+use statrs::distribution::{
+    Bernoulli, Beta, Binomial, Categorical, Cauchy, Chi, ChiSquared, ContinuousCDF, Dirac,
+    Discrete, DiscreteCDF, DiscreteUniform, Erlang, Exp, FisherSnedecor, Gamma, Geometric, Gumbel,
+    Hypergeometric, InverseGamma, Laplace, LogNormal, NegativeBinomial, Normal, Pareto, Poisson,
+    StudentsT, Triangular, Uniform, Weibull,
+};
+use statrs::function::beta::ln_beta;
+use statrs::function::gamma::ln_gamma;
+use statrs::statistics::{Distribution, Max, Min};
+
+use super::factory2f::Factory2F;
+
+/// The logarithms of the CDF and SF, accurate where the CDF and SF themselves underflow.
+pub trait LnCdf<K> {
+    fn ln_cdf(&self, x: K) -> f64;
+    fn ln_sf(&self, x: K) -> f64;
+}
+
+/// Below this a probability from statrs is taken to have lost precision to underflow.
+const TINY: f64 = 1e-280;
+
+const EPS: f64 = f64::EPSILON;
+
+const MAX_ITER: usize = 10_000;
+
+/// `ln(p)` for a probability `p` with complement `q`, both computed directly. Near one
+/// the complement is more accurate, and in the far tail `tail` computes the logarithm
+/// some other way.
+fn ln_prob(p: f64, q: f64, tail: impl FnOnce() -> f64) -> f64 {
+    if p > 0.5 {
+        (-q).ln_1p()
+    } else if p >= TINY || p.is_nan() {
+        p.ln()
+    } else {
+        let ln_p = tail();
+        if ln_p.is_nan() { p.ln() } else { ln_p }
+    }
+}
+
+/// `ln(1 - exp(ln_q))`, the log of the complement of a probability given by its log.
+fn ln_complement(ln_q: f64) -> f64 {
+    if ln_q > -LN_2 {
+        (-ln_q.exp_m1()).ln()
+    } else {
+        (-ln_q.exp()).ln_1p()
+    }
+}
+
+macro_rules! impl_ln_cdf_by_log {
+    ($k:ty: $($dist:ty),+) => {
+        $(
+            impl LnCdf<$k> for $dist {
+                fn ln_cdf(&self, x: $k) -> f64 {
+                    let p = self.cdf(x);
+                    ln_prob(p, self.sf(x), || p.ln())
+                }
+
+                fn ln_sf(&self, x: $k) -> f64 {
+                    let p = self.sf(x);
+                    ln_prob(p, self.cdf(x), || p.ln())
+                }
+            }
+        )+
+    };
+}
+
+impl_ln_cdf_by_log!(f64: Dirac, Triangular, Uniform);
+impl_ln_cdf_by_log!(u64: Bernoulli, Categorical);
+impl_ln_cdf_by_log!(i64: DiscreteUniform);
+
+impl LnCdf<f64> for Exp {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        (-(-self.rate() * x).exp_m1()).ln()
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        -self.rate() * x
+    }
+}
+
+impl LnCdf<f64> for Weibull {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        (-(-(x / self.scale()).powf(self.shape())).exp_m1()).ln()
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        -(x / self.scale()).powf(self.shape())
+    }
+}
+
+impl LnCdf<f64> for Gumbel {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        -(-(x - self.location()) / self.scale()).exp()
+    }
+
+    /// `ln(1 - exp(-t))` with `t = exp(-z)`, which is `-z - t/2` once `t` is too small to
+    /// tell `1 - exp(-t)` from `t`, and before `t` underflows.
+    fn ln_sf(&self, x: f64) -> f64 {
+        let z = (x - self.location()) / self.scale();
+        let t = (-z).exp();
+        if t < EPS {
+            -z - t / 2.0
+        } else {
+            ln_complement(-t)
+        }
+    }
+}
+
+impl LnCdf<f64> for Pareto {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        ln_complement(self.ln_sf(x))
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x <= self.scale() {
+            return 0.0;
+        }
+        self.shape() * (self.scale() / x).ln()
+    }
+}
+
+impl LnCdf<f64> for Laplace {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        let z = (x - self.location()) / self.scale();
+        if z < 0.0 {
+            z - LN_2
+        } else {
+            (-0.5 * (-z).exp()).ln_1p()
+        }
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        let z = (x - self.location()) / self.scale();
+        if z > 0.0 {
+            -z - LN_2
+        } else {
+            (-0.5 * z.exp()).ln_1p()
+        }
+    }
+}
+
+impl LnCdf<f64> for Cauchy {
+    /// `atan2(1, -z) / π` is the CDF without the cancellation in `1/2 + atan(z) / π`.
+    fn ln_cdf(&self, x: f64) -> f64 {
+        let z = (x - self.location()) / self.scale();
+        (1.0f64.atan2(-z) / PI).ln()
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        let z = (x - self.location()) / self.scale();
+        (1.0f64.atan2(z) / PI).ln()
+    }
+}
+
+/// `ln(sf(z))` of the standard normal distribution for large `z`, from its density and
+/// the continued fraction for Mills' ratio.
+fn ln_normal_tail(z: f64) -> f64 {
+    if z == f64::INFINITY {
+        return f64::NEG_INFINITY;
+    }
+    let mut t = z;
+    for n in (1..=100).rev() {
+        t = z + n as f64 / t;
+    }
+    -0.5 * z * z - LN_SQRT_2PI - t.ln()
+}
+
+impl LnCdf<f64> for Normal {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        let z = (x - self.mean().unwrap()) / self.std_dev().unwrap();
+        ln_prob(self.cdf(x), self.sf(x), || ln_normal_tail(-z))
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        let z = (x - self.mean().unwrap()) / self.std_dev().unwrap();
+        ln_prob(self.sf(x), self.cdf(x), || ln_normal_tail(z))
+    }
+}
+
+/// A log-normal distribution with its parameters μ and σ, which statrs keeps private.
+/// Recovering them from the median and entropy cancels badly when |μ| ≫ σ, and the
+/// median overflows for large μ, so the tails take them from the arguments instead.
+#[derive(Debug)]
+pub struct LogNormalParams {
+    pub dist: LogNormal,
+    pub mu: f64,
+    pub sigma: f64,
+}
+
+impl Factory2F for LogNormalParams {
+    fn make(p1: f64, p2: f64) -> Result<Self, DataFusionError> {
+        Ok(LogNormalParams {
+            dist: LogNormal::make(p1, p2)?,
+            mu: p1,
+            sigma: p2,
+        })
+    }
+}
+
+impl LogNormalParams {
+    /// The standardized log of `x`.
+    fn z(&self, x: f64) -> f64 {
+        (x.ln() - self.mu) / self.sigma
+    }
+}
+
+impl LnCdf<f64> for LogNormalParams {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        ln_prob(self.dist.cdf(x), self.dist.sf(x), || {
+            ln_normal_tail(-self.z(x))
+        })
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        ln_prob(self.dist.sf(x), self.dist.cdf(x), || {
+            ln_normal_tail(self.z(x))
+        })
+    }
+}
+
+/// `ln P(a, x)` by its series, converging for `x < a + 1`.
+fn ln_gamma_p_series(a: f64, x: f64) -> f64 {
+    let mut ap = a;
+    let mut term = 1.0 / a;
+    let mut sum = term;
+    for _ in 0..MAX_ITER {
+        ap += 1.0;
+        term *= x / ap;
+        sum += term;
+        if term < sum * EPS {
+            break;
+        }
+    }
+    -x + a * x.ln() - ln_gamma(a) + sum.ln()
+}
+
+/// `ln Q(a, x)` by its continued fraction, converging for `x > a + 1`.
+fn ln_gamma_q_fraction(a: f64, x: f64) -> f64 {
+    let h = lentz(x + 1.0 - a, |i| {
+        let i = i as f64;
+        (-i * (i - a), x + 2.0 * i + 1.0 - a)
+    });
+    -x + a * x.ln() - ln_gamma(a) - h.ln()
+}
+
+/// The log of the regularized lower incomplete gamma function `P(a, x)`.
+pub fn ln_gamma_lr(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        f64::NEG_INFINITY
+    } else if x == f64::INFINITY {
+        0.0
+    } else if x < a + 1.0 {
+        ln_gamma_p_series(a, x)
+    } else {
+        ln_complement(ln_gamma_q_fraction(a, x))
+    }
+}
+
+/// The log of the regularized upper incomplete gamma function `Q(a, x)`.
+pub fn ln_gamma_ur(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        0.0
+    } else if x == f64::INFINITY {
+        f64::NEG_INFINITY
+    } else if x < a + 1.0 {
+        ln_complement(ln_gamma_p_series(a, x))
+    } else {
+        ln_gamma_q_fraction(a, x)
+    }
+}
+
+/// The denominator `b0 + a1 / (b1 + a2 / (b2 + ...))` of a continued fraction, by the
+/// modified Lentz method, with `terms(i)` giving `(ai, bi)`.
+fn lentz(b0: f64, terms: impl Fn(usize) -> (f64, f64)) -> f64 {
+    const FPMIN: f64 = f64::MIN_POSITIVE / EPS;
+    let guard = |v: f64| if v.abs() < FPMIN { FPMIN } else { v };
+    let mut f = guard(b0);
+    let mut c = f;
+    let mut d = 0.0;
+    for i in 1..MAX_ITER {
+        let (a, b) = terms(i);
+        d = 1.0 / guard(b + a * d);
+        c = guard(b + a / c);
+        let delta = c * d;
+        f *= delta;
+        if (delta - 1.0).abs() < EPS {
+            break;
+        }
+    }
+    f
+}
+
+/// `ln I_x(a, b)` by its continued fraction, converging for `x < (a + 1) / (a + b + 2)`.
+fn ln_beta_fraction(a: f64, b: f64, x: f64) -> f64 {
+    let h = lentz(1.0, |i| {
+        let m = (i / 2) as f64;
+        let numerator = if i % 2 == 1 {
+            -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0))
+        } else {
+            m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m))
+        };
+        (numerator, 1.0)
+    });
+    a * x.ln() + b * (-x).ln_1p() - ln_beta(a, b) - a.ln() - h.ln()
+}
+
+/// The log of the regularized incomplete beta function `I_x(a, b)`.
+pub fn ln_beta_reg(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        f64::NEG_INFINITY
+    } else if x >= 1.0 {
+        0.0
+    } else if x < (a + 1.0) / (a + b + 2.0) {
+        ln_beta_fraction(a, b, x)
+    } else {
+        ln_complement(ln_beta_fraction(b, a, 1.0 - x))
+    }
+}
+
+// statrs panics when the argument it passes to the incomplete gamma function underflows to
+// zero, so a positive `x` that small is treated as zero.
+impl LnCdf<f64> for Gamma {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if self.rate() * x <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        ln_prob(self.cdf(x), self.sf(x), || {
+            ln_gamma_lr(self.shape(), self.rate() * x)
+        })
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        if self.rate() * x <= 0.0 {
+            return 0.0;
+        }
+        ln_prob(self.sf(x), self.cdf(x), || {
+            ln_gamma_ur(self.shape(), self.rate() * x)
+        })
+    }
+}
+
+impl LnCdf<f64> for Erlang {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if self.rate() * x <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        ln_prob(self.cdf(x), self.sf(x), || {
+            ln_gamma_lr(self.shape() as f64, self.rate() * x)
+        })
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        if self.rate() * x <= 0.0 {
+            return 0.0;
+        }
+        ln_prob(self.sf(x), self.cdf(x), || {
+            ln_gamma_ur(self.shape() as f64, self.rate() * x)
+        })
+    }
+}
+
+impl LnCdf<f64> for ChiSquared {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x / 2.0 <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        ln_prob(self.cdf(x), self.sf(x), || {
+            ln_gamma_lr(self.freedom() / 2.0, x / 2.0)
+        })
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x / 2.0 <= 0.0 {
+            return 0.0;
+        }
+        ln_prob(self.sf(x), self.cdf(x), || {
+            ln_gamma_ur(self.freedom() / 2.0, x / 2.0)
+        })
+    }
+}
+
+impl LnCdf<f64> for Chi {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x * x / 2.0 == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        let k = self.freedom() as f64;
+        ln_prob(self.cdf(x), self.sf(x), || {
+            ln_gamma_lr(k / 2.0, x * x / 2.0)
+        })
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x * x / 2.0 == 0.0 {
+            return 0.0;
+        }
+        let k = self.freedom() as f64;
+        ln_prob(self.sf(x), self.cdf(x), || {
+            ln_gamma_ur(k / 2.0, x * x / 2.0)
+        })
+    }
+}
+
+impl LnCdf<f64> for InverseGamma {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        ln_prob(self.cdf(x), self.sf(x), || {
+            ln_gamma_ur(self.shape(), self.rate() / x)
+        })
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        ln_prob(self.sf(x), self.cdf(x), || {
+            ln_gamma_lr(self.shape(), self.rate() / x)
+        })
+    }
+}
+
+impl LnCdf<f64> for Beta {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        let (a, b) = (self.shape_a(), self.shape_b());
+        ln_prob(self.cdf(x), self.sf(x), || ln_beta_reg(a, b, x))
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        let (a, b) = (self.shape_a(), self.shape_b());
+        ln_prob(self.sf(x), self.cdf(x), || ln_beta_reg(b, a, 1.0 - x))
+    }
+}
+
+/// `ln(sf(t))` of the standard Student's t distribution with `v` degrees of freedom, for
+/// `t > 0`.
+fn ln_students_t_tail(t: f64, v: f64) -> f64 {
+    if v == f64::INFINITY {
+        return ln_normal_tail(t);
+    }
+    ln_beta_reg(v / 2.0, 0.5, v / (v + t * t)) - LN_2
+}
+
+impl LnCdf<f64> for StudentsT {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        let t = (x - self.location()) / self.scale();
+        ln_prob(self.cdf(x), self.sf(x), || {
+            ln_students_t_tail(-t, self.freedom())
+        })
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        let t = (x - self.location()) / self.scale();
+        ln_prob(self.sf(x), self.cdf(x), || {
+            ln_students_t_tail(t, self.freedom())
+        })
+    }
+}
+
+impl LnCdf<f64> for FisherSnedecor {
+    fn ln_cdf(&self, x: f64) -> f64 {
+        let (d1, d2) = (self.freedom_1(), self.freedom_2());
+        ln_prob(self.cdf(x), self.sf(x), || {
+            ln_beta_reg(d1 / 2.0, d2 / 2.0, d1 * x / (d1 * x + d2))
+        })
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        let (d1, d2) = (self.freedom_1(), self.freedom_2());
+        ln_prob(self.sf(x), self.cdf(x), || {
+            ln_beta_reg(d2 / 2.0, d1 / 2.0, d2 / (d2 + d1 * x))
+        })
+    }
+}
+
+impl LnCdf<u64> for Geometric {
+    fn ln_cdf(&self, x: u64) -> f64 {
+        ln_complement(self.ln_sf(x))
+    }
+
+    fn ln_sf(&self, x: u64) -> f64 {
+        if x == 0 {
+            return 0.0;
+        }
+        x as f64 * (-self.p()).ln_1p()
+    }
+}
+
+impl LnCdf<u64> for Poisson {
+    fn ln_cdf(&self, x: u64) -> f64 {
+        ln_prob(self.cdf(x), self.sf(x), || {
+            ln_gamma_ur(x as f64 + 1.0, self.lambda())
+        })
+    }
+
+    fn ln_sf(&self, x: u64) -> f64 {
+        ln_prob(self.sf(x), self.cdf(x), || {
+            ln_gamma_lr(x as f64 + 1.0, self.lambda())
+        })
+    }
+}
+
+impl LnCdf<u64> for Binomial {
+    fn ln_cdf(&self, x: u64) -> f64 {
+        if x >= self.n() {
+            return 0.0;
+        }
+        let (n, k) = (self.n() as f64, x as f64);
+        ln_prob(self.cdf(x), self.sf(x), || {
+            ln_beta_reg(n - k, k + 1.0, 1.0 - self.p())
+        })
+    }
+
+    fn ln_sf(&self, x: u64) -> f64 {
+        if x >= self.n() {
+            return f64::NEG_INFINITY;
+        }
+        let (n, k) = (self.n() as f64, x as f64);
+        ln_prob(self.sf(x), self.cdf(x), || {
+            ln_beta_reg(k + 1.0, n - k, self.p())
+        })
+    }
+}
+
+impl LnCdf<u64> for NegativeBinomial {
+    fn ln_cdf(&self, x: u64) -> f64 {
+        let k = x as f64;
+        ln_prob(self.cdf(x), self.sf(x), || {
+            ln_beta_reg(self.r(), k + 1.0, self.p())
+        })
+    }
+
+    fn ln_sf(&self, x: u64) -> f64 {
+        let k = x as f64;
+        ln_prob(self.sf(x), self.cdf(x), || {
+            ln_beta_reg(k + 1.0, self.r(), 1.0 - self.p())
+        })
+    }
+}
+
+/// The log of the sum of the terms whose logs are `ln_terms`, for a tail beyond the mode
+/// where they start at the largest and decrease, stopping once they no longer count.
+fn ln_tail_sum(mut ln_terms: impl Iterator<Item = f64>) -> f64 {
+    let Some(first) = ln_terms.next() else {
+        return f64::NEG_INFINITY;
+    };
+    let mut sum = 1.0;
+    for ln_term in ln_terms {
+        let term = (ln_term - first).exp();
+        sum += term;
+        if term < sum * EPS {
+            break;
+        }
+    }
+    first + sum.ln()
+}
+
+impl LnCdf<u64> for Hypergeometric {
+    fn ln_cdf(&self, x: u64) -> f64 {
+        let lo = Min::min(self);
+        ln_prob(self.cdf(x), self.sf(x), || {
+            ln_tail_sum((lo..=x).rev().map(|k| self.ln_pmf(k)))
+        })
+    }
+
+    fn ln_sf(&self, x: u64) -> f64 {
+        let hi = Max::max(self);
+        ln_prob(self.sf(x), self.cdf(x), || {
+            ln_tail_sum((x.saturating_add(1)..=hi).map(|k| self.ln_pmf(k)))
+        })
+    }
+}
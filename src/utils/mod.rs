@@ -1,27 +1,83 @@
+pub mod cache;
+pub mod coerce;
 pub mod continuous1f1u;
 pub mod continuous1f1u1f;
 pub mod continuous2f;
+pub mod continuous2l;
 pub mod continuous3f;
+pub mod continuous3l;
+pub mod continuous3l1f;
 pub mod continuous4f;
+pub mod discrete1lu1l;
 pub mod discrete1u1f;
+pub mod discrete1u1l;
 pub mod discrete1u2f;
 pub mod discrete2u1f;
+pub mod discrete3i;
 pub mod discrete4u;
+pub mod empirical;
 pub mod evaluator1f1u;
 pub mod evaluator1f1u1f;
+pub mod evaluator1lu1l;
 pub mod evaluator1u1f;
+pub mod evaluator1u1l;
 pub mod evaluator1u2f;
 pub mod evaluator2f;
+pub mod evaluator2l;
 pub mod evaluator2u1f;
 pub mod evaluator3f;
+pub mod evaluator3i;
+pub mod evaluator3l;
+pub mod evaluator3l1f;
 pub mod evaluator4f;
 pub mod evaluator4u;
 pub mod factory1f;
-pub mod factory1f1u;
+pub mod factory1l;
+pub mod factory1l1u;
 pub mod factory1u;
 pub mod factory1u1f;
 pub mod factory2f;
 pub mod factory2i;
+pub mod factory2l;
+pub mod factory2l1f;
 pub mod factory3f;
 pub mod factory3u;
-pub mod register;
\ No newline at end of file
+pub mod family;
+pub mod fit;
+pub mod hazard;
+pub mod interval;
+pub mod interval1f;
+pub mod interval1l;
+pub mod interval1u;
+pub mod interval1u1f;
+pub mod interval2f;
+pub mod interval2i;
+pub mod interval3f;
+pub mod interval3u;
+pub mod invalid_params;
+pub mod inverse_cdf;
+pub mod inverse_sf;
+pub mod list;
+pub mod ln_cdf;
+pub mod null_as_nan;
+pub mod param;
+pub mod random;
+pub mod random1f;
+pub mod random1l;
+pub mod random1u;
+pub mod random1u1f;
+pub mod random2f;
+pub mod random2i;
+pub mod random3f;
+pub mod random3u;
+pub mod register;
+pub mod statistic;
+pub mod statistic1f;
+pub mod statistic1l;
+pub mod statistic1u;
+pub mod statistic1u1f;
+pub mod statistic2f;
+pub mod statistic2i;
+pub mod statistic3f;
+pub mod statistic3u;
+pub mod wrap;
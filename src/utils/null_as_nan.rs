@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, Float64Array},
+        datatypes::{DataType, FieldRef},
+    },
+    common::cast::as_float64_array,
+    error::DataFusionError,
+    logical_expr::{
+        ColumnarValue, Documentation, Expr, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDF,
+        ScalarUDFImpl, Signature,
+        interval_arithmetic::Interval,
+        simplify::{ExprSimplifyResult, SimplifyInfo},
+        sort_properties::{ExprProperties, SortProperties},
+    },
+    scalar::ScalarValue,
+};
+
+use super::wrap::rewrap;
+
+/// Wraps a function so that NULL `Float64` results come back as NaN, which is how the
+/// functions treated NULL arguments before they propagated NULLs.
+///
+/// Everything but the evaluation is left to the wrapped function, including
+/// [`ScalarUDFImpl::simplify`], whose result is wrapped in turn.
+#[derive(Debug)]
+pub struct NullAsNan {
+    inner: Arc<ScalarUDF>,
+}
+
+impl NullAsNan {
+    pub fn new(inner: Arc<ScalarUDF>) -> Self {
+        NullAsNan { inner }
+    }
+}
+
+impl ScalarUDFImpl for NullAsNan {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn display_name(&self, args: &[Expr]) -> datafusion::error::Result<String> {
+        self.inner.display_name(args)
+    }
+
+    fn schema_name(&self, args: &[Expr]) -> datafusion::error::Result<String> {
+        self.inner.schema_name(args)
+    }
+
+    fn aliases(&self) -> &[String] {
+        self.inner.aliases()
+    }
+
+    fn signature(&self) -> &Signature {
+        self.inner.signature()
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        self.inner.coerce_types(arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        self.inner.return_type(arg_types)
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> datafusion::error::Result<FieldRef> {
+        self.inner.return_field_from_args(args)
+    }
+
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> datafusion::error::Result<ExprSimplifyResult> {
+        let result = self.inner.simplify(args, info)?;
+        Ok(rewrap(self.name(), result, |inner| {
+            ScalarUDF::from(NullAsNan::new(inner))
+        }))
+    }
+
+    fn short_circuits(&self) -> bool {
+        self.inner.short_circuits()
+    }
+
+    fn evaluate_bounds(&self, input: &[&Interval]) -> datafusion::error::Result<Interval> {
+        self.inner.evaluate_bounds(input)
+    }
+
+    fn propagate_constraints(
+        &self,
+        interval: &Interval,
+        inputs: &[&Interval],
+    ) -> datafusion::error::Result<Option<Vec<Interval>>> {
+        self.inner.propagate_constraints(interval, inputs)
+    }
+
+    fn output_ordering(
+        &self,
+        inputs: &[ExprProperties],
+    ) -> datafusion::error::Result<SortProperties> {
+        self.inner.output_ordering(inputs)
+    }
+
+    fn preserves_lex_ordering(&self, inputs: &[ExprProperties]) -> datafusion::error::Result<bool> {
+        self.inner.preserves_lex_ordering(inputs)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.inner.documentation()
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        match self.inner.invoke_with_args(args)? {
+            ColumnarValue::Array(array) if array.data_type() == &DataType::Float64 => {
+                let array: Float64Array = as_float64_array(&array)?
+                    .iter()
+                    .map(|v| Some(v.unwrap_or(f64::NAN)))
+                    .collect();
+                Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+            }
+            ColumnarValue::Scalar(ScalarValue::Float64(None)) => {
+                Ok(ColumnarValue::Scalar(ScalarValue::Float64(Some(f64::NAN))))
+            }
+            result => Ok(result),
+        }
+    }
+}
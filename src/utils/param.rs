@@ -0,0 +1,153 @@
+use datafusion::{
+    arrow::{
+        array::{Array, AsArray, PrimitiveArray},
+        datatypes::{ArrowPrimitiveType, UInt64Type},
+    },
+    error::DataFusionError,
+    logical_expr::ColumnarValue,
+};
+
+use super::coerce::count_arg;
+
+/// Argument `index` of the function `name`, or an error if there are too few.
+pub fn arg<'a>(
+    name: &str,
+    args: &'a [ColumnarValue],
+    index: usize,
+) -> Result<&'a ColumnarValue, DataFusionError> {
+    args.get(index).ok_or_else(|| {
+        DataFusionError::Execution(format!(
+            "{name} expects at least {} arguments, got {}",
+            index + 1,
+            args.len()
+        ))
+    })
+}
+
+/// The error for argument `index` of the function `name` having an unexpected type.
+pub fn type_error(
+    name: &str,
+    index: usize,
+    expected: impl std::fmt::Display,
+    actual: impl std::fmt::Display,
+) -> DataFusionError {
+    DataFusionError::Execution(format!(
+        "{name}: argument {} must be {expected}, got {actual}",
+        index + 1
+    ))
+}
+
+/// Invalid distribution parameters, either rejected by a statrs constructor, which is kept
+/// as the [`source`](std::error::Error::source), or accepted by statrs but not by the
+/// functions, such as a range with more values than fit in the result type.
+///
+/// It is returned as [`DataFusionError::External`], the variant reserved for invalid
+/// parameters, see [`is_invalid_params`].
+#[derive(Debug)]
+pub struct InvalidParamsError {
+    msg: String,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl std::fmt::Display for InvalidParamsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.msg)
+    }
+}
+
+impl std::error::Error for InvalidParamsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.as_deref().map(|e| e as _)
+    }
+}
+
+/// The error for invalid distribution parameters described by `msg`.
+pub fn invalid_params(msg: String) -> DataFusionError {
+    DataFusionError::External(Box::new(InvalidParamsError { msg, source: None }))
+}
+
+/// Whether `e` comes from building a distribution from invalid parameters, rather than
+/// from the types, number or shape of the arguments.
+pub fn is_invalid_params(e: &DataFusionError) -> bool {
+    matches!(e, DataFusionError::External(_))
+}
+
+/// Prefix an execution error, or an error for invalid parameters, with the function
+/// `name`. A statrs error is wrapped in an [`InvalidParamsError`] whose source it is.
+pub fn named(name: &str, e: DataFusionError) -> DataFusionError {
+    match e {
+        DataFusionError::Execution(msg) => DataFusionError::Execution(format!("{name}: {msg}")),
+        DataFusionError::External(e) => {
+            let e = match e.downcast::<InvalidParamsError>() {
+                Ok(e) => InvalidParamsError {
+                    msg: format!("{name}: {}", e.msg),
+                    source: e.source,
+                },
+                Err(e) => InvalidParamsError {
+                    msg: format!("{name}: {e}"),
+                    source: Some(e),
+                },
+            };
+            DataFusionError::External(Box::new(e))
+        }
+        e => e,
+    }
+}
+
+/// A primitive argument that is either a literal, read once, or a column, read row by row,
+/// so that literal arguments are never expanded into arrays.
+pub enum Param<T: ArrowPrimitiveType> {
+    Scalar(Option<T::Native>),
+    Array(PrimitiveArray<T>),
+}
+
+impl<T: ArrowPrimitiveType> Param<T> {
+    /// Argument `index` of the function `name`.
+    pub fn new(name: &str, args: &[ColumnarValue], index: usize) -> Result<Self, DataFusionError> {
+        Self::from_value(name, index, arg(name, args, index)?)
+    }
+
+    fn from_value(name: &str, index: usize, arg: &ColumnarValue) -> Result<Self, DataFusionError> {
+        match arg {
+            ColumnarValue::Scalar(scalar) => {
+                let array = scalar.to_array()?;
+                let array = Self::downcast(name, index, array.as_ref())?;
+                Ok(Param::Scalar(array.is_valid(0).then(|| array.value(0))))
+            }
+            ColumnarValue::Array(array) => Ok(Param::Array(
+                Self::downcast(name, index, array.as_ref())?.clone(),
+            )),
+        }
+    }
+
+    fn downcast<'a>(
+        name: &str,
+        index: usize,
+        array: &'a dyn Array,
+    ) -> Result<&'a PrimitiveArray<T>, DataFusionError> {
+        array
+            .as_primitive_opt::<T>()
+            .ok_or_else(|| type_error(name, index, T::DATA_TYPE, array.data_type()))
+    }
+
+    /// The value in row `i`, or `None` if it is null.
+    pub fn get(&self, i: usize) -> Option<T::Native> {
+        match self {
+            Param::Scalar(value) => *value,
+            Param::Array(array) => array.is_valid(i).then(|| array.value(i)),
+        }
+    }
+}
+
+impl Param<UInt64Type> {
+    /// Count argument `index` of the function `name`, which may have been coerced to a
+    /// signed type; see [`count_arg`].
+    pub fn count(
+        name: &str,
+        args: &[ColumnarValue],
+        index: usize,
+    ) -> Result<Self, DataFusionError> {
+        let arg = count_arg(name, index + 1, arg(name, args, index)?)?;
+        Self::from_value(name, index, &arg)
+    }
+}
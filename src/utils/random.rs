@@ -0,0 +1,93 @@
+use datafusion::{
+    arrow::datatypes::{DataType, Int64Type},
+    error::DataFusionError,
+    logical_expr::ColumnarValue,
+};
+use rand::{Rng, SeedableRng, rngs::StdRng, rngs::ThreadRng};
+use statrs::distribution::{
+    Bernoulli, Beta, Binomial, Categorical, Cauchy, Chi, ChiSquared, Dirac, DiscreteUniform,
+    Erlang, Exp, FisherSnedecor, Gamma, Geometric, Gumbel, Hypergeometric, InverseGamma, Laplace,
+    LogNormal, NegativeBinomial, Normal, Pareto, Poisson, StudentsT, Triangular, Uniform, Weibull,
+};
+
+use super::coerce::{Arg, coerce_args};
+use super::param::Param;
+use super::statistic::{Location, Native};
+
+/// Random variates of a univariate distribution, drawn as the same type as its other
+/// values, see [`Location`].
+pub trait Random: Location {
+    fn random<R: Rng + ?Sized>(&self, rng: &mut R) -> Native<Self::Value>;
+}
+
+macro_rules! impl_random {
+    ($k:ty: $($dist:ty),+) => {
+        $(
+            impl Random for $dist {
+                fn random<R: Rng + ?Sized>(&self, rng: &mut R) -> $k {
+                    rand::distributions::Distribution::<$k>::sample(self, rng)
+                }
+            }
+        )+
+    };
+}
+
+impl_random!(
+    f64: Beta, Cauchy, Chi, ChiSquared, Dirac, Erlang, Exp, FisherSnedecor, Gamma, Gumbel,
+    InverseGamma, Laplace, LogNormal, Normal, Pareto, StudentsT, Triangular, Uniform, Weibull
+);
+impl_random!(u64: Binomial, Categorical, Geometric, Hypergeometric, NegativeBinomial, Poisson);
+impl_random!(i64: DiscreteUniform);
+
+impl Random for Bernoulli {
+    /// statrs draws Bernoulli variates as `bool`.
+    fn random<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        rand::distributions::Distribution::<bool>::sample(self, rng) as u64
+    }
+}
+
+/// Coerce the argument types of the random variate function `name`, which takes the
+/// distribution parameters `params` and an optional integer seed.
+pub fn coerce_random_args(
+    name: &str,
+    arg_types: &[DataType],
+    params: &[Arg],
+) -> Result<Vec<DataType>, DataFusionError> {
+    if arg_types.len() == params.len() + 1 {
+        coerce_args(name, arg_types, &[params, &[Arg::Integer]].concat())
+    } else {
+        coerce_args(name, arg_types, params)
+    }
+}
+
+/// Where the random variates of a batch come from.
+pub enum Seed {
+    /// Without a seed argument, the thread RNG.
+    Unseeded(ThreadRng),
+    /// With a seed, literal or not, an RNG seeded with the seed of each row, so the value
+    /// of a row depends only on its parameters and seed, whatever the partitioning and
+    /// batching of the query. A function is not told the partition or row it evaluates,
+    /// so this is the only way for a seed to be reproducible row by row.
+    Rows(Param<Int64Type>),
+}
+
+impl Seed {
+    /// The seed argument `index` of the function `name`, if it was given.
+    pub fn new(name: &str, args: &[ColumnarValue], index: usize) -> Result<Self, DataFusionError> {
+        if index < args.len() {
+            Ok(Seed::Rows(Param::new(name, args, index)?))
+        } else {
+            Ok(Seed::Unseeded(rand::thread_rng()))
+        }
+    }
+
+    /// A variate of `d` for row `i`, or `None` if the seed of the row is null.
+    pub fn sample<D: Random>(&mut self, d: &D, i: usize) -> Option<Native<D::Value>> {
+        match self {
+            Seed::Unseeded(rng) => Some(d.random(rng)),
+            Seed::Rows(seed) => seed
+                .get(i)
+                .map(|s| d.random(&mut StdRng::seed_from_u64(s as u64))),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, PrimitiveArray},
+        datatypes::{ArrowPrimitiveType, DataType},
+    },
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
+};
+
+use super::cache::LastDist;
+use super::coerce::Arg;
+use super::factory1l::Factory1L;
+use super::list::ListParam;
+use super::param::named;
+use super::random::{Random, Seed, coerce_random_args};
+
+#[derive(Debug)]
+pub struct Random1L<D: Factory1L + Random> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1L + Random> Random1L<D> {
+    pub fn new(name: &str) -> Self {
+        Random1L {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Volatile),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<D: Factory1L + Random> ScalarUDFImpl for Random1L<D> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_random_args(&self.name, arg_types, &[Arg::FloatList])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(D::Value::DATA_TYPE)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let p = ListParam::new(&self.name, &args.args, 0)?;
+        let mut seed = Seed::new(&self.name, &args.args, 1)?;
+
+        let mut cache = LastDist::<Vec<f64>, D>::new();
+        let array: PrimitiveArray<D::Value> = (0..args.number_rows)
+            .map(|i| match p.row(i)? {
+                Some(p) => {
+                    let d = cache.get(p.values().as_ref(), || {
+                        D::make(p.values()).map_err(|e| named(&self.name, e))
+                    })?;
+                    Ok(seed.sample(d, i))
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<PrimitiveArray<D::Value>, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
@@ -0,0 +1,75 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, PrimitiveArray},
+        datatypes::{ArrowPrimitiveType, DataType, Float64Type},
+    },
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
+};
+
+use super::cache::LastDist;
+use super::coerce::Arg;
+use super::factory1u1f::Factory1U1F;
+use super::param::{Param, named};
+use super::random::{Random, Seed, coerce_random_args};
+
+#[derive(Debug)]
+pub struct Random1U1F<D: Factory1U1F + Random> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Factory1U1F + Random> Random1U1F<D> {
+    pub fn new(name: &str) -> Self {
+        Random1U1F {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Volatile),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<D: Factory1U1F + Random> ScalarUDFImpl for Random1U1F<D> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_random_args(&self.name, arg_types, &[Arg::Count, Arg::Float])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(D::Value::DATA_TYPE)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let p1 = Param::count(&self.name, &args.args, 0)?;
+        let p2 = Param::<Float64Type>::new(&self.name, &args.args, 1)?;
+        let mut seed = Seed::new(&self.name, &args.args, 2)?;
+
+        let mut cache = LastDist::new();
+        let array: PrimitiveArray<D::Value> = (0..args.number_rows)
+            .map(|i| match (p1.get(i), p2.get(i)) {
+                (Some(p1), Some(p2)) => {
+                    let d = cache.get(&(p1, p2), || {
+                        D::make(p1, p2).map_err(|e| named(&self.name, e))
+                    })?;
+                    Ok(seed.sample(d, i))
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<PrimitiveArray<D::Value>, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
@@ -1,18 +1,90 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
-use datafusion::{error::DataFusionError, execution::FunctionRegistry, logical_expr::ScalarUDF};
+use datafusion::{
+    error::DataFusionError,
+    execution::FunctionRegistry,
+    logical_expr::{AggregateUDF, ScalarUDF, WindowUDF, planner::ExprPlanner},
+};
 use log::warn;
 
-pub fn register(registry: &mut dyn FunctionRegistry, functions: Vec<ScalarUDF>) -> Result<(), DataFusionError> {
-    functions
-        .into_iter()
-        .map(|f| Arc::new(f))
-        .try_for_each(|udf| {
-            let existing_udf = registry.register_udf(udf)?;
-            if let Some(existing_udf) = existing_udf {
-                warn!("Overwrite existing UDF: {}", existing_udf.name());
-            }
-            Ok(()) as Result<(), DataFusionError>
-        })?;
+pub fn register(
+    registry: &mut dyn FunctionRegistry,
+    functions: Vec<ScalarUDF>,
+) -> Result<(), DataFusionError> {
+    functions.into_iter().map(Arc::new).try_for_each(|udf| {
+        let existing_udf = registry.register_udf(udf)?;
+        if let Some(existing_udf) = existing_udf {
+            warn!("Overwrite existing UDF: {}", existing_udf.name());
+        }
+        Ok(()) as Result<(), DataFusionError>
+    })?;
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn register_aggregates(
+    registry: &mut dyn FunctionRegistry,
+    functions: Vec<AggregateUDF>,
+) -> Result<(), DataFusionError> {
+    functions.into_iter().map(Arc::new).try_for_each(|udaf| {
+        let existing_udaf = registry.register_udaf(udaf)?;
+        if let Some(existing_udaf) = existing_udaf {
+            warn!("Overwrite existing UDAF: {}", existing_udaf.name());
+        }
+        Ok(()) as Result<(), DataFusionError>
+    })?;
+    Ok(())
+}
+
+/// A [`FunctionRegistry`] that just records what is registered with it, so the functions
+/// can be adjusted before being handed on to a real registry.
+#[derive(Default)]
+pub struct Collector {
+    pub udfs: Vec<Arc<ScalarUDF>>,
+    pub udafs: Vec<Arc<AggregateUDF>>,
+}
+
+impl FunctionRegistry for Collector {
+    fn udfs(&self) -> HashSet<String> {
+        self.udfs.iter().map(|udf| udf.name().to_string()).collect()
+    }
+
+    fn udf(&self, name: &str) -> Result<Arc<ScalarUDF>, DataFusionError> {
+        self.udfs
+            .iter()
+            .find(|udf| udf.name() == name)
+            .cloned()
+            .ok_or_else(|| DataFusionError::Plan(format!("no UDF named {name}")))
+    }
+
+    fn udaf(&self, name: &str) -> Result<Arc<AggregateUDF>, DataFusionError> {
+        self.udafs
+            .iter()
+            .find(|udaf| udaf.name() == name)
+            .cloned()
+            .ok_or_else(|| DataFusionError::Plan(format!("no UDAF named {name}")))
+    }
+
+    fn udwf(&self, name: &str) -> Result<Arc<WindowUDF>, DataFusionError> {
+        Err(DataFusionError::Plan(format!("no UDWF named {name}")))
+    }
+
+    fn register_udf(
+        &mut self,
+        udf: Arc<ScalarUDF>,
+    ) -> Result<Option<Arc<ScalarUDF>>, DataFusionError> {
+        self.udfs.push(udf);
+        Ok(None)
+    }
+
+    fn register_udaf(
+        &mut self,
+        udaf: Arc<AggregateUDF>,
+    ) -> Result<Option<Arc<AggregateUDF>>, DataFusionError> {
+        self.udafs.push(udaf);
+        Ok(None)
+    }
+
+    fn expr_planners(&self) -> Vec<Arc<dyn ExprPlanner>> {
+        vec![]
+    }
+}
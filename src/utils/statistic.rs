@@ -0,0 +1,329 @@
+use std::marker::PhantomData;
+
+use datafusion::arrow::datatypes::{ArrowPrimitiveType, Float64Type, Int64Type, UInt64Type};
+use statrs::distribution::{
+    Bernoulli, Beta, Binomial, Categorical, Cauchy, Chi, ChiSquared, Dirac, DiscreteUniform,
+    Erlang, Exp, FisherSnedecor, Gamma, Geometric, Gumbel, Hypergeometric, InverseGamma, Laplace,
+    LogNormal, NegativeBinomial, Normal, Pareto, Poisson, StudentsT, Triangular, Uniform, Weibull,
+};
+use statrs::distribution::{Discrete, DiscreteCDF};
+use statrs::statistics::{DiscreteDistribution, Distribution, Max, Median, Min, Mode};
+
+use super::inverse_cdf::{InverseCdf, discrete_quantile, discrete_quantile_i64};
+
+/// The summary statistics of a univariate distribution, which statrs provides through
+/// [`Distribution`] for most distributions and [`DiscreteDistribution`] for the rest.
+/// `None` means the statistic is undefined for the parameters.
+pub trait Moments: std::fmt::Debug + Send + Sync + 'static {
+    fn mean(&self) -> Option<f64>;
+    fn variance(&self) -> Option<f64>;
+    fn std_dev(&self) -> Option<f64>;
+    fn skewness(&self) -> Option<f64>;
+    fn entropy(&self) -> Option<f64>;
+}
+
+macro_rules! impl_moments {
+    ($statrs:ident: $($dist:ty),+) => {
+        $(
+            impl Moments for $dist {
+                fn mean(&self) -> Option<f64> {
+                    $statrs::mean(self)
+                }
+
+                fn variance(&self) -> Option<f64> {
+                    $statrs::variance(self)
+                }
+
+                fn std_dev(&self) -> Option<f64> {
+                    $statrs::std_dev(self)
+                }
+
+                fn skewness(&self) -> Option<f64> {
+                    $statrs::skewness(self)
+                }
+
+                fn entropy(&self) -> Option<f64> {
+                    $statrs::entropy(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_moments!(
+    Distribution: Bernoulli, Beta, Binomial, Categorical, Cauchy, Chi, ChiSquared, Dirac,
+    DiscreteUniform, Erlang, Exp, FisherSnedecor, Gamma, Geometric, Gumbel, Hypergeometric,
+    InverseGamma, Laplace, LogNormal, Normal, Pareto, Poisson, StudentsT, Triangular, Uniform,
+    Weibull
+);
+impl_moments!(DiscreteDistribution: NegativeBinomial);
+
+/// The mode, median and bounds of the support of a univariate distribution, all values of
+/// the distribution, so `UInt64` for counts, `Int64` for the discrete uniform and `Float64`
+/// otherwise.
+pub trait Location: std::fmt::Debug + Send + Sync + 'static {
+    type Value: ArrowPrimitiveType;
+
+    fn mode(&self) -> Option<Native<Self::Value>>;
+    fn median(&self) -> Native<Self::Value>;
+    fn support_min(&self) -> Native<Self::Value>;
+    fn support_max(&self) -> Native<Self::Value>;
+}
+
+pub type Native<T> = <T as ArrowPrimitiveType>::Native;
+
+/// The median as statrs defines it.
+fn statrs_median<D: Median<f64>>(d: &D) -> f64 {
+    d.median()
+}
+
+/// The median of a continuous distribution for which statrs has no closed form.
+fn continuous_median<D: InverseCdf>(d: &D) -> f64 {
+    d.quantile(0.5)
+}
+
+/// The median of a discrete distribution, as its quantile at one half: statrs' closed forms
+/// are approximations, which can even be negative.
+fn discrete_median<D: DiscreteCDF<u64, f64>>(d: &D) -> u64 {
+    discrete_quantile(d, 0.5)
+}
+
+/// [`discrete_median`] for a distribution over `Int64`.
+fn discrete_median_i64<D: DiscreteCDF<i64, f64>>(d: &D) -> i64 {
+    discrete_quantile_i64(d, 0.5)
+}
+
+macro_rules! impl_location {
+    ($value:ty, $median:ident: $($dist:ty),+) => {
+        $(
+            impl Location for $dist {
+                type Value = $value;
+
+                fn mode(&self) -> Option<Native<$value>> {
+                    Mode::mode(self)
+                }
+
+                fn median(&self) -> Native<$value> {
+                    $median(self)
+                }
+
+                fn support_min(&self) -> Native<$value> {
+                    Min::min(self)
+                }
+
+                fn support_max(&self) -> Native<$value> {
+                    Max::max(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_location!(
+    Float64Type, statrs_median: Cauchy, ChiSquared, Dirac, Exp, Laplace, LogNormal, Normal,
+    Pareto, StudentsT, Triangular, Uniform, Weibull
+);
+impl_location!(
+    Float64Type, continuous_median: Beta, Chi, Erlang, FisherSnedecor, Gamma, InverseGamma
+);
+impl_location!(
+    UInt64Type, discrete_median: Bernoulli, Binomial, Geometric, Hypergeometric, Poisson
+);
+impl_location!(Int64Type, discrete_median_i64: DiscreteUniform);
+
+impl Location for Gumbel {
+    type Value = Float64Type;
+
+    fn mode(&self) -> Option<f64> {
+        Some(Mode::mode(self))
+    }
+
+    fn median(&self) -> f64 {
+        Median::median(self)
+    }
+
+    fn support_min(&self) -> f64 {
+        Min::min(self)
+    }
+
+    fn support_max(&self) -> f64 {
+        Max::max(self)
+    }
+}
+
+impl Location for NegativeBinomial {
+    type Value = UInt64Type;
+
+    /// statrs gives the mode as a whole `f64`.
+    fn mode(&self) -> Option<u64> {
+        Mode::mode(self).map(|m| m as u64)
+    }
+
+    fn median(&self) -> u64 {
+        discrete_median(self)
+    }
+
+    fn support_min(&self) -> u64 {
+        Min::min(self)
+    }
+
+    fn support_max(&self) -> u64 {
+        Max::max(self)
+    }
+}
+
+impl Location for Categorical {
+    type Value = UInt64Type;
+
+    /// statrs has no mode for the categorical distribution, so take the first most
+    /// probable category.
+    fn mode(&self) -> Option<u64> {
+        (0..=Max::max(self)).reduce(|m, x| if self.pmf(x) > self.pmf(m) { x } else { m })
+    }
+
+    fn median(&self) -> u64 {
+        discrete_median(self)
+    }
+
+    fn support_min(&self) -> u64 {
+        Min::min(self)
+    }
+
+    fn support_max(&self) -> u64 {
+        Max::max(self)
+    }
+}
+
+/// A statistic of a distribution that depends only on its parameters.
+pub trait Statistic: std::fmt::Debug + Send + Sync + 'static {
+    type Dist;
+    type Output: ArrowPrimitiveType;
+
+    fn eval(d: &Self::Dist) -> Option<Native<Self::Output>>;
+}
+
+#[derive(Debug)]
+pub struct MeanStatistic<D: Moments> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Moments> Statistic for MeanStatistic<D> {
+    type Dist = D;
+    type Output = Float64Type;
+
+    fn eval(d: &D) -> Option<f64> {
+        d.mean()
+    }
+}
+
+#[derive(Debug)]
+pub struct VarianceStatistic<D: Moments> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Moments> Statistic for VarianceStatistic<D> {
+    type Dist = D;
+    type Output = Float64Type;
+
+    fn eval(d: &D) -> Option<f64> {
+        d.variance()
+    }
+}
+
+#[derive(Debug)]
+pub struct StdDevStatistic<D: Moments> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Moments> Statistic for StdDevStatistic<D> {
+    type Dist = D;
+    type Output = Float64Type;
+
+    fn eval(d: &D) -> Option<f64> {
+        d.std_dev()
+    }
+}
+
+#[derive(Debug)]
+pub struct SkewnessStatistic<D: Moments> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Moments> Statistic for SkewnessStatistic<D> {
+    type Dist = D;
+    type Output = Float64Type;
+
+    fn eval(d: &D) -> Option<f64> {
+        d.skewness()
+    }
+}
+
+#[derive(Debug)]
+pub struct EntropyStatistic<D: Moments> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Moments> Statistic for EntropyStatistic<D> {
+    type Dist = D;
+    type Output = Float64Type;
+
+    fn eval(d: &D) -> Option<f64> {
+        d.entropy()
+    }
+}
+
+#[derive(Debug)]
+pub struct ModeStatistic<D: Location> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Location> Statistic for ModeStatistic<D> {
+    type Dist = D;
+    type Output = D::Value;
+
+    fn eval(d: &D) -> Option<Native<D::Value>> {
+        d.mode()
+    }
+}
+
+#[derive(Debug)]
+pub struct MedianStatistic<D: Location> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Location> Statistic for MedianStatistic<D> {
+    type Dist = D;
+    type Output = D::Value;
+
+    fn eval(d: &D) -> Option<Native<D::Value>> {
+        Some(d.median())
+    }
+}
+
+#[derive(Debug)]
+pub struct SupportMinStatistic<D: Location> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Location> Statistic for SupportMinStatistic<D> {
+    type Dist = D;
+    type Output = D::Value;
+
+    fn eval(d: &D) -> Option<Native<D::Value>> {
+        Some(d.support_min())
+    }
+}
+
+#[derive(Debug)]
+pub struct SupportMaxStatistic<D: Location> {
+    _phantom: PhantomData<D>,
+}
+
+impl<D: Location> Statistic for SupportMaxStatistic<D> {
+    type Dist = D;
+    type Output = D::Value;
+
+    fn eval(d: &D) -> Option<Native<D::Value>> {
+        Some(d.support_max())
+    }
+}
@@ -0,0 +1,71 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, PrimitiveArray},
+        datatypes::{ArrowPrimitiveType, DataType, Float64Type},
+    },
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
+};
+
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::factory1f::Factory1F;
+use super::param::{Param, named};
+use super::statistic::Statistic;
+
+#[derive(Debug)]
+pub struct Statistic1F<E: Statistic<Dist: Factory1F>> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Statistic<Dist: Factory1F>> Statistic1F<E> {
+    pub fn new(name: &str) -> Self {
+        Statistic1F {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: Statistic<Dist: Factory1F>> ScalarUDFImpl for Statistic1F<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Float])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(E::Output::DATA_TYPE)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let p = Param::<Float64Type>::new(&self.name, &args.args, 0)?;
+
+        let mut cache = LastDist::new();
+        let array: PrimitiveArray<E::Output> = (0..args.number_rows)
+            .map(|i| match p.get(i) {
+                Some(p) => {
+                    let d = cache.get(&p, || E::Dist::make(p).map_err(|e| named(&self.name, e)))?;
+                    Ok(E::eval(d))
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<PrimitiveArray<E::Output>, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
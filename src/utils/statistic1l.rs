@@ -0,0 +1,74 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, PrimitiveArray},
+        datatypes::{ArrowPrimitiveType, DataType},
+    },
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
+};
+
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::factory1l::Factory1L;
+use super::list::ListParam;
+use super::param::named;
+use super::statistic::Statistic;
+
+#[derive(Debug)]
+pub struct Statistic1L<E: Statistic<Dist: Factory1L>> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Statistic<Dist: Factory1L>> Statistic1L<E> {
+    pub fn new(name: &str) -> Self {
+        Statistic1L {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: Statistic<Dist: Factory1L>> ScalarUDFImpl for Statistic1L<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::FloatList])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(E::Output::DATA_TYPE)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let p = ListParam::new(&self.name, &args.args, 0)?;
+
+        let mut cache = LastDist::<Vec<f64>, E::Dist>::new();
+        let array: PrimitiveArray<E::Output> = (0..args.number_rows)
+            .map(|i| match p.row(i)? {
+                Some(p) => {
+                    let d = cache.get(p.values().as_ref(), || {
+                        E::Dist::make(p.values()).map_err(|e| named(&self.name, e))
+                    })?;
+                    Ok(E::eval(d))
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<PrimitiveArray<E::Output>, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
@@ -0,0 +1,74 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, PrimitiveArray},
+        datatypes::{ArrowPrimitiveType, DataType, Float64Type},
+    },
+    error::DataFusionError,
+    logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility},
+};
+
+use super::cache::LastDist;
+use super::coerce::{Arg, coerce_args};
+use super::factory1u1f::Factory1U1F;
+use super::param::{Param, named};
+use super::statistic::Statistic;
+
+#[derive(Debug)]
+pub struct Statistic1U1F<E: Statistic<Dist: Factory1U1F>> {
+    name: String,
+    signature: Signature,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Statistic<Dist: Factory1U1F>> Statistic1U1F<E> {
+    pub fn new(name: &str) -> Self {
+        Statistic1U1F {
+            name: String::from(name),
+            signature: Signature::user_defined(Volatility::Immutable),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: Statistic<Dist: Factory1U1F>> ScalarUDFImpl for Statistic1U1F<E> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> datafusion::error::Result<Vec<DataType>> {
+        coerce_args(&self.name, arg_types, &[Arg::Count, Arg::Float])
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(E::Output::DATA_TYPE)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue, DataFusionError> {
+        let p1 = Param::count(&self.name, &args.args, 0)?;
+        let p2 = Param::<Float64Type>::new(&self.name, &args.args, 1)?;
+
+        let mut cache = LastDist::new();
+        let array: PrimitiveArray<E::Output> = (0..args.number_rows)
+            .map(|i| match (p1.get(i), p2.get(i)) {
+                (Some(p1), Some(p2)) => {
+                    let d = cache.get(&(p1, p2), || {
+                        E::Dist::make(p1, p2).map_err(|e| named(&self.name, e))
+                    })?;
+                    Ok(E::eval(d))
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<PrimitiveArray<E::Output>, DataFusionError>>()?;
+        Ok(ColumnarValue::from(Arc::new(array) as ArrayRef))
+    }
+}
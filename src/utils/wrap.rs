@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use datafusion::logical_expr::{
+    Expr, ScalarUDF, expr::ScalarFunction, simplify::ExprSimplifyResult,
+};
+
+/// Wrap the function of a call that the wrapped function `name` simplified to, such as the
+/// same function with some state taken from its literal arguments, so the wrapper is not
+/// lost. Any other result is returned as it is.
+pub fn rewrap(
+    name: &str,
+    result: ExprSimplifyResult,
+    wrap: impl FnOnce(Arc<ScalarUDF>) -> ScalarUDF,
+) -> ExprSimplifyResult {
+    match result {
+        ExprSimplifyResult::Simplified(Expr::ScalarFunction(f)) if f.name() == name => {
+            let udf = Arc::new(wrap(f.func));
+            ExprSimplifyResult::Simplified(Expr::ScalarFunction(ScalarFunction::new_udf(
+                udf, f.args,
+            )))
+        }
+        result => result,
+    }
+}